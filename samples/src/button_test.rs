@@ -6,6 +6,15 @@ enum State {
     None,
     Quit,
     PrintWord,
+    ToggleTheme,
+}
+
+#[derive(Clone, Debug)]
+enum Event {
+    Nothing,
+    Quit,
+    PrintWord,
+    ToggleTheme,
 }
 
 fn main() {
@@ -14,31 +23,67 @@ fn main() {
     let mut state = State::None;
 
     let mut g_handler = GuiHandler::new(Colour::WHITE);
+    g_handler.add_button_with_position("Hello", Event::PrintWord, (100, 0));
+    g_handler.add_button("Goodbye", Event::Quit);
+    g_handler.add_button("Hello again", Event::PrintWord);
+    g_handler.add_button("Hello again again", Event::PrintWord);
+    g_handler.add_button_with_position("Oop I'm over here now", Event::Nothing, (600, 100));
+    g_handler.add_button("Wooop", Event::Nothing);
+    g_handler.add_button("Toggle theme", Event::ToggleTheme);
     g_handler
-        .add_button_with_position("Hello", "print_word", (100, 0))
-        .add_button("Goodbye", "quit")
-        .add_button("Hello again", "print_word")
-        .add_button("Hello again again", "print_word")
-        .add_button_with_position("Oop I'm over here now", "", (600, 100))
-        .add_button("Wooop", "")
         .set_components_fix_widths(true)
-        .set_button_action_function(|state, action| match action {
-            "quit" => *state = State::Quit,
-            "print_word" => *state = State::PrintWord,
-            _ => {}
+        .set_button_action_function(|state, event| match event {
+            Event::Quit => *state = State::Quit,
+            Event::PrintWord => *state = State::PrintWord,
+            Event::ToggleTheme => *state = State::ToggleTheme,
+            Event::Nothing => {}
         });
 
+    let mut light = false;
+    // Clicks that miss every button leave a mark, standing in for game input
+    // that should not fire when the GUI is clicked.
+    let mut marks: Vec<Vector2> = Vec::new();
+
     while !rl_handler.window_should_close() && state != State::Quit {
+        if state == State::ToggleTheme {
+            light = !light;
+            g_handler.set_theme(match light {
+                true => Theme::light(),
+                false => Theme::dark(),
+            });
+            state = State::None;
+        }
+
         let mut draw_handler = g_handler
             .execute_actions(&mut state)
-            .draw(&mut rl_handler, &rl_thread)
-            .unwrap();
+            .draw(&mut rl_handler, &rl_thread);
 
         if state == State::PrintWord {
             println!("Hello");
             state = State::None;
         }
 
+        for event in g_handler.events() {
+            match event {
+                GuiEvent::ButtonDoubleClicked { event, .. } => {
+                    println!("Double-clicked {:?}", event)
+                }
+                GuiEvent::ButtonRightClicked { event, .. } => println!("Right-clicked {:?}", event),
+                GuiEvent::ButtonLongPressed { event, .. } => println!("Long-pressed {:?}", event),
+                _ => (),
+            }
+        }
+
+        if !g_handler.get_input_capture().mouse
+            && draw_handler.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON)
+        {
+            marks.push(draw_handler.get_mouse_position());
+        }
+
+        for mark in marks.iter() {
+            draw_handler.draw_circle_v(mark, 5.0, Colour::RED);
+        }
+
         draw_handler.draw_fps(0, 0);
     }
 }
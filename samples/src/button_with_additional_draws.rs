@@ -8,20 +8,30 @@ enum State {
     PrintWord,
 }
 
-struct External;
+#[derive(Clone, Debug)]
+enum Event {
+    Nothing,
+    Quit,
+    PrintWord,
+}
+
+struct External {
+    position: Point,
+}
 impl External {
     fn new() -> External {
-        External {}
+        External {
+            position: (640, 360),
+        }
     }
 }
 
 impl Drawable for External {
-    fn draw(&mut self, draw_func: &mut RaylibDrawHandle) {
-        draw_func.draw_ellipse(
-            draw_func.get_mouse_x(),
-            draw_func.get_mouse_y(),
-            30.0,
-            30.0,
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        renderer.draw_rounded_rectangle(
+            (self.position.0 - 30, self.position.1 - 30),
+            (60, 60),
+            30,
             Colour::GREEN,
         );
     }
@@ -34,26 +44,25 @@ fn main() {
     let external = External::new();
 
     let mut g_handler = GuiHandler::new(Colour::WHITE);
+    g_handler.add_button_with_position("Hello", Event::PrintWord, (100, 0));
+    g_handler.add_button("Goodbye", Event::Quit);
+    g_handler.add_button("Hello again", Event::PrintWord);
+    g_handler.add_button("Hello again again", Event::PrintWord);
+    g_handler.add_button_with_position("Oop I'm over here now", Event::Nothing, (600, 100));
+    g_handler.add_button("Wooop", Event::Nothing);
     g_handler
-        .add_button_with_position("Hello", "print_word", (100, 0))
-        .add_button("Goodbye", "quit")
-        .add_button("Hello again", "print_word")
-        .add_button("Hello again again", "print_word")
-        .add_button_with_position("Oop I'm over here now", "", (600, 100))
-        .add_button("Wooop", "")
         .add_external_draw(Box::new(external)) // <- add a new external draw
         .set_components_fix_widths(true)
-        .set_button_action_function(|state, action| match action {
-            "quit" => *state = State::Quit,
-            "print_word" => *state = State::PrintWord,
-            _ => {}
+        .set_button_action_function(|state, event| match event {
+            Event::Quit => *state = State::Quit,
+            Event::PrintWord => *state = State::PrintWord,
+            Event::Nothing => {}
         });
 
     while !rl_handler.window_should_close() && state != State::Quit {
         let mut draw_handler = g_handler
             .execute_actions(&mut state)
-            .draw(&mut rl_handler, &rl_thread)
-            .unwrap();
+            .draw(&mut rl_handler, &rl_thread);
 
         if state == State::PrintWord {
             println!("Hello");
@@ -0,0 +1,58 @@
+use ptgui::prelude::*;
+use raylib::prelude::*;
+
+const RESOLUTIONS: [(i32, i32); 6] = [
+    (800, 600),
+    (1024, 768),
+    (1280, 720),
+    (1366, 768),
+    (1600, 900),
+    (1920, 1080),
+];
+
+fn main() {
+    let (mut rl_handler, rl_thread) = raylib::init()
+        .size(1280, 720)
+        .title("Combo Box Test")
+        .build();
+    rl_handler.set_target_fps(60);
+
+    let resolution_names: Vec<String> = RESOLUTIONS
+        .iter()
+        .map(|(width, height)| format!("{} x {}", width, height))
+        .collect();
+    let resolution_names: Vec<&str> = resolution_names.iter().map(|n| n.as_str()).collect();
+
+    let mut g_handler = GuiHandler::<(), ()>::new(Colour::WHITE);
+    g_handler.add_label("Resolution");
+    let resolution = g_handler.add_combo_box(&resolution_names, Some(2));
+    g_handler
+        .get_combo_box_mut(resolution)
+        .unwrap()
+        .set_max_visible_options(4);
+    g_handler.add_label_with_position("Language", (300, 0));
+    let language = g_handler.add_combo_box_with_position(
+        &["English", "French", "German", "Spanish", "Japanese"],
+        Some(0),
+        (300, 50),
+    );
+
+    while !rl_handler.window_should_close() {
+        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread);
+
+        draw_handler.draw_fps(0, 690);
+
+        if let Ok(Some(language)) = g_handler.get_combo_box_text(language) {
+            draw_handler.draw_text(language, 600, 60, 20, Colour::BLACK);
+        }
+
+        for event in g_handler.drain_events() {
+            if let GuiEvent::ComboBoxChanged { combo_box, index } = event {
+                if combo_box == resolution {
+                    let (width, height) = RESOLUTIONS[index];
+                    rl_handler.set_window_size(width, height);
+                }
+            }
+        }
+    }
+}
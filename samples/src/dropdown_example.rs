@@ -8,19 +8,36 @@ fn main() {
         .build();
     rl_handler.set_target_fps(60);
 
-    let mut g_handler = GuiHandler::<()>::new(Colour::WHITE);
-    g_handler
-        .add_slider(69, 420, 69.0)
-        .set_components_fix_widths(true)
-        .add_dropdown("Test")
-        .get_dropdowns_mut()
+    let mut g_handler = GuiHandler::<(), ()>::new(Colour::WHITE);
+    g_handler.add_slider(69.0, 420.0, 69.0);
+    g_handler.set_components_fix_widths(true);
+
+    let dropdown = g_handler.add_dropdown("Test");
+    let nested_slider = g_handler
+        .get_dropdown_mut(dropdown)
         .unwrap()
-        .get_mut(0)
+        .add_slider(0.0, 100.0, 50.0);
+    let nested_checkbox = g_handler
+        .get_dropdown_mut(dropdown)
         .unwrap()
-        .add_slider(0, 100, 50.0);
+        .add_checkbox("Show value", true);
+
+    let levels = g_handler.add_dropdown("Levels");
+    let levels_dropdown = g_handler.get_dropdown_mut(levels).unwrap();
+    for level in 1..=30 {
+        levels_dropdown.add_button(&format!("Level {}", level), ());
+    }
+    levels_dropdown.set_max_height(Some(300));
 
     while !rl_handler.window_should_close() {
-        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread).unwrap();
+        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread);
+
+        if let (Ok(value), Ok(true)) = (
+            g_handler.get_slider_value_i32(nested_slider),
+            g_handler.get_checkbox_value(nested_checkbox),
+        ) {
+            draw_handler.draw_text(&value.to_string(), 0, 690, 20, Colour::BLACK);
+        }
 
         draw_handler.draw_fps(0, 0);
     }
@@ -11,16 +11,14 @@ fn main() {
     rl_handler.set_target_fps(60);
 
     let mut g_handler = GuiHandler::new(Colour::WHITE);
+    g_handler.add_label("Main Menu");
+    g_handler.add_button("Test button", ());
     g_handler
-        .add_label("Main Menu")
-        .add_button("Test button", "")
-        .set_button_action_function(|s, a| match a {
-            _ => *s = State::Empty,
-        })
+        .set_button_action_function(|s, _| *s = State::Empty)
         .set_components_fix_widths(true);
 
     while !rl_handler.window_should_close() {
-        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread).unwrap();
+        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread);
 
         draw_handler.draw_fps(0, 0);
     }
@@ -0,0 +1,119 @@
+use ptgui::prelude::*;
+use raylib::prelude::*;
+
+const DIFFICULTIES: [&str; 3] = ["Easy", "Normal", "Hard"];
+
+struct Menu {
+    clicks: u32,
+    difficulty: usize,
+}
+
+fn main() {
+    let (mut rl_handler, rl_thread) = raylib::init()
+        .size(1280, 720)
+        .resizable()
+        .title("Layout Test")
+        .build();
+    rl_handler.set_target_fps(60);
+    // Escape closes dropdowns and lists instead of the window.
+    rl_handler.set_exit_key(None);
+    let mut menu = Menu {
+        clicks: 0,
+        difficulty: 1,
+    };
+
+    let mut g_handler = GuiHandler::<Menu, ()>::new(Colour::WHITE);
+    // Scales the menu up or down to fill the same amount of any window.
+    g_handler.set_reference_resolution((1280, 720));
+    // Reloads the theme whenever the file is saved, run from the `samples`
+    // directory.
+    g_handler.watch_theme_file("themes/menu.toml").unwrap();
+    let title = g_handler.add_label("Main Menu");
+    let continue_game = g_handler.add_button("Continue", ());
+    let play = g_handler.add_button("Play", ());
+    let options = g_handler.add_button("Options", ());
+    let quit = g_handler.add_button("Quit", ());
+    let version = g_handler.add_label("v0.4.0");
+    let volume = g_handler.add_slider(0.0, 100.0, 50.0);
+    let subtitles = g_handler.add_toggle("Subtitles", true);
+    let difficulty = g_handler.add_radio_group(&DIFFICULTIES, Some(1));
+    let left = g_handler.add_button("<", ());
+    let right = g_handler.add_button(">", ());
+
+    // There is no save file to continue from, so the button is greyed out and
+    // skipped by the arrow keys.
+    g_handler.set_enabled(continue_game, false).unwrap();
+    g_handler.on_click(play, Box::new(|menu| menu.clicks += 1));
+    g_handler.on_select(difficulty, Box::new(|menu, index| menu.difficulty = index));
+
+    let mut arrows = Layout::hbox();
+    arrows.set_spacing(5).add_widget(left).add_widget(right);
+
+    let mut layout = Layout::vbox();
+    layout
+        .set_anchor(Anchor::Centre)
+        .set_padding(10)
+        .set_spacing(5)
+        .set_alignment(Alignment::Fill)
+        .add_widget(title)
+        .add_widget(continue_game)
+        .add_widget(play)
+        .add_widget(options)
+        .add_widget(quit)
+        .add_widget_with_limits(volume, (0, 0), Some((300, 50)))
+        .add_widget(subtitles)
+        .add_widget(difficulty)
+        .add_layout(arrows);
+    g_handler.add_layout(layout);
+
+    // Stays in the bottom-right corner, 2% away from the edges, when the window
+    // is resized.
+    let mut corner = Layout::vbox();
+    corner
+        .set_anchor(Anchor::BottomRight)
+        .set_offset(Offset::Percent(-2.0), Offset::Percent(-2.0))
+        .add_widget(version);
+    g_handler.add_layout(corner);
+
+    // Starts with "Play" focused, so the menu can be used straight away with
+    // the arrow keys, Tab or a gamepad.
+    g_handler.set_focus(play).unwrap();
+
+    while !rl_handler.window_should_close() {
+        let mut draw_handler = g_handler
+            .execute_actions(&mut menu)
+            .draw(&mut rl_handler, &rl_thread);
+
+        draw_handler.draw_fps(0, 0);
+
+        // The shoulder buttons of a gamepad change the difficulty.
+        let mut tabs = 0;
+        for event in g_handler.events() {
+            match event {
+                GuiEvent::ThemeError(error) => eprintln!("{}", error),
+                GuiEvent::NextTab => tabs += 1,
+                GuiEvent::PreviousTab => tabs -= 1,
+                _ => (),
+            }
+        }
+        if tabs != 0 {
+            menu.difficulty =
+                (menu.difficulty as i32 + tabs).rem_euclid(DIFFICULTIES.len() as i32) as usize;
+            g_handler
+                .get_radio_group_mut(difficulty)
+                .unwrap()
+                .set_selected(Some(menu.difficulty));
+        }
+
+        // Changing the text of a component reflows the rest of the layout.
+        g_handler
+            .set_text(
+                title,
+                &format!(
+                    "Played {} times on {}",
+                    menu.clicks, DIFFICULTIES[menu.difficulty]
+                ),
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+use ptgui::prelude::*;
+use raylib::prelude::*;
+
+fn main() {
+    let (mut rl_handler, rl_thread) = raylib::init()
+        .size(1280, 720)
+        .title("Progress Bar Test")
+        .build();
+    rl_handler.set_target_fps(60);
+
+    let mut g_handler = GuiHandler::<(), ()>::new(Colour::WHITE);
+    g_handler.add_label("Health");
+    let health = g_handler.add_progress_bar(0.0, 100.0, 100.0, (300, 30));
+    g_handler
+        .get_progress_bar_mut(health)
+        .unwrap()
+        .set_smoothing(0.5)
+        .set_show_text(true);
+    g_handler.add_label_with_position("Stamina", (350, 0));
+    let stamina = g_handler.add_progress_bar_with_position(0.0, 10.0, 10.0, (40, 200), (350, 50));
+    g_handler
+        .get_progress_bar_mut(stamina)
+        .unwrap()
+        .set_direction(FillDirection::Up)
+        .set_segments(10);
+    g_handler.add_label_with_position("Loading", (0, 300));
+    let loading = g_handler.add_progress_bar_with_position(0.0, 1.0, 0.0, (600, 20), (0, 350));
+
+    let mut progress = 0.0;
+
+    while !rl_handler.window_should_close() {
+        // Space takes damage, R heals and holding shift uses up stamina.
+        if rl_handler.is_key_pressed(KeyboardKey::KEY_SPACE) {
+            let value = g_handler.get_progress_bar_value(health).unwrap();
+            g_handler
+                .set_progress_bar_value(health, value - 15.0)
+                .unwrap();
+        }
+        if rl_handler.is_key_pressed(KeyboardKey::KEY_R) {
+            g_handler.set_progress_bar_value(health, 100.0).unwrap();
+        }
+
+        let stamina_change = match rl_handler.is_key_down(KeyboardKey::KEY_LEFT_SHIFT) {
+            true => -4.0,
+            false => 2.0,
+        };
+        let value = g_handler.get_progress_bar_value(stamina).unwrap();
+        g_handler
+            .set_progress_bar_value(
+                stamina,
+                value + stamina_change * rl_handler.get_frame_time(),
+            )
+            .unwrap();
+
+        progress = (progress + rl_handler.get_frame_time() / 5.0) % 1.0;
+        g_handler.set_progress_bar_value(loading, progress).unwrap();
+
+        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread);
+
+        draw_handler.draw_fps(0, 690);
+    }
+}
@@ -1,25 +1,75 @@
 use ptgui::prelude::*;
 use raylib::prelude::*;
 
+#[derive(Default)]
+struct Settings {
+    brightness: f32,
+    frequencies: (f32, f32),
+    sensitivity: f32,
+    volume: f32,
+}
+
 fn main() {
     let (mut rl_handler, rl_thread) = raylib::init().size(1280, 720).title("Slider Test").build();
     rl_handler.set_target_fps(60);
+    let mut settings = Settings::default();
+
+    let mut g_handler = GuiHandler::<Settings, ()>::new(Colour::WHITE);
+    let brightness = g_handler.add_slider_with_position(0.0, 255.0, 0.0, (100, 100));
+    let volume = g_handler.add_slider(0.0, 100.0, 50.0);
+    g_handler.add_slider_with_position(69.0, 420.0, 0.0, (500, 0));
+    let sensitivity = g_handler.add_slider(0.1, 10.0, 1.0);
+    let gain = g_handler.add_slider_with_position(-12.0, 12.0, 0.0, (1000, 100));
+    let frequencies = g_handler.add_range_slider(20.0, 20000.0, 200.0, 2000.0);
+
+    // Volume moves in 5% steps and shows as a percentage.
+    g_handler
+        .get_slider_mut(volume)
+        .unwrap()
+        .set_step(Some(5.0))
+        .set_formatter(Box::new(|value| format!("{}%", value)));
+    // Each part of the track multiplies the sensitivity by the same amount.
+    g_handler
+        .get_slider_mut(sensitivity)
+        .unwrap()
+        .set_logarithmic(true)
+        .set_formatter(Box::new(|value| format!("{:.2}x", value)));
+    g_handler
+        .get_slider_mut(gain)
+        .unwrap()
+        .set_orientation(Orientation::Vertical)
+        .set_step(Some(1.0))
+        .set_formatter(Box::new(|value| format!("{:+}dB", value)));
+    g_handler
+        .get_range_slider_mut(frequencies)
+        .unwrap()
+        .set_logarithmic(true)
+        .set_formatter(Box::new(|value| format!("{:.0}Hz", value)));
 
-    let mut g_handler = GuiHandler::<()>::new(Colour::WHITE);
     g_handler
-        .add_slider_with_position(0, 255, 0.0, (100, 100))
-        .add_slider(0, 10, 0.0)
-        .add_slider_with_position(69, 420, 0.0, (500, 0))
-        .add_slider(10, 20, 10.0)
+        .on_change(
+            brightness,
+            Box::new(|settings, value| settings.brightness = value),
+        )
+        .on_change(volume, Box::new(|settings, value| settings.volume = value))
+        .on_change(
+            sensitivity,
+            Box::new(|settings, value| settings.sensitivity = value),
+        )
+        .on_range_change(
+            frequencies,
+            Box::new(|settings, values| settings.frequencies = values),
+        )
         .set_components_fix_widths(true);
 
     while !rl_handler.window_should_close() {
         println!(
-            "Slider 1: {}, Slider 2: {}",
-            g_handler.get_slider_value(0).unwrap(),
-            g_handler.get_slider_value(1).unwrap()
+            "Brightness: {}, Volume: {}, Sensitivity: {}, Frequencies: {:?}",
+            settings.brightness, settings.volume, settings.sensitivity, settings.frequencies
         );
-        let mut draw_handler = g_handler.draw(&mut rl_handler, &rl_thread).unwrap();
+        let mut draw_handler = g_handler
+            .execute_actions(&mut settings)
+            .draw(&mut rl_handler, &rl_thread);
 
         draw_handler.draw_fps(0, 0);
     }
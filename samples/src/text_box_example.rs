@@ -0,0 +1,48 @@
+use ptgui::prelude::*;
+use raylib::prelude::*;
+
+#[derive(Default)]
+struct Login {
+    name: String,
+}
+
+fn main() {
+    let (mut rl_handler, rl_thread) = raylib::init()
+        .size(1280, 720)
+        .title("Text Box Test")
+        .build();
+    rl_handler.set_target_fps(60);
+    let mut login = Login::default();
+
+    let mut g_handler = GuiHandler::<Login, ()>::new(Colour::WHITE);
+    g_handler.add_label("Log in");
+    let name = g_handler.add_text_box(300);
+    let password = g_handler.add_text_box(300);
+
+    g_handler
+        .get_text_box_mut(name)
+        .unwrap()
+        .set_placeholder("Player name")
+        .set_max_length(Some(16));
+    g_handler
+        .get_text_box_mut(password)
+        .unwrap()
+        .set_placeholder("Password")
+        .set_password(true);
+    g_handler.on_submit(name, Box::new(|login, text| login.name = text));
+
+    while !rl_handler.window_should_close() {
+        let mut draw_handler = g_handler
+            .execute_actions(&mut login)
+            .draw(&mut rl_handler, &rl_thread);
+
+        draw_handler.draw_fps(0, 0);
+        draw_handler.draw_text(
+            &format!("Hello, {}!", login.name),
+            400,
+            60,
+            20,
+            Colour::BLACK,
+        );
+    }
+}
@@ -1,114 +1,283 @@
 use crate::{
     common::*,
     gui_component::GuiComponentBehaviour,
-    prelude::{state_get_colour, Colour, Dimensions, Point, StateColour},
+    prelude::{
+        Action, Callback, Click, ClickRecognizer, Dimensions, InputState, Point, PointerButton,
+        Renderer, TextMeasurer, Theme, WidgetId, WidgetState, WidgetStyle,
+    },
 };
-use raylib::prelude::{text::measure_text, *};
 use std::iter::FromIterator;
 
-#[derive(PartialEq)]
-pub struct Button {
-    action_string: String,
-    colour: Colour,
-    font_size: i32,
+pub struct Button<T, E> {
+    clicked: bool,
+    clicks: ClickRecognizer,
+    custom_style: bool,
+    enabled: bool,
+    event: E,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    on_click: Option<Callback<T>>,
     pub dimensions: Dimensions,
     pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
     text: String,
+    text_measurer: TextMeasurer,
+    visible: bool,
 }
 
-impl Button {
+impl<T, E> Button<T, E> {
     /// Create a new button, automatically figuring out width depending on the
     /// `text` and the given `font_size`.
-    pub fn new(text: &str, action_string: &str, font_size: i32, position: Point) -> Self {
-        let dimensions = (measure_text(text, font_size) + 10, 50);
-        Self {
-            text: text.to_string(),
-            action_string: action_string.to_string(),
-            font_size,
-            position,
-            dimensions,
-            colour: state_get_colour(StateColour::Default),
-        }
+    pub fn new(text: &str, event: E, font_size: i32, position: Point) -> Self {
+        let mut button = Self::new_with_dimensions(text, event, font_size, position, (0, 0));
+        button.fit_text();
+
+        button
     }
 
     /// Create a new button with specific `dimensions`.
     pub fn new_with_dimensions(
         text: &str,
-        action_string: &str,
+        event: E,
         font_size: i32,
         position: Point,
         dimensions: Dimensions,
     ) -> Self {
         Self {
             text: text.to_string(),
-            action_string: action_string.to_string(),
-            font_size,
+            event,
             position,
             dimensions,
-            colour: state_get_colour(StateColour::Default),
+            preferred_dimensions: dimensions,
+            clicked: false,
+            clicks: ClickRecognizer::new(),
+            custom_style: false,
+            enabled: true,
+            focused: false,
+            hovered: false,
+            style: WidgetStyle {
+                font_size,
+                ..Theme::default().button
+            },
+            id: WidgetId::unique(),
+            on_click: None,
+            text_measurer: TextMeasurer::default(),
+            visible: true,
         }
     }
 
-    /// Set the text of a button, automatically resizing it's width to
-    /// compensate for the change.
+    /// Returns the `WidgetId` of the button.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Sets the function used to measure the width of the button's text,
+    /// resizing it to fit.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+        self.fit_text();
+
+        self
+    }
+
+    /// Set the text of a button, automatically resizing it to compensate for
+    /// the change.
     pub fn set_text(&mut self, text: &str) -> &Self {
-        let new_width = measure_text(text, self.font_size);
-        self.resize((new_width, self.dimensions.1));
+        self.text = text.to_string();
+        self.fit_text();
 
         &*self
     }
 
+    fn fit_text(&mut self) {
+        let text_width = self.text_measurer.measure(&self.text, self.style.font_size);
+        self.preferred_dimensions = self.style.fit_text(text_width);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Gives the button its own `style`, which is used instead of the theme
+    /// of the `GuiHandler`, resizing it to fit.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
+
+        self
+    }
+
+    /// Styles the button using `theme`, unless it has been given its own style
+    /// with `set_style`, resizing it to fit.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.button.clone();
+            self.fit_text();
+        }
+    }
+
+    /// Returns the event the button was created with.
+    pub fn get_event(&self) -> &E { &self.event }
+
+    /// Returns the state the button is currently drawn in, which is
+    /// `WidgetState::Active` while it is held down with the mouse over it.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match (self.is_pressed(), self.hovered) {
+            (true, true) => WidgetState::Active,
+            (false, true) => WidgetState::Hovered,
+            (_, false) => WidgetState::Default,
+        }
+    }
+
+    /// Returns whether or not the left mouse button was pressed on the button
+    /// and is still being held.
+    pub fn is_pressed(&self) -> bool { self.clicks.get_pressed() == Some(PointerButton::Left) }
+
+    /// Recognises clicks on the button with the given `input`, returning the
+    /// clicks that were made. The button is clicked when the left mouse button
+    /// is released over it after being pressed on it.
+    pub fn handle_input(&mut self, input: &InputState) -> Vec<Click> {
+        let inside = is_inside(self.position, self.dimensions, input.mouse_position);
+        let clicks = self.clicks.update(input, inside);
+        self.clicked = clicks.contains(&Click::Primary);
+
+        clicks
+    }
+
+    /// Returns whether or not the button is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the button.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the button responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the button. A disabled button is drawn in the
+    /// disabled colours of its style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        if !enabled {
+            self.clicks.reset();
+        }
+
+        self
+    }
+
+    /// Returns whether or not the button has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the button.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Clicks the button without the mouse, such as when it is activated while
+    /// focused, returning its event.
+    pub fn activate(&mut self) -> E
+    where
+        E: Clone,
+    {
+        self.clicked = true;
+
+        self.event.clone()
+    }
+
+    /// Returns the dimensions the button was created with, updated to fit its
+    /// text whenever it is changed with `set_text` or restyled. Layouts use
+    /// this as the size the button would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
     /// Resizes the button to the given `new_dimensions`.
     pub fn resize(&mut self, new_dimensions: Dimensions) { self.dimensions = new_dimensions; }
+
+    /// Sets the callback that is called when the button has been clicked,
+    /// instead of the `GuiHandler`'s button action function.
+    pub fn set_on_click(&mut self, on_click: Callback<T>) -> &mut Self {
+        self.on_click = Some(on_click);
+
+        self
+    }
+
+    /// Calls the button's callback if it was clicked during the last update,
+    /// falling back to `button_action` with the button's event if it has no
+    /// callback of its own.
+    pub fn execute_callbacks(&mut self, state: &mut T, button_action: Option<Action<T, E>>) {
+        if !std::mem::take(&mut self.clicked) {
+            return;
+        }
+
+        match (self.on_click.as_mut(), button_action) {
+            (Some(on_click), _) => on_click(state),
+            (None, Some(button_action)) => button_action(state, &self.event),
+            (None, None) => (),
+        }
+    }
 }
 
-impl GuiComponentBehaviour<String> for Button {
+impl<T, E: Clone> GuiComponentBehaviour<Option<E>> for Button<T, E> {
     /// Draw `Button` to screen.
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle) {
-        let mouse_position = (draw_handler.get_mouse_x(), draw_handler.get_mouse_y());
-        self.is_hovered(mouse_position);
-
-        draw_handler.draw_rectangle(
-            self.position.0,
-            self.position.1,
-            self.dimensions.0 + 10,
-            self.dimensions.1,
-            self.colour,
-        );
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
 
-        draw_handler.draw_text(
-            self.text.as_str(),
-            self.position.0 + 10,
-            self.position.1 + self.dimensions.1 - 30,
-            self.font_size,
-            state_get_colour(StateColour::Text),
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+        draw_widget_text(
+            renderer,
+            &self.text,
+            self.position,
+            self.dimensions,
+            &self.style,
+            state,
         );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
     }
 
-    /// Checks whether cursor is hovering over button, changes colour and
+    /// Checks whether cursor is hovering over button, changes its state and
     /// returns `true` or `false` depending on the result.
     fn is_hovered(&mut self, mouse_position: Point) -> bool {
-        if is_inside(self.position, self.dimensions, mouse_position) {
-            self.colour = state_get_colour(StateColour::Hovered);
-            true
-        } else {
-            self.colour = state_get_colour(StateColour::Default);
-            false
-        }
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
     }
 
-    /// Checks whether or not the user is clicking on the button.
-    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> String {
-        if is_inside(self.position, self.dimensions, mouse_position) && is_clicked {
-            self.action_string.to_string()
+    /// Checks whether or not the user has clicked on the button, returning
+    /// its event if they have, where `is_clicked` is whether or not the left
+    /// mouse button is being held down. Like `handle_input`, the button is
+    /// only clicked when the mouse is released over it after being pressed on
+    /// it.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> Option<E> {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
+
+        match (is_clicked, self.is_pressed()) {
+            (true, false) => input = input.with_button_pressed(PointerButton::Left),
+            (true, true) => input = input.with_button_down(PointerButton::Left),
+            (false, true) => input = input.with_button_released(PointerButton::Left),
+            (false, false) => (),
+        }
+
+        if self.handle_input(&input).contains(&Click::Primary) {
+            Some(self.event.clone())
         } else {
-            "".to_string()
+            None
         }
     }
 }
 
-impl FromIterator<DrawableType> for Vec<Button> {
-    fn from_iter<T: IntoIterator<Item = DrawableType>>(iter: T) -> Self {
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<Button<T, E>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
         let mut c = Vec::new();
 
         for i in iter {
@@ -120,3 +289,31 @@ impl FromIterator<DrawableType> for Vec<Button> {
         c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button() -> Button<(), &'static str> {
+        Button::new_with_dimensions("Button", "clicked", 20, (0, 0), (60, 30))
+    }
+
+    #[test]
+    fn is_clicked_waits_for_the_release() {
+        let mut button = button();
+
+        assert_eq!(button.is_clicked((5, 5), true), None);
+        assert!(button.is_pressed());
+        assert_eq!(button.is_clicked((5, 5), true), None);
+        assert_eq!(button.is_clicked((5, 5), false), Some("clicked"));
+        assert!(!button.is_pressed());
+    }
+
+    #[test]
+    fn is_clicked_is_cancelled_by_releasing_off_of_the_button() {
+        let mut button = button();
+
+        button.is_clicked((5, 5), true);
+        assert_eq!(button.is_clicked((-5, -5), false), None);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, Point, Renderer, TextMeasurer, Theme, ValueCallback, WidgetId, WidgetState,
+        WidgetStyle,
+    },
+};
+
+/// Whether a `Checkbox` is ticked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckState {
+    Unchecked,
+    Checked,
+    /// Neither ticked nor unticked, such as when a `Checkbox` stands for a
+    /// group of options where only some are ticked.
+    Indeterminate,
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        match checked {
+            true => CheckState::Checked,
+            false => CheckState::Unchecked,
+        }
+    }
+}
+
+/// A box that is ticked and unticked by clicking on it or on its label.
+pub struct Checkbox<T> {
+    changed: bool,
+    check_state: CheckState,
+    custom_style: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    on_change: Option<ValueCallback<T, CheckState>>,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
+    text: String,
+    text_measurer: TextMeasurer,
+    tri_state: bool,
+    visible: bool,
+}
+
+impl<T> Checkbox<T> {
+    /// Create a new `Checkbox` labelled with `text`, automatically figuring
+    /// out its width.
+    pub fn new(text: &str, checked: bool, position: Point) -> Self {
+        let mut checkbox = Self {
+            changed: false,
+            check_state: checked.into(),
+            custom_style: false,
+            dimensions: (0, 0),
+            enabled: true,
+            focused: false,
+            hovered: false,
+            id: WidgetId::unique(),
+            on_change: None,
+            position,
+            preferred_dimensions: (0, 0),
+            style: Theme::default().checkbox,
+            text: text.to_string(),
+            text_measurer: TextMeasurer::default(),
+            tri_state: false,
+            visible: true,
+        };
+        checkbox.fit_text();
+
+        checkbox
+    }
+
+    widget_methods!(Checkbox);
+    widget_style_methods!(Checkbox, checkbox, fit_text);
+    widget_on_change!(Checkbox, CheckState, |checkbox| checkbox.check_state);
+
+    /// Sets the text of the label of the `Checkbox`, resizing it to fit.
+    pub fn set_text(&mut self, text: &str) -> &mut Self {
+        self.text = text.to_string();
+        self.fit_text();
+
+        self
+    }
+
+    /// The length of each side of the box, which matches the font size.
+    fn box_size(&self) -> i32 { self.style.font_size }
+
+    fn fit_text(&mut self) {
+        let text_width = self.text_measurer.measure(&self.text, self.style.font_size);
+        self.preferred_dimensions = self
+            .style
+            .fit_text(self.box_size() + self.style.padding.0 + text_width);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns the `CheckState` of the `Checkbox`.
+    pub fn get_check_state(&self) -> CheckState { self.check_state }
+
+    /// Returns `true` if the `Checkbox` is ticked.
+    pub fn is_checked(&self) -> bool { self.check_state == CheckState::Checked }
+
+    /// Sets the `CheckState` of the `Checkbox`. This can be
+    /// `CheckState::Indeterminate` even if clicking cannot select it.
+    pub fn set_check_state(&mut self, check_state: CheckState) -> &mut Self {
+        self.check_state = check_state;
+
+        self
+    }
+
+    /// Ticks or unticks the `Checkbox`.
+    pub fn set_checked(&mut self, checked: bool) -> &mut Self {
+        self.set_check_state(checked.into())
+    }
+
+    /// Makes clicking the `Checkbox` cycle through
+    /// `CheckState::Indeterminate` as well as checked and unchecked.
+    pub fn set_tri_state(&mut self, tri_state: bool) -> &mut Self {
+        self.tri_state = tri_state;
+
+        self
+    }
+
+    /// Returns the `CheckState` that clicking the `Checkbox` moves it to.
+    fn next_check_state(&self) -> CheckState {
+        match (self.check_state, self.tri_state) {
+            (CheckState::Unchecked, _) => CheckState::Checked,
+            (CheckState::Checked, true) => CheckState::Indeterminate,
+            (CheckState::Checked, false) => CheckState::Unchecked,
+            (CheckState::Indeterminate, true) => CheckState::Unchecked,
+            (CheckState::Indeterminate, false) => CheckState::Checked,
+        }
+    }
+
+    /// Returns whether or not the `Checkbox` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `Checkbox`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Moves the `Checkbox` to its next `CheckState` without the mouse, such
+    /// as when it is activated while focused.
+    pub fn activate(&mut self) -> &mut Self {
+        self.check_state = self.next_check_state();
+        self.changed = true;
+
+        self
+    }
+
+    /// Returns the state the `Checkbox` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match self.hovered {
+            true => WidgetState::Hovered,
+            false => WidgetState::Default,
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for Checkbox<T> {
+    /// Draw `Checkbox` to screen.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        let size = self.box_size();
+        let box_position = (
+            self.position.0 + self.style.padding.0,
+            self.position.1 + (self.dimensions.1 - size) / 2,
+        );
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        renderer.draw_rectangle(box_position, (size, size), self.style.border.get(state));
+        renderer.draw_rectangle(
+            (box_position.0 + 2, box_position.1 + 2),
+            (size - 4, size - 4),
+            self.style.accent.get(state),
+        );
+
+        let inset = size / 4;
+        match self.check_state {
+            CheckState::Checked => renderer.draw_rectangle(
+                (box_position.0 + inset, box_position.1 + inset),
+                (size - inset * 2, size - inset * 2),
+                self.style.foreground.get(state),
+            ),
+            CheckState::Indeterminate => renderer.draw_rectangle(
+                (box_position.0 + inset, box_position.1 + size / 2 - 2),
+                (size - inset * 2, 4),
+                self.style.foreground.get(state),
+            ),
+            CheckState::Unchecked => (),
+        }
+
+        renderer.draw_text(
+            &self.text,
+            (
+                box_position.0 + size + self.style.padding.0,
+                self.position.1 + (self.dimensions.1 - self.style.font_size) / 2,
+            ),
+            self.style.font_size,
+            self.style.text.get(state),
+        );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
+    }
+
+    /// Checks if the `Checkbox` or its label is being hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// Checks if the `Checkbox` or its label was clicked, moving it to its
+    /// next `CheckState` and returning whether or not it changed.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let clicked = is_inside(self.position, self.dimensions, mouse_position) && is_clicked;
+
+        if clicked {
+            self.check_state = self.next_check_state();
+        }
+        self.changed |= clicked;
+
+        clicked
+    }
+}
+
+widget_from_drawables!(Checkbox<T>, Checkbox);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::measure_text_monospace;
+
+    fn checkbox(check_state: CheckState) -> Checkbox<Vec<CheckState>> {
+        let mut checkbox = Checkbox::new("Subtitles", false, (0, 0));
+        checkbox
+            .set_text_measurer(TextMeasurer::new(measure_text_monospace))
+            .set_check_state(check_state)
+            .set_on_change(Box::new(|states: &mut Vec<CheckState>, state| {
+                states.push(state)
+            }));
+
+        checkbox
+    }
+
+    /// Clicks `checkbox` `clicks` times, returning each `CheckState` it
+    /// moves to.
+    fn click(checkbox: &mut Checkbox<Vec<CheckState>>, clicks: usize) -> Vec<CheckState> {
+        (0..clicks)
+            .map(|_| {
+                assert!(checkbox.is_clicked((5, 5), true));
+                checkbox.get_check_state()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn clicking_ticks_and_unticks_the_box() {
+        let mut checkbox = checkbox(CheckState::Unchecked);
+
+        assert_eq!(
+            click(&mut checkbox, 2),
+            vec![CheckState::Checked, CheckState::Unchecked]
+        );
+        assert!(!checkbox.is_clicked((5, 5), false));
+        assert!(!checkbox.is_clicked((-5, 5), true));
+        assert_eq!(checkbox.get_check_state(), CheckState::Unchecked);
+    }
+
+    #[test]
+    fn a_tri_state_checkbox_cycles_through_indeterminate() {
+        let mut checkbox = checkbox(CheckState::Unchecked);
+        checkbox.set_tri_state(true);
+
+        assert_eq!(
+            click(&mut checkbox, 3),
+            vec![
+                CheckState::Checked,
+                CheckState::Indeterminate,
+                CheckState::Unchecked
+            ]
+        );
+    }
+
+    #[test]
+    fn an_indeterminate_checkbox_is_ticked_unless_it_is_tri_state() {
+        let mut checkbox = checkbox(CheckState::Indeterminate);
+
+        assert_eq!(click(&mut checkbox, 1), vec![CheckState::Checked]);
+        assert!(checkbox.is_checked());
+    }
+
+    #[test]
+    fn the_callback_is_called_once_for_each_update_it_changed_in() {
+        let mut checkbox = checkbox(CheckState::Unchecked);
+        let mut states = Vec::new();
+
+        checkbox.activate();
+        // A mouse that misses the `Checkbox` later in the same update does not
+        // undo the change.
+        checkbox.is_clicked((-5, 5), true);
+        checkbox.execute_callbacks(&mut states);
+        checkbox.execute_callbacks(&mut states);
+
+        assert_eq!(states, vec![CheckState::Checked]);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::prelude::{InputState, PointerButton};
+
+/// How quickly a second click has to follow the first to make a
+/// double-click, and how long the left mouse button has to be held to make a
+/// long press, both in seconds.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClickTimings {
+    pub double_click_time: f32,
+    pub long_press_time: f32,
+}
+
+impl Default for ClickTimings {
+    fn default() -> Self {
+        Self {
+            double_click_time: 0.3,
+            long_press_time: 0.6,
+        }
+    }
+}
+
+/// Something a `ClickRecognizer` recognised the mouse doing to a component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Click {
+    /// The left mouse button was pressed and then released over the
+    /// component.
+    Primary,
+    /// A `Primary` click followed another one quickly enough to make a
+    /// double-click. The `Primary` click is still recognised as well.
+    Double,
+    /// The right mouse button was pressed and then released over the
+    /// component.
+    Secondary,
+    /// The left mouse button was held over the component for long enough to
+    /// make a long press, which stops it from being a `Primary` click when it
+    /// is released.
+    LongPress,
+}
+
+/// Recognises clicks from the presses and releases of the mouse over a single
+/// component. A click is only recognised when the mouse is released over the
+/// component it was pressed on, so it can be cancelled by dragging the mouse
+/// off of the component first. Custom widgets can use this to behave like the
+/// components of `ptgui`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClickRecognizer {
+    held_time: f32,
+    long_pressed: bool,
+    pressed: Option<PointerButton>,
+    since_click: Option<f32>,
+}
+
+impl ClickRecognizer {
+    /// Creates a new `ClickRecognizer` with nothing pressed.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns the mouse button that was pressed over the component and is
+    /// still being held.
+    pub fn get_pressed(&self) -> Option<PointerButton> { self.pressed }
+
+    /// Forgets about the button that is being held and the last click, so
+    /// that neither of them lead to any more clicks.
+    pub fn reset(&mut self) { *self = Self::default(); }
+
+    /// Returns the clicks made this frame with `input`, where `inside` is
+    /// whether or not the mouse is over the component, using the timings in
+    /// `input.click_timings`.
+    pub fn update(&mut self, input: &InputState, inside: bool) -> Vec<Click> {
+        let timings = input.click_timings;
+        let mut clicks = Vec::new();
+
+        if let Some(since_click) = self.since_click.as_mut() {
+            *since_click += input.frame_time;
+        }
+
+        if inside && self.pressed.is_none() {
+            self.pressed = [PointerButton::Left, PointerButton::Right]
+                .iter()
+                .copied()
+                .find(|&button| input.is_button_pressed(button));
+            self.held_time = 0.0;
+            self.long_pressed = false;
+        }
+
+        match self.pressed {
+            Some(button) if input.is_button_released(button) || !input.is_button_down(button) => {
+                self.pressed = None;
+
+                if !inside || !input.is_button_released(button) || self.long_pressed {
+                    return clicks;
+                }
+
+                match button {
+                    PointerButton::Left => {
+                        clicks.push(Click::Primary);
+
+                        match self.since_click {
+                            Some(since_click) if since_click <= timings.double_click_time => {
+                                clicks.push(Click::Double);
+                                self.since_click = None;
+                            },
+                            _ => self.since_click = Some(0.0),
+                        }
+                    },
+                    PointerButton::Right => clicks.push(Click::Secondary),
+                    PointerButton::Middle => (),
+                }
+            },
+            Some(PointerButton::Left) if inside && !self.long_pressed => {
+                self.held_time += input.frame_time;
+
+                if self.held_time >= timings.long_press_time {
+                    clicks.push(Click::LongPress);
+                    self.long_pressed = true;
+                }
+            },
+            // Dragging off of the component starts the long press over, so
+            // moving back onto it has to be held for the full time again.
+            Some(_) if !inside => self.held_time = 0.0,
+            _ => (),
+        }
+
+        clicks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press() -> InputState { InputState::new().with_button_pressed(PointerButton::Left) }
+
+    fn hold(frame_time: f32) -> InputState {
+        InputState::new()
+            .with_button_down(PointerButton::Left)
+            .with_frame_time(frame_time)
+    }
+
+    fn release() -> InputState { InputState::new().with_button_released(PointerButton::Left) }
+
+    #[test]
+    fn press_and_release_inside_is_primary() {
+        let mut clicks = ClickRecognizer::new();
+
+        assert!(clicks.update(&press(), true).is_empty());
+        assert_eq!(clicks.get_pressed(), Some(PointerButton::Left));
+        assert_eq!(clicks.update(&release(), true), vec![Click::Primary]);
+        assert_eq!(clicks.get_pressed(), None);
+    }
+
+    #[test]
+    fn releasing_after_dragging_off_is_not_a_click() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(&press(), true);
+        assert!(clicks.update(&hold(0.1), false).is_empty());
+        assert!(clicks.update(&release(), false).is_empty());
+        assert_eq!(clicks.get_pressed(), None);
+    }
+
+    #[test]
+    fn two_quick_clicks_are_a_double_click() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(&press(), true);
+        assert_eq!(clicks.update(&release(), true), vec![Click::Primary]);
+        clicks.update(&press().with_frame_time(0.1), true);
+        assert_eq!(
+            clicks.update(&release().with_frame_time(0.1), true),
+            vec![Click::Primary, Click::Double]
+        );
+    }
+
+    #[test]
+    fn two_slow_clicks_are_not_a_double_click() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(&press(), true);
+        clicks.update(&release(), true);
+        clicks.update(&InputState::new().with_frame_time(0.5), true);
+        clicks.update(&press(), true);
+        assert_eq!(clicks.update(&release(), true), vec![Click::Primary]);
+    }
+
+    #[test]
+    fn holding_is_a_long_press_and_not_a_primary_click() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(&press(), true);
+        assert!(clicks.update(&hold(0.4), true).is_empty());
+        assert_eq!(clicks.update(&hold(0.4), true), vec![Click::LongPress]);
+        assert!(clicks.update(&hold(0.4), true).is_empty());
+        assert!(clicks.update(&release(), true).is_empty());
+    }
+
+    #[test]
+    fn dragging_off_and_back_starts_the_long_press_over() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(&press(), true);
+        clicks.update(&hold(0.5), true);
+        clicks.update(&hold(0.1), false);
+        assert!(clicks.update(&hold(0.5), true).is_empty());
+        assert_eq!(clicks.update(&release(), true), vec![Click::Primary]);
+    }
+
+    #[test]
+    fn right_button_is_secondary() {
+        let mut clicks = ClickRecognizer::new();
+
+        clicks.update(
+            &InputState::new().with_button_pressed(PointerButton::Right),
+            true,
+        );
+        assert_eq!(
+            clicks.update(
+                &InputState::new().with_button_released(PointerButton::Right),
+                true
+            ),
+            vec![Click::Secondary]
+        );
+    }
+}
@@ -0,0 +1,647 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, InputState, Key, Orientation, Point, PointerButton, Renderer, ScrollBar,
+        TextMeasurer, Theme, ValueCallback, WidgetId, WidgetState, WidgetStyle,
+    },
+};
+use std::iter::FromIterator;
+
+/// A box that shows the selected option and opens a list below it to pick a
+/// different one, closing again once an option is picked.
+pub struct ComboBox<T> {
+    changed: bool,
+    custom_style: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    hovered_option: Option<usize>,
+    id: WidgetId,
+    max_visible_options: usize,
+    on_change: Option<ValueCallback<T, usize>>,
+    open: bool,
+    options: Vec<String>,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    scroll_bar: Box<ScrollBar>,
+    selected: Option<usize>,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    visible: bool,
+}
+
+impl<T> ComboBox<T> {
+    /// The default number of options shown in the open list before it
+    /// scrolls.
+    pub const DEFAULT_MAX_VISIBLE_OPTIONS: usize = 8;
+
+    /// Create a new, closed `ComboBox` with an option for each of `options`,
+    /// automatically figuring out its width from the widest option.
+    pub fn new(options: &[&str], selected: Option<usize>, position: Point) -> Self {
+        let mut combo_box = Self {
+            changed: false,
+            custom_style: false,
+            dimensions: (0, 0),
+            enabled: true,
+            focused: false,
+            hovered: false,
+            hovered_option: None,
+            id: WidgetId::unique(),
+            max_visible_options: Self::DEFAULT_MAX_VISIBLE_OPTIONS,
+            on_change: None,
+            open: false,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            position,
+            preferred_dimensions: (0, 0),
+            scroll_bar: Box::new(ScrollBar::new(position, 0, Orientation::Vertical)),
+            selected: None,
+            style: Theme::default().combo_box,
+            text_measurer: TextMeasurer::default(),
+            visible: true,
+        };
+        combo_box.set_selected(selected);
+        combo_box.fit_text();
+
+        combo_box
+    }
+
+    /// Returns the `WidgetId` of the `ComboBox`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `ComboBox` is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `ComboBox`, closing its list when it is hidden.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self.open &= visible;
+
+        self
+    }
+
+    /// Returns whether or not the `ComboBox` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `ComboBox`, closing its list when it is
+    /// disabled. A disabled `ComboBox` is drawn in the disabled colours of its
+    /// style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self.open &= enabled;
+
+        self
+    }
+
+    /// Sets the function used to measure the width of the options of the
+    /// `ComboBox`, resizing it to fit.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+        self.fit_text();
+
+        self
+    }
+
+    /// Adds an option labelled with `text` to the end of the list, resizing
+    /// the `ComboBox` to fit.
+    pub fn add_option(&mut self, text: &str) -> &mut Self {
+        self.options.push(text.to_string());
+        self.fit_text();
+
+        self
+    }
+
+    /// Returns the labels of the options of the `ComboBox`.
+    pub fn get_options(&self) -> &[String] { &self.options }
+
+    /// Returns the index of the selected option, if there is one.
+    pub fn get_selected(&self) -> Option<usize> { self.selected }
+
+    /// Returns the label of the selected option, if there is one.
+    pub fn get_selected_text(&self) -> Option<&str> {
+        self.selected.map(|index| self.options[index].as_str())
+    }
+
+    /// Selects the option at `index`, or clears the selection if it is
+    /// `None`. An index past the last option clears the selection too.
+    pub fn set_selected(&mut self, index: Option<usize>) -> &mut Self {
+        self.selected = index.filter(|&index| index < self.options.len());
+
+        self
+    }
+
+    /// Returns `true` while the list of options is open.
+    pub fn is_open(&self) -> bool { self.open }
+
+    /// Opens or closes the list of options, scrolling the list to the
+    /// selected option when it is opened.
+    pub fn set_open(&mut self, open: bool) -> &mut Self {
+        if open && !self.open {
+            self.layout_scroll_bar();
+            self.scroll_to_selected();
+        }
+
+        self.open = open;
+
+        self
+    }
+
+    /// Selects the option `offset` places after the selected one, stopping at
+    /// the first and last options, without the mouse. The open list is
+    /// scrolled to keep the option in view. Returns whether or not a
+    /// different option was picked.
+    pub fn select_by(&mut self, offset: i32) -> bool {
+        if self.options.is_empty() {
+            return false;
+        }
+
+        let last = self.options.len() as i32 - 1;
+        let index = match self.selected {
+            Some(selected) => (selected as i32 + offset).clamp(0, last),
+            None if offset < 0 => last,
+            None => 0,
+        } as usize;
+
+        let changed = self.selected != Some(index);
+        self.selected = Some(index);
+        self.changed |= changed;
+
+        self.layout_scroll_bar();
+        let top = index as i32 * self.dimensions.1;
+        let bottom = top + self.dimensions.1;
+        let (_, list_dimensions) = self.list_area();
+        let scroll = self.scroll_bar.get_offset();
+
+        if top < scroll {
+            self.scroll_bar.set_offset(top);
+        } else if bottom > scroll + list_dimensions.1 {
+            self.scroll_bar.set_offset(bottom - list_dimensions.1);
+        }
+
+        changed
+    }
+
+    /// Returns whether or not the `ComboBox` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `ComboBox`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Sets how many options are shown in the open list at once, scrolling
+    /// through the rest.
+    pub fn set_max_visible_options(&mut self, max_visible_options: usize) -> &mut Self {
+        self.max_visible_options = max_visible_options.max(1);
+
+        self
+    }
+
+    fn fit_text(&mut self) {
+        let text_width = self
+            .options
+            .iter()
+            .map(|option| self.text_measurer.measure(option, self.style.font_size))
+            .max()
+            .unwrap_or(0);
+
+        // Leaves room for the arrow on the right.
+        self.preferred_dimensions = self
+            .style
+            .fit_text(text_width + self.style.padding.0 + self.style.font_size);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns the position and dimensions of the open list, below the
+    /// `ComboBox`.
+    fn list_area(&self) -> (Point, Dimensions) {
+        let rows = self.options.len().min(self.max_visible_options) as i32;
+
+        (
+            (self.position.0, self.position.1 + self.dimensions.1),
+            (self.dimensions.0, rows * self.dimensions.1),
+        )
+    }
+
+    /// Returns the width of the rows of the open list, which leaves room for
+    /// the `ScrollBar` when there are more options than fit.
+    fn row_width(&self) -> i32 {
+        match self.scroll_bar.is_scrollable() {
+            true => self.dimensions.0 - ScrollBar::THICKNESS,
+            false => self.dimensions.0,
+        }
+    }
+
+    /// Returns the position of the row of the option at `index`, moved up by
+    /// how far the list is scrolled.
+    fn row_position(&self, index: usize) -> Point {
+        let (list_position, _) = self.list_area();
+
+        (
+            list_position.0,
+            list_position.1 + index as i32 * self.dimensions.1 - self.scroll_bar.get_offset(),
+        )
+    }
+
+    /// Returns the index of the option in the open list under
+    /// `mouse_position`, if there is one.
+    fn option_at(&self, mouse_position: Point) -> Option<usize> {
+        let (list_position, list_dimensions) = self.list_area();
+
+        if !self.open
+            || !is_inside(
+                list_position,
+                (self.row_width(), list_dimensions.1),
+                mouse_position,
+            )
+        {
+            return None;
+        }
+
+        (0..self.options.len()).find(|&index| {
+            is_inside(
+                self.row_position(index),
+                (self.row_width(), self.dimensions.1),
+                mouse_position,
+            )
+        })
+    }
+
+    /// Fits the `ScrollBar` to the right edge of the open list.
+    fn layout_scroll_bar(&mut self) {
+        let (list_position, list_dimensions) = self.list_area();
+
+        self.scroll_bar.set_content(
+            self.options.len() as i32 * self.dimensions.1,
+            list_dimensions.1,
+        );
+        self.scroll_bar
+            .resize((ScrollBar::THICKNESS, list_dimensions.1));
+        self.scroll_bar.position = (
+            list_position.0 + list_dimensions.0 - ScrollBar::THICKNESS,
+            list_position.1,
+        );
+    }
+
+    /// Opens and closes the list, picks the option that is clicked and
+    /// scrolls the list with the mouse wheel or its `ScrollBar`. Clicking
+    /// outside of the open list or pressing Escape closes it.
+    pub fn handle_input(&mut self, input: &InputState) {
+        let mouse_position = input.mouse_position;
+        let pressed = input.is_button_pressed(PointerButton::Left);
+        let (list_position, list_dimensions) = self.list_area();
+        self.changed = false;
+        self.layout_scroll_bar();
+
+        if !self.open {
+            if pressed && is_inside(self.position, self.dimensions, mouse_position) {
+                self.open = true;
+                self.scroll_to_selected();
+            }
+
+            return;
+        }
+
+        if self.scroll_bar.is_scrollable() {
+            // The wheel scrolls the whole list by rows below, including over
+            // the `ScrollBar`, so the `ScrollBar` is not given it as well.
+            let without_wheel = InputState {
+                mouse_wheel: 0.0,
+                ..input.clone()
+            };
+            self.scroll_bar.is_hovered(mouse_position);
+            self.scroll_bar.handle_input(&without_wheel);
+
+            if is_inside(list_position, list_dimensions, mouse_position) && input.mouse_wheel != 0.0
+            {
+                self.scroll_bar
+                    .scroll_by(-(input.mouse_wheel * self.dimensions.1 as f32) as i32);
+            }
+        }
+
+        self.hovered_option = self.option_at(mouse_position);
+
+        if input.is_key_pressed(Key::Escape) {
+            self.open = false;
+        } else if pressed {
+            if let Some(index) = self.hovered_option {
+                self.changed = self.selected != Some(index);
+                self.selected = Some(index);
+                self.open = false;
+            } else if !is_inside(list_position, list_dimensions, mouse_position) {
+                // Clicking the `ComboBox` itself also lands here, closing it.
+                self.open = false;
+            }
+        }
+    }
+
+    /// Scrolls the open list so that the selected option is at the top of it.
+    fn scroll_to_selected(&mut self) {
+        let offset = self.selected.unwrap_or(0) as i32 * self.dimensions.1;
+        self.scroll_bar.set_offset(offset);
+    }
+
+    /// Returns `true` if a different option was picked during the last
+    /// update.
+    pub fn was_changed(&self) -> bool { self.changed }
+
+    /// Gives the `ComboBox` its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`, resizing it to fit.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
+
+        self
+    }
+
+    /// Styles the `ComboBox` and the `ScrollBar` of its list using `theme`,
+    /// unless it has been given its own style with `set_style`, resizing it to
+    /// fit.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.scroll_bar.apply_theme(theme);
+
+        if !self.custom_style {
+            self.style = theme.combo_box.clone();
+            self.fit_text();
+        }
+    }
+
+    /// Returns the state the `ComboBox` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match (self.hovered, self.open) {
+            (true, _) => WidgetState::Hovered,
+            (false, true) => WidgetState::Active,
+            (false, false) => WidgetState::Default,
+        }
+    }
+
+    /// Returns the dimensions that fit the widest option of the `ComboBox`,
+    /// which layouts use as the size the `ComboBox` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the `ComboBox` to the given `new_dimensions`, which the rows of
+    /// its list match.
+    pub fn resize(&mut self, new_dimensions: Dimensions) { self.dimensions = new_dimensions; }
+
+    /// Sets the callback that is called with the index of the newly selected
+    /// option whenever a different option is picked.
+    pub fn set_on_change(&mut self, on_change: ValueCallback<T, usize>) -> &mut Self {
+        self.on_change = Some(on_change);
+
+        self
+    }
+
+    /// Calls the `ComboBox`'s callback if a different option was picked
+    /// during the last update.
+    pub fn execute_callbacks(&mut self, state: &mut T) {
+        if std::mem::take(&mut self.changed) {
+            if let (Some(on_change), Some(selected)) = (self.on_change.as_mut(), self.selected) {
+                on_change(state, selected);
+            }
+        }
+    }
+
+    /// Returns `true` if the list is open and `mouse_position` is over it,
+    /// in which case the components underneath it should not react to the
+    /// mouse.
+    pub fn is_list_hovered(&self, mouse_position: Point) -> bool {
+        let (list_position, list_dimensions) = self.list_area();
+
+        self.open && is_inside(list_position, list_dimensions, mouse_position)
+    }
+
+    /// Draws the list of options if it is open, highlighting the selected
+    /// option with the accent colour. This is drawn after every other
+    /// component so that the list covers them.
+    pub fn draw_list(&mut self, renderer: &mut dyn Renderer) {
+        if !self.open {
+            return;
+        }
+
+        self.layout_scroll_bar();
+        let (list_position, list_dimensions) = self.list_area();
+        let row_dimensions = (self.row_width(), self.dimensions.1);
+
+        renderer.push_clip(list_position, list_dimensions);
+
+        for index in 0..self.options.len() {
+            let position = self.row_position(index);
+            let state = match self.hovered_option == Some(index) {
+                true => WidgetState::Hovered,
+                false => WidgetState::Default,
+            };
+
+            if self.selected == Some(index) {
+                renderer.draw_rectangle(position, row_dimensions, self.style.accent.get(state));
+            } else {
+                renderer.draw_rectangle(position, row_dimensions, self.style.background.get(state));
+            }
+
+            draw_widget_text(
+                renderer,
+                &self.options[index],
+                position,
+                row_dimensions,
+                &self.style,
+                state,
+            );
+        }
+
+        renderer.pop_clip();
+
+        if self.scroll_bar.is_scrollable() {
+            self.scroll_bar.draw(renderer);
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for ComboBox<T> {
+    /// Draw `ComboBox` to screen, showing the selected option and an arrow.
+    /// The list of options is drawn separately by `draw_list`.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+        draw_widget_text(
+            renderer,
+            self.get_selected_text().unwrap_or(""),
+            self.position,
+            self.dimensions,
+            &self.style,
+            state,
+        );
+
+        let arrow_size = self.style.font_size / 2;
+        let arrow_centre = (
+            self.position.0 + self.dimensions.0 - self.style.padding.0 - arrow_size,
+            self.position.1 + self.dimensions.1 / 2,
+        );
+        let arrow_tip = match self.open {
+            true => -arrow_size / 2,
+            false => arrow_size / 2,
+        };
+        let colour = self.style.foreground.get(state);
+        renderer.draw_line(
+            (arrow_centre.0 - arrow_size, arrow_centre.1 - arrow_tip),
+            (arrow_centre.0, arrow_centre.1 + arrow_tip),
+            2.0,
+            colour,
+        );
+        renderer.draw_line(
+            (arrow_centre.0, arrow_centre.1 + arrow_tip),
+            (arrow_centre.0 + arrow_size, arrow_centre.1 - arrow_tip),
+            2.0,
+            colour,
+        );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
+    }
+
+    /// Checks if the `ComboBox` or an option of its open list is being
+    /// hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+        self.hovered_option = self.option_at(mouse_position);
+
+        self.hovered || self.hovered_option.is_some()
+    }
+
+    /// Checks if the `ComboBox` or an option of its open list was clicked,
+    /// returning whether or not a different option was picked.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
+        if is_clicked {
+            input = input.with_button_pressed(PointerButton::Left);
+        }
+
+        self.handle_input(&input);
+
+        self.changed
+    }
+}
+
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<ComboBox<T>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
+        let mut c = Vec::new();
+
+        for i in iter {
+            if let DrawableType::ComboBox(c_box) = i {
+                c.push(c_box)
+            }
+        }
+
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::measure_text_monospace;
+
+    /// An open `ComboBox` with more options than fit in its list.
+    fn combo_box() -> ComboBox<()> {
+        let options = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+        let mut combo_box = ComboBox::new(&options, None, (0, 0));
+        combo_box
+            .set_text_measurer(TextMeasurer::new(measure_text_monospace))
+            .set_max_visible_options(4)
+            .set_open(true);
+        combo_box.handle_input(&InputState::new());
+
+        combo_box
+    }
+
+    fn scroll_at(combo_box: &mut ComboBox<()>, mouse_position: Point) -> i32 {
+        let previous_offset = combo_box.scroll_bar.get_offset();
+        combo_box.handle_input(
+            &InputState::new()
+                .with_mouse_position(mouse_position)
+                .with_mouse_wheel(-1.0),
+        );
+
+        combo_box.scroll_bar.get_offset() - previous_offset
+    }
+
+    #[test]
+    fn wheel_scrolls_one_row_over_the_options_and_the_scroll_bar() {
+        let mut combo_box = combo_box();
+        let row_height = combo_box.dimensions.1;
+        let (list_position, list_dimensions) = combo_box.list_area();
+        let y = list_position.1 + list_dimensions.1 / 2;
+
+        assert_eq!(
+            scroll_at(&mut combo_box, (list_position.0 + 5, y)),
+            row_height
+        );
+        assert_eq!(
+            scroll_at(&mut combo_box, (list_position.0 + list_dimensions.0 - 5, y)),
+            row_height
+        );
+    }
+
+    fn click_at(combo_box: &mut ComboBox<()>, mouse_position: Point) {
+        combo_box.handle_input(
+            &InputState::new()
+                .with_mouse_position(mouse_position)
+                .with_button_pressed(PointerButton::Left),
+        );
+    }
+
+    #[test]
+    fn clicking_an_option_picks_it_and_closes_the_list() {
+        let mut combo_box = combo_box();
+        let row = combo_box.row_position(2);
+
+        click_at(&mut combo_box, (row.0 + 5, row.1 + 5));
+        assert_eq!(combo_box.get_selected(), Some(2));
+        assert_eq!(combo_box.get_selected_text(), Some("c"));
+        assert!(combo_box.was_changed());
+        assert!(!combo_box.is_open());
+
+        click_at(&mut combo_box, (5, 5));
+        assert!(combo_box.is_open());
+        assert!(!combo_box.was_changed());
+    }
+
+    #[test]
+    fn clicking_outside_or_pressing_escape_closes_the_list_without_picking() {
+        let mut combo_box = combo_box();
+
+        click_at(&mut combo_box, (500, 500));
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.get_selected(), None);
+        assert!(!combo_box.was_changed());
+
+        combo_box.set_open(true);
+        combo_box.handle_input(&InputState::new().with_key_pressed(Key::Escape));
+        assert!(!combo_box.is_open());
+        assert_eq!(combo_box.get_selected(), None);
+    }
+
+    #[test]
+    fn selecting_by_an_offset_stops_at_the_ends_and_scrolls_to_the_option() {
+        let mut combo_box = combo_box();
+        let row_height = combo_box.dimensions.1;
+
+        assert!(combo_box.select_by(-1));
+        assert_eq!(combo_box.get_selected(), Some(9));
+        assert_eq!(combo_box.scroll_bar.get_offset(), 6 * row_height);
+        assert!(!combo_box.select_by(1));
+
+        assert!(combo_box.select_by(-20));
+        assert_eq!(combo_box.get_selected(), Some(0));
+        assert_eq!(combo_box.scroll_bar.get_offset(), 0);
+    }
+}
@@ -1,5 +1,187 @@
 use crate::{gui_component::*, prelude::*};
-use raylib::prelude::*;
+
+/// Implements the methods that every component has in the same way, for a
+/// component named `$name` with `id`, `visible`, `enabled`, `dimensions` and
+/// `preferred_dimensions` fields.
+macro_rules! widget_methods {
+    ($name:ident) => {
+        #[doc = concat!("Returns the `WidgetId` of the `", stringify!($name), "`.")]
+        pub fn get_id(&self) -> $crate::prelude::WidgetId { self.id }
+
+        #[doc = concat!(
+                            "Returns whether or not the `",
+                            stringify!($name),
+                            "` is drawn and responds to input."
+                        )]
+        pub fn is_visible(&self) -> bool { self.visible }
+
+        #[doc = concat!("Shows or hides the `", stringify!($name), "`.")]
+        pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+            self.visible = visible;
+
+            self
+        }
+
+        #[doc = concat!(
+                            "Returns whether or not the `",
+                            stringify!($name),
+                            "` responds to input."
+                        )]
+        pub fn is_enabled(&self) -> bool { self.enabled }
+
+        #[doc = concat!(
+                            "Enables or disables the `",
+                            stringify!($name),
+                            "`. A disabled `",
+                            stringify!($name),
+                            "` is drawn in the disabled colours of its style and ignores input."
+                        )]
+        pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+            self.enabled = enabled;
+
+            self
+        }
+
+        #[doc = concat!(
+                            "Returns the dimensions that fit the `",
+                            stringify!($name),
+                            "`, which layouts use as the size it would like to be."
+                        )]
+        pub fn get_preferred_dimensions(&self) -> $crate::prelude::Dimensions {
+            self.preferred_dimensions
+        }
+
+        #[doc = concat!(
+                            "Resizes the `",
+                            stringify!($name),
+                            "` to the given `new_dimensions`."
+                        )]
+        pub fn resize(&mut self, new_dimensions: $crate::prelude::Dimensions) {
+            self.dimensions = new_dimensions;
+        }
+    };
+}
+pub(crate) use widget_methods;
+
+/// Implements `set_text_measurer`, `set_style` and `apply_theme` for a
+/// component named `$name` that is styled by the `$theme` field of a `Theme`.
+/// If `$fit` is given, that method is called to resize the component whenever
+/// its text is measured differently or its style changes.
+macro_rules! widget_style_methods {
+    (@resizes) => {
+        ""
+    };
+    (@resizes $fit:ident) => {
+        ", resizing it to fit"
+    };
+    ($name:ident, $theme:ident $(, $fit:ident)?) => {
+        #[doc = concat!(
+            "Sets the function used to measure the width of the text of the `",
+            stringify!($name),
+            "`",
+            $crate::common::widget_style_methods!(@resizes $($fit)?),
+            "."
+        )]
+        pub fn set_text_measurer(
+            &mut self,
+            text_measurer: $crate::prelude::TextMeasurer,
+        ) -> &mut Self {
+            self.text_measurer = text_measurer;
+            $(self.$fit();)?
+
+            self
+        }
+
+        #[doc = concat!(
+            "Gives the `",
+            stringify!($name),
+            "` its own `style`, which is used instead of the theme of the `GuiHandler`",
+            $crate::common::widget_style_methods!(@resizes $($fit)?),
+            "."
+        )]
+        pub fn set_style(&mut self, style: $crate::prelude::WidgetStyle) -> &mut Self {
+            self.style = style;
+            self.custom_style = true;
+            $(self.$fit();)?
+
+            self
+        }
+
+        #[doc = concat!(
+            "Styles the `",
+            stringify!($name),
+            "` using `theme`, unless it has been given its own style with `set_style`",
+            $crate::common::widget_style_methods!(@resizes $($fit)?),
+            "."
+        )]
+        pub fn apply_theme(&mut self, theme: &$crate::prelude::Theme) {
+            if !self.custom_style {
+                self.style = theme.$theme.clone();
+                $(self.$fit();)?
+            }
+        }
+    };
+}
+pub(crate) use widget_style_methods;
+
+/// Implements `set_on_change` and `execute_callbacks` for a component named
+/// `$name` with `changed` and `on_change` fields, whose callback is called
+/// with the `$value` of the component, taken from it by `|$this| $value`,
+/// after it changes.
+macro_rules! widget_on_change {
+    ($name:ident, $value_type:ty, |$this:ident| $value:expr) => {
+        #[doc = concat!(
+                                    "Sets the callback that is called with the new value of the `",
+                                    stringify!($name),
+                                    "` whenever it changes."
+                                )]
+        pub fn set_on_change(
+            &mut self,
+            on_change: $crate::prelude::ValueCallback<T, $value_type>,
+        ) -> &mut Self {
+            self.on_change = Some(on_change);
+
+            self
+        }
+
+        #[doc = concat!(
+                                    "Calls the callback of the `",
+                                    stringify!($name),
+                                    "` if it changed during the last update."
+                                )]
+        pub fn execute_callbacks(&mut self, state: &mut T) {
+            if std::mem::take(&mut self.changed) {
+                let $this = &*self;
+                let value = $value;
+
+                if let Some(on_change) = self.on_change.as_mut() {
+                    on_change(state, value);
+                }
+            }
+        }
+    };
+}
+pub(crate) use widget_on_change;
+
+/// Implements collecting the components of a `Vec<DrawableType<T, E>>` that
+/// are `DrawableType::$variant`s into a `Vec<$widget>`.
+macro_rules! widget_from_drawables {
+    ($widget:ty, $variant:ident) => {
+        impl<T, E> std::iter::FromIterator<$crate::common::DrawableType<T, E>> for Vec<$widget> {
+            fn from_iter<I: IntoIterator<Item = $crate::common::DrawableType<T, E>>>(
+                iter: I,
+            ) -> Self {
+                iter.into_iter()
+                    .filter_map(|component| match component {
+                        $crate::common::DrawableType::$variant(widget) => Some(widget),
+                        _ => None,
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+pub(crate) use widget_from_drawables;
 
 pub fn is_inside(position: Point, dimensions: Dimensions, mouse_position: Point) -> bool {
     let rect_points = [
@@ -15,59 +197,912 @@ pub fn is_inside(position: Point, dimensions: Dimensions, mouse_position: Point)
         && (mouse_position.1 < rect_points[2].1 && mouse_position.1 < rect_points[3].1)
 }
 
+fn fill_rectangle(
+    renderer: &mut dyn Renderer,
+    position: Point,
+    dimensions: Dimensions,
+    corner_radius: i32,
+    colour: Colour,
+) {
+    if corner_radius > 0 {
+        renderer.draw_rounded_rectangle(position, dimensions, corner_radius, colour);
+    } else {
+        renderer.draw_rectangle(position, dimensions, colour);
+    }
+}
+
+/// Draws the background of a component, along with its border if the `style`
+/// has one.
+pub fn draw_background(
+    renderer: &mut dyn Renderer,
+    position: Point,
+    dimensions: Dimensions,
+    style: &WidgetStyle,
+    state: WidgetState,
+) {
+    let background = style.background.get(state);
+
+    if style.border_width <= 0 {
+        fill_rectangle(
+            renderer,
+            position,
+            dimensions,
+            style.corner_radius,
+            background,
+        );
+        return;
+    }
+
+    let border = style.border_width;
+    fill_rectangle(
+        renderer,
+        position,
+        dimensions,
+        style.corner_radius,
+        style.border.get(state),
+    );
+    fill_rectangle(
+        renderer,
+        (position.0 + border, position.1 + border),
+        (dimensions.0 - border * 2, dimensions.1 - border * 2),
+        (style.corner_radius - border).max(0),
+        background,
+    );
+}
+
+/// Draws the `text` of a component, inset from its left edge by the padding
+/// of the `style` and centred vertically.
+pub fn draw_widget_text(
+    renderer: &mut dyn Renderer,
+    text: &str,
+    position: Point,
+    dimensions: Dimensions,
+    style: &WidgetStyle,
+    state: WidgetState,
+) {
+    renderer.draw_text(
+        text,
+        (
+            position.0 + style.padding.0,
+            position.1 + (dimensions.1 - style.font_size) / 2,
+        ),
+        style.font_size,
+        style.text.get(state),
+    );
+}
+
+/// Draws an outline just outside of a component that has keyboard or gamepad
+/// focus, in the focused border colour of its `style`.
+pub fn draw_focus_outline(
+    renderer: &mut dyn Renderer,
+    position: Point,
+    dimensions: Dimensions,
+    style: &WidgetStyle,
+) {
+    renderer.draw_rectangle_lines(
+        (position.0 - 2, position.1 - 2),
+        (dimensions.0 + 4, dimensions.1 + 4),
+        2,
+        style.border.get(WidgetState::Focused),
+    );
+}
+
+/// Returns the state a component is drawn in when nothing is happening to it,
+/// which is `WidgetState::Disabled` if it is not `enabled`.
+pub fn resting_state(enabled: bool) -> WidgetState {
+    match enabled {
+        true => WidgetState::Default,
+        false => WidgetState::Disabled,
+    }
+}
+
 /// Allows for the various components in `ptgui` to be contained within a single
 /// collection rather than having seperate collections for each component that
-/// can be drawn by the `GuiHandler<T>`.
-#[derive(PartialEq)]
-pub enum DrawableType {
-    Button(Button),
-    Slider(Slider),
-    Dropdown(Dropdown),
-    Label(Label),
-}
-
-impl DrawableType {
-    pub fn draw(&mut self, draw_handler: &mut RaylibDrawHandle) {
-        match self {
-            DrawableType::Button(b) => b.draw(draw_handler),
-            DrawableType::Slider(s) => s.draw(draw_handler),
-            DrawableType::Dropdown(d) => d.draw(draw_handler),
-            DrawableType::Label(l) => l.draw(draw_handler),
-        }
-    }
-
-    pub fn is_clicked(
-        &mut self,
-        mouse_position: Point,
-        actions: &mut Vec<String>,
-        draw_handler: &RaylibDrawHandle,
-    ) {
-        match self {
-            DrawableType::Button(b) => actions.push(b.is_clicked(
-                mouse_position,
-                draw_handler.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON),
-            )),
-            DrawableType::Slider(s) => s.is_clicked(
-                mouse_position,
-                draw_handler.is_mouse_button_down(MouseButton::MOUSE_LEFT_BUTTON),
-            ),
+/// can be drawn by the `GuiHandler<T, E>`.
+pub enum DrawableType<T, E> {
+    Button(Button<T, E>),
+    Slider(Slider<T>),
+    RangeSlider(RangeSlider<T>),
+    Dropdown(Dropdown<T, E>),
+    Label(Label<T>),
+    TextBox(TextBox<T>),
+    Checkbox(Checkbox<T>),
+    Toggle(Toggle<T>),
+    RadioGroup(RadioGroup<T>),
+    ComboBox(ComboBox<T>),
+    ScrollBar(ScrollBar),
+    ProgressBar(ProgressBar),
+    ScrollPanel(ScrollPanel<T, E>),
+}
+
+/// Updates each of the visible `components` with the given `input`, pushing
+/// the events they produce onto `events`.
+pub fn update_components<T, E: Clone>(
+    components: &mut [DrawableType<T, E>],
+    input: &InputState,
+    events: &mut Vec<GuiEvent<E>>,
+) {
+    // Components underneath a popup, such as the open list of a `ComboBox`,
+    // and components that do not hold the pointer capture are given a mouse
+    // that is nowhere near them.
+    let popup = components
+        .iter()
+        .position(|c| c.is_visible() && c.is_popup_hovered(input.mouse_position));
+    let covered = input.clone().with_mouse_position((i32::MIN, i32::MIN));
+    // Disabled components are still animated, but are given no input at all.
+    let disabled = InputState {
+        frame_time: input.frame_time,
+        screen_size: input.screen_size,
+        ..InputState::new()
+    }
+    .with_mouse_position((i32::MIN, i32::MIN));
+
+    for (index, component) in components.iter_mut().enumerate() {
+        if !component.is_visible() {
+            continue;
+        }
+
+        let input = match (input.pointer_capture, popup) {
+            _ if !component.is_enabled() => &disabled,
+            (Some(captured), _) => match component.contains(captured) {
+                true => input,
+                false => &covered,
+            },
+            (None, Some(popup)) if popup != index => &covered,
+            _ => input,
+        };
+
+        component.is_hovered(input.mouse_position);
+        component.is_clicked(input, events);
+    }
+}
+
+/// Draws each of the visible `components`, followed by the popups of any of
+/// them so that the popups cover every component.
+pub fn draw_components<T, E: Clone>(
+    components: &mut [DrawableType<T, E>],
+    renderer: &mut dyn Renderer,
+) {
+    for component in components.iter_mut() {
+        component.draw(renderer);
+    }
+
+    for component in components.iter_mut() {
+        component.draw_popup(renderer);
+    }
+}
+
+/// Finds the component with the given `id` in `components`, including any
+/// components nested inside of a `Dropdown` or `ScrollPanel`.
+pub fn find_component<T, E>(
+    components: &[DrawableType<T, E>],
+    id: WidgetId,
+) -> Option<&DrawableType<T, E>> {
+    for component in components.iter() {
+        if component.get_id() == id {
+            return Some(component);
+        }
+
+        let nested = match component {
+            DrawableType::Dropdown(d) => d.get_components(),
+            DrawableType::ScrollPanel(p) => p.get_components(),
+            _ => continue,
+        };
+
+        if let Some(found) = find_component(nested, id) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Finds the component with the given `id` in `components`, including any
+/// components nested inside of a `Dropdown` or `ScrollPanel`.
+pub fn find_component_mut<T, E>(
+    components: &mut [DrawableType<T, E>],
+    id: WidgetId,
+) -> Option<&mut DrawableType<T, E>> {
+    for component in components.iter_mut() {
+        if component.get_id() == id {
+            return Some(component);
+        }
+
+        let nested = match component {
+            DrawableType::Dropdown(d) => d.get_components_mut(),
+            DrawableType::ScrollPanel(p) => p.get_components_mut(),
+            _ => continue,
+        };
+
+        if let Some(found) = find_component_mut(nested, id) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Removes the component with the given `id` from `components`, including any
+/// components nested inside of a `Dropdown` or `ScrollPanel`, returning it if
+/// it was found.
+pub fn remove_component<T, E>(
+    components: &mut Vec<DrawableType<T, E>>,
+    id: WidgetId,
+) -> Option<DrawableType<T, E>> {
+    if let Some(index) = components.iter().position(|c| c.get_id() == id) {
+        return Some(components.remove(index));
+    }
+
+    for component in components.iter_mut() {
+        let removed = match component {
+            DrawableType::Dropdown(d) => d.remove(id),
+            DrawableType::ScrollPanel(p) => p.remove(id),
+            _ => None,
+        };
+
+        if removed.is_some() {
+            return removed;
+        }
+    }
+
+    None
+}
+
+/// Returns the id of the innermost of the visible `components` at
+/// `mouse_position`, including the open list of a `ComboBox` and the
+/// components of an open `Dropdown`.
+pub fn find_component_at<T, E: Clone>(
+    components: &[DrawableType<T, E>],
+    mouse_position: Point,
+) -> Option<WidgetId> {
+    let mut visible = components.iter().rev().filter(|c| c.is_visible());
+
+    // Popups cover every component, so they are checked first.
+    match visible.clone().find(|c| c.is_popup_hovered(mouse_position)) {
+        Some(popup) => popup.component_at(mouse_position),
+        None => visible.find_map(|c| c.component_at(mouse_position)),
+    }
+}
+
+/// Returns `true` if `mouse_position` is over any of the visible
+/// `components`, including the open list of a `ComboBox` and the components of
+/// an open `Dropdown`.
+pub fn is_mouse_over_components<T, E: Clone>(
+    components: &[DrawableType<T, E>],
+    mouse_position: Point,
+) -> bool {
+    find_component_at(components, mouse_position).is_some()
+}
+
+/// Returns `true` if any of the visible `components` is a `Dropdown` or
+/// `ComboBox` that is open, including those inside of another component.
+pub fn has_open_popup<T, E>(components: &[DrawableType<T, E>]) -> bool {
+    components.iter().any(|c| {
+        c.is_visible()
+            && match c {
+                DrawableType::Dropdown(d) => d.is_open() || has_open_popup(d.get_components()),
+                DrawableType::ComboBox(c) => c.is_open(),
+                DrawableType::ScrollPanel(p) => has_open_popup(p.get_components()),
+                _ => false,
+            }
+    })
+}
+
+/// Returns `true` if any of the visible `components` is a `TextBox` that is
+/// being typed into, including those inside of another component.
+pub fn has_editing_text_box<T, E>(components: &[DrawableType<T, E>]) -> bool {
+    components.iter().any(|c| {
+        c.is_visible()
+            && match c {
+                DrawableType::TextBox(t) => t.is_editing(),
+                DrawableType::Dropdown(d) =>
+                    d.is_open() && has_editing_text_box(d.get_components()),
+                DrawableType::ScrollPanel(p) => has_editing_text_box(p.get_components()),
+                _ => false,
+            }
+    })
+}
+
+/// Adds the ids of the visible `components` that can be focused to `order`,
+/// in the order that focus moves through them. The components of an open
+/// `Dropdown` or a `ScrollPanel` come straight after it, unless it is
+/// disabled.
+pub fn collect_focus_order<T, E>(components: &[DrawableType<T, E>], order: &mut Vec<WidgetId>) {
+    for component in components.iter() {
+        collect_component_focus_order(component, order);
+    }
+}
+
+/// Adds the id of `component` to `order` if it can be focused, followed by
+/// those of its components if it is an open `Dropdown` or a `ScrollPanel`.
+pub fn collect_component_focus_order<T, E>(
+    component: &DrawableType<T, E>,
+    order: &mut Vec<WidgetId>,
+) {
+    if !component.is_visible() || !component.is_enabled() {
+        return;
+    }
+
+    if component.is_focusable() {
+        order.push(component.get_id());
+    }
+
+    match component {
+        DrawableType::Dropdown(d) if d.is_open() => collect_focus_order(d.get_components(), order),
+        DrawableType::ScrollPanel(p) => collect_focus_order(p.get_components(), order),
+        _ => (),
+    }
+}
+
+/// Returns the id of the innermost `Dropdown` in `components` that the
+/// component with the given `id` was added to, if there is one.
+pub fn find_parent_dropdown<T, E>(
+    components: &[DrawableType<T, E>],
+    id: WidgetId,
+) -> Option<WidgetId> {
+    for component in components.iter() {
+        let (nested, dropdown) = match component {
+            DrawableType::Dropdown(d) => (d.get_components(), Some(d.get_id())),
+            DrawableType::ScrollPanel(p) => (p.get_components(), None),
+            _ => continue,
+        };
+
+        if let Some(found) = find_parent_dropdown(nested, id) {
+            return Some(found);
+        }
+        if nested.iter().any(|c| c.get_id() == id) {
+            if let Some(dropdown) = dropdown {
+                return Some(dropdown);
+            }
+        }
+    }
+
+    None
+}
+
+/// Scrolls every `ScrollPanel` in `components` that the component with the
+/// given `id` is inside of, including those of a `Dropdown`, so that the
+/// component can be seen.
+pub fn scroll_into_view<T, E>(components: &mut [DrawableType<T, E>], id: WidgetId) {
+    for component in components.iter_mut() {
+        let panel = match component {
+            DrawableType::Dropdown(d) => d.get_panel_mut(),
+            DrawableType::ScrollPanel(p) => p,
+            _ => continue,
+        };
+
+        if find_component(panel.get_components(), id).is_none() {
+            continue;
+        }
+
+        scroll_into_view(panel.get_components_mut(), id);
+
+        // A component nested further in is scrolled to through the component
+        // of this panel that holds it.
+        let holder = panel
+            .get_components()
+            .iter()
+            .find(|c| find_component(std::slice::from_ref(*c), id).is_some())
+            .map(|c| c.get_id());
+        if let Some(holder) = holder {
+            panel.scroll_into_view(holder);
+        }
+    }
+}
+
+impl<T, E> DrawableType<T, E> {
+    pub fn get_id(&self) -> WidgetId {
+        match self {
+            DrawableType::Button(b) => b.get_id(),
+            DrawableType::Slider(s) => s.get_id(),
+            DrawableType::RangeSlider(r) => r.get_id(),
+            DrawableType::Dropdown(d) => d.get_id(),
+            DrawableType::Label(l) => l.get_id(),
+            DrawableType::TextBox(t) => t.get_id(),
+            DrawableType::Checkbox(c) => c.get_id(),
+            DrawableType::Toggle(t) => t.get_id(),
+            DrawableType::RadioGroup(r) => r.get_id(),
+            DrawableType::ComboBox(c) => c.get_id(),
+            DrawableType::ScrollBar(s) => s.get_id(),
+            DrawableType::ProgressBar(p) => p.get_id(),
+            DrawableType::ScrollPanel(p) => p.get_id(),
+        }
+    }
+
+    /// Returns `true` if this is the component with the given `id`, or it
+    /// contains it inside of a `Dropdown` or `ScrollPanel`.
+    pub fn contains(&self, id: WidgetId) -> bool {
+        find_component(std::slice::from_ref(self), id).is_some()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        match self {
+            DrawableType::Button(b) => b.is_visible(),
+            DrawableType::Slider(s) => s.is_visible(),
+            DrawableType::RangeSlider(r) => r.is_visible(),
+            DrawableType::Dropdown(d) => d.is_visible(),
+            DrawableType::Label(l) => l.is_visible(),
+            DrawableType::TextBox(t) => t.is_visible(),
+            DrawableType::Checkbox(c) => c.is_visible(),
+            DrawableType::Toggle(t) => t.is_visible(),
+            DrawableType::RadioGroup(r) => r.is_visible(),
+            DrawableType::ComboBox(c) => c.is_visible(),
+            DrawableType::ScrollBar(s) => s.is_visible(),
+            DrawableType::ProgressBar(p) => p.is_visible(),
+            DrawableType::ScrollPanel(p) => p.is_visible(),
+        }
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_visible(visible);
+            },
+            DrawableType::Slider(s) => {
+                s.set_visible(visible);
+            },
+            DrawableType::RangeSlider(r) => {
+                r.set_visible(visible);
+            },
+            DrawableType::Dropdown(d) => {
+                d.set_visible(visible);
+            },
+            DrawableType::Label(l) => {
+                l.set_visible(visible);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_visible(visible);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_visible(visible);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_visible(visible);
+            },
+            DrawableType::RadioGroup(r) => {
+                r.set_visible(visible);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_visible(visible);
+            },
+            DrawableType::ScrollBar(s) => {
+                s.set_visible(visible);
+            },
+            DrawableType::ProgressBar(p) => {
+                p.set_visible(visible);
+            },
+            DrawableType::ScrollPanel(p) => {
+                p.set_visible(visible);
+            },
+        }
+    }
+
+    /// Returns `true` if the component responds to input.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            DrawableType::Button(b) => b.is_enabled(),
+            DrawableType::Slider(s) => s.is_enabled(),
+            DrawableType::RangeSlider(r) => r.is_enabled(),
+            DrawableType::Dropdown(d) => d.is_enabled(),
+            DrawableType::Label(l) => l.is_enabled(),
+            DrawableType::TextBox(t) => t.is_enabled(),
+            DrawableType::Checkbox(c) => c.is_enabled(),
+            DrawableType::Toggle(t) => t.is_enabled(),
+            DrawableType::RadioGroup(r) => r.is_enabled(),
+            DrawableType::ComboBox(c) => c.is_enabled(),
+            DrawableType::ScrollBar(s) => s.is_enabled(),
+            DrawableType::ProgressBar(p) => p.is_enabled(),
+            DrawableType::ScrollPanel(p) => p.is_enabled(),
+        }
+    }
+
+    /// Enables or disables the component. Disabled components are drawn in
+    /// their disabled colours, ignore input and cannot be focused.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_enabled(enabled);
+            },
+            DrawableType::Slider(s) => {
+                s.set_enabled(enabled);
+            },
+            DrawableType::RangeSlider(r) => {
+                r.set_enabled(enabled);
+            },
+            DrawableType::Dropdown(d) => {
+                d.set_enabled(enabled);
+            },
+            DrawableType::Label(l) => {
+                l.set_enabled(enabled);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_enabled(enabled);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_enabled(enabled);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_enabled(enabled);
+            },
+            DrawableType::RadioGroup(r) => {
+                r.set_enabled(enabled);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_enabled(enabled);
+            },
+            DrawableType::ScrollBar(s) => {
+                s.set_enabled(enabled);
+            },
+            DrawableType::ProgressBar(p) => {
+                p.set_enabled(enabled);
+            },
+            DrawableType::ScrollPanel(p) => {
+                p.set_enabled(enabled);
+            },
+        }
+    }
+
+    /// Returns `true` if the component can be given keyboard or gamepad focus,
+    /// which every enabled component that responds to being clicked can
+    /// except for a `Label`, along with a `ScrollPanel` that has something to
+    /// scroll.
+    pub fn is_focusable(&self) -> bool {
+        self.is_enabled()
+            && match self {
+                DrawableType::Button(_)
+                | DrawableType::Slider(_)
+                | DrawableType::RangeSlider(_)
+                | DrawableType::Dropdown(_)
+                | DrawableType::TextBox(_)
+                | DrawableType::Checkbox(_)
+                | DrawableType::Toggle(_)
+                | DrawableType::RadioGroup(_)
+                | DrawableType::ComboBox(_) => true,
+                DrawableType::ScrollPanel(p) => p.is_scrollable(),
+                DrawableType::Label(_)
+                | DrawableType::ScrollBar(_)
+                | DrawableType::ProgressBar(_) => false,
+            }
+    }
+
+    /// Gives or takes away keyboard or gamepad focus, doing nothing for a
+    /// component that cannot be focused. Focusing a `TextBox` does not let it
+    /// be typed into until it is activated or clicked.
+    pub fn set_focused(&mut self, focused: bool) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_focused(focused);
+            },
+            DrawableType::Slider(s) => {
+                s.set_focused(focused);
+            },
+            DrawableType::RangeSlider(r) => {
+                r.set_focused(focused);
+            },
+            DrawableType::Dropdown(d) => {
+                d.set_focused(focused);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_focused(focused);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_focused(focused);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_focused(focused);
+            },
+            DrawableType::RadioGroup(r) => {
+                r.set_focused(focused);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_focused(focused);
+            },
+            DrawableType::ScrollPanel(p) => {
+                p.set_focused(focused);
+            },
+            DrawableType::Label(_) | DrawableType::ScrollBar(_) | DrawableType::ProgressBar(_) =>
+                (),
+        }
+    }
+
+    /// Returns `true` if the component is moved along by the analog stick
+    /// while it is focused, rather than the stick moving focus left and right.
+    pub fn is_adjustable(&self) -> bool {
+        matches!(self, DrawableType::Slider(_) | DrawableType::RangeSlider(_))
+    }
+
+    /// Sets the text of a `Button`, `Checkbox`, `Dropdown`, `Label`,
+    /// `TextBox` or `Toggle`, doing nothing for any other component.
+    pub fn set_text(&mut self, text: &str) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_text(text);
+            },
             DrawableType::Dropdown(d) => {
-                d.is_clicked(
-                    mouse_position,
-                    draw_handler.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON),
-                );
-                actions.append(&mut d.actions)
+                d.set_text(text);
+            },
+            DrawableType::Label(l) => {
+                l.set_text(text);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_text(text);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_text(text);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_text(text);
+            },
+            DrawableType::Slider(_)
+            | DrawableType::RangeSlider(_)
+            | DrawableType::RadioGroup(_)
+            | DrawableType::ComboBox(_)
+            | DrawableType::ScrollBar(_)
+            | DrawableType::ProgressBar(_)
+            | DrawableType::ScrollPanel(_) => (),
+        }
+    }
+
+    pub fn set_position(&mut self, new_position: Point) {
+        match self {
+            DrawableType::Button(b) => b.position = new_position,
+            DrawableType::Slider(s) => s.move_to(new_position),
+            DrawableType::RangeSlider(r) => r.move_to(new_position),
+            DrawableType::Dropdown(d) => d.move_to(new_position),
+            DrawableType::Label(l) => l.position = new_position,
+            DrawableType::TextBox(t) => t.position = new_position,
+            DrawableType::Checkbox(c) => c.position = new_position,
+            DrawableType::Toggle(t) => t.position = new_position,
+            DrawableType::RadioGroup(r) => r.position = new_position,
+            DrawableType::ComboBox(c) => c.position = new_position,
+            DrawableType::ScrollBar(s) => s.position = new_position,
+            DrawableType::ProgressBar(p) => p.position = new_position,
+            DrawableType::ScrollPanel(p) => p.move_to(new_position),
+        }
+    }
+
+    pub fn execute_callbacks(&mut self, state: &mut T, button_action: Option<Action<T, E>>) {
+        if !self.is_visible() {
+            return;
+        }
+
+        match self {
+            DrawableType::Button(b) => b.execute_callbacks(state, button_action),
+            DrawableType::Slider(s) => s.execute_callbacks(state),
+            DrawableType::RangeSlider(r) => r.execute_callbacks(state),
+            DrawableType::Dropdown(d) => d.execute_callbacks(state, button_action),
+            DrawableType::Label(l) => l.execute_callbacks(state),
+            DrawableType::TextBox(t) => t.execute_callbacks(state),
+            DrawableType::Checkbox(c) => c.execute_callbacks(state),
+            DrawableType::Toggle(t) => t.execute_callbacks(state),
+            DrawableType::RadioGroup(r) => r.execute_callbacks(state),
+            DrawableType::ComboBox(c) => c.execute_callbacks(state),
+            DrawableType::ScrollBar(_) | DrawableType::ProgressBar(_) => (),
+            DrawableType::ScrollPanel(p) => p.execute_callbacks(state, button_action),
+        }
+    }
+
+    /// Sets the click callback of a `Button` or `Label`, doing nothing for
+    /// any other component.
+    pub fn set_on_click(&mut self, on_click: Callback<T>) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_on_click(on_click);
+            },
+            DrawableType::Label(l) => {
+                l.set_on_click(on_click);
             },
             _ => (),
         }
     }
 
+    /// Sets the change callback of a `Slider`, doing nothing for any other
+    /// component.
+    pub fn set_on_change(&mut self, on_change: ValueCallback<T, f32>) {
+        if let DrawableType::Slider(s) = self {
+            s.set_on_change(on_change);
+        }
+    }
+
+    /// Sets the change callback of a `RangeSlider`, doing nothing for any
+    /// other component.
+    pub fn set_on_range_change(&mut self, on_range_change: ValueCallback<T, (f32, f32)>) {
+        if let DrawableType::RangeSlider(r) = self {
+            r.set_on_change(on_range_change);
+        }
+    }
+
+    /// Sets the change callback of a `Checkbox`, doing nothing for any other
+    /// component.
+    pub fn set_on_check(&mut self, on_check: ValueCallback<T, CheckState>) {
+        if let DrawableType::Checkbox(c) = self {
+            c.set_on_change(on_check);
+        }
+    }
+
+    /// Sets the change callback of a `Toggle`, doing nothing for any other
+    /// component.
+    pub fn set_on_toggle(&mut self, on_toggle: ValueCallback<T, bool>) {
+        if let DrawableType::Toggle(t) = self {
+            t.set_on_change(on_toggle);
+        }
+    }
+
+    /// Sets the change callback of a `RadioGroup` or `ComboBox`, doing
+    /// nothing for any other component.
+    pub fn set_on_select(&mut self, on_select: ValueCallback<T, usize>) {
+        match self {
+            DrawableType::RadioGroup(r) => {
+                r.set_on_change(on_select);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_on_change(on_select);
+            },
+            _ => (),
+        }
+    }
+
+    /// Sets the submit callback of a `TextBox`, doing nothing for any other
+    /// component.
+    pub fn set_on_submit(&mut self, on_submit: ValueCallback<T, String>) {
+        if let DrawableType::TextBox(t) = self {
+            t.set_on_submit(on_submit);
+        }
+    }
+
+    /// Sets the open callback of a `Dropdown`, doing nothing for any other
+    /// component.
+    pub fn set_on_open(&mut self, on_open: ValueCallback<T, bool>) {
+        if let DrawableType::Dropdown(d) = self {
+            d.set_on_open(on_open);
+        }
+    }
+
     pub fn get_position(&self) -> Point {
         match self {
             DrawableType::Button(b) => b.position,
             DrawableType::Slider(s) => s.position,
+            DrawableType::RangeSlider(r) => r.position,
             DrawableType::Dropdown(d) => d.position,
             DrawableType::Label(l) => l.position,
+            DrawableType::TextBox(t) => t.position,
+            DrawableType::Checkbox(c) => c.position,
+            DrawableType::Toggle(t) => t.position,
+            DrawableType::RadioGroup(r) => r.position,
+            DrawableType::ComboBox(c) => c.position,
+            DrawableType::ScrollBar(s) => s.position,
+            DrawableType::ProgressBar(p) => p.position,
+            DrawableType::ScrollPanel(p) => p.position,
+        }
+    }
+
+    /// Styles the component using `theme`, unless it has been given its own
+    /// style.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        match self {
+            DrawableType::Button(b) => b.apply_theme(theme),
+            DrawableType::Slider(s) => s.apply_theme(theme),
+            DrawableType::RangeSlider(r) => r.apply_theme(theme),
+            DrawableType::Dropdown(d) => d.apply_theme(theme),
+            DrawableType::Label(l) => l.apply_theme(theme),
+            DrawableType::TextBox(t) => t.apply_theme(theme),
+            DrawableType::Checkbox(c) => c.apply_theme(theme),
+            DrawableType::Toggle(t) => t.apply_theme(theme),
+            DrawableType::RadioGroup(r) => r.apply_theme(theme),
+            DrawableType::ComboBox(c) => c.apply_theme(theme),
+            DrawableType::ScrollBar(s) => s.apply_theme(theme),
+            DrawableType::ProgressBar(p) => p.apply_theme(theme),
+            DrawableType::ScrollPanel(p) => p.apply_theme(theme),
+        }
+    }
+
+    /// Sets the function used to measure the width of the text of the
+    /// component, resizing it to fit if it is sized by its text.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_text_measurer(text_measurer);
+            },
+            DrawableType::Slider(s) => {
+                s.set_text_measurer(text_measurer);
+            },
+            DrawableType::RangeSlider(r) => {
+                r.set_text_measurer(text_measurer);
+            },
+            DrawableType::Dropdown(d) => {
+                d.set_text_measurer(text_measurer);
+            },
+            DrawableType::Label(l) => {
+                l.set_text_measurer(text_measurer);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_text_measurer(text_measurer);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_text_measurer(text_measurer);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_text_measurer(text_measurer);
+            },
+            DrawableType::RadioGroup(r) => {
+                r.set_text_measurer(text_measurer);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_text_measurer(text_measurer);
+            },
+            DrawableType::ScrollBar(_) => (),
+            DrawableType::ProgressBar(p) => {
+                p.set_text_measurer(text_measurer);
+            },
+            DrawableType::ScrollPanel(p) => {
+                p.set_text_measurer(text_measurer);
+            },
+        }
+    }
+
+    /// Gives the component its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`.
+    pub fn set_style(&mut self, style: WidgetStyle) {
+        match self {
+            DrawableType::Button(b) => {
+                b.set_style(style);
+            },
+            DrawableType::Slider(s) => {
+                s.set_style(style);
+            },
+            DrawableType::RangeSlider(r) => {
+                r.set_style(style);
+            },
+            DrawableType::Dropdown(d) => {
+                d.set_style(style);
+            },
+            DrawableType::Label(l) => {
+                l.set_style(style);
+            },
+            DrawableType::TextBox(t) => {
+                t.set_style(style);
+            },
+            DrawableType::Checkbox(c) => {
+                c.set_style(style);
+            },
+            DrawableType::Toggle(t) => {
+                t.set_style(style);
+            },
+            DrawableType::RadioGroup(r) => {
+                r.set_style(style);
+            },
+            DrawableType::ComboBox(c) => {
+                c.set_style(style);
+            },
+            DrawableType::ScrollBar(s) => {
+                s.set_style(style);
+            },
+            DrawableType::ProgressBar(p) => {
+                p.set_style(style);
+            },
+            DrawableType::ScrollPanel(p) => {
+                p.set_style(style);
+            },
+        }
+    }
+
+    /// Returns the dimensions the component would like to be, which is what
+    /// a `Layout` sizes it to before applying any limits or alignment.
+    pub fn get_preferred_dimensions(&self) -> Dimensions {
+        match self {
+            DrawableType::Button(b) => b.get_preferred_dimensions(),
+            DrawableType::Slider(s) => s.get_preferred_dimensions(),
+            DrawableType::RangeSlider(r) => r.get_preferred_dimensions(),
+            DrawableType::Dropdown(d) => d.get_preferred_dimensions(),
+            DrawableType::Label(l) => l.get_preferred_dimensions(),
+            DrawableType::TextBox(t) => t.get_preferred_dimensions(),
+            DrawableType::Checkbox(c) => c.get_preferred_dimensions(),
+            DrawableType::Toggle(t) => t.get_preferred_dimensions(),
+            DrawableType::RadioGroup(r) => r.get_preferred_dimensions(),
+            DrawableType::ComboBox(c) => c.get_preferred_dimensions(),
+            DrawableType::ScrollBar(s) => s.get_preferred_dimensions(),
+            DrawableType::ProgressBar(p) => p.get_preferred_dimensions(),
+            DrawableType::ScrollPanel(p) => p.get_preferred_dimensions(),
         }
     }
 
@@ -75,8 +1110,17 @@ impl DrawableType {
         match self {
             DrawableType::Button(b) => b.dimensions,
             DrawableType::Slider(s) => s.dimensions,
+            DrawableType::RangeSlider(r) => r.dimensions,
             DrawableType::Dropdown(d) => d.dimensions,
             DrawableType::Label(l) => l.dimensions,
+            DrawableType::TextBox(t) => t.dimensions,
+            DrawableType::Checkbox(c) => c.dimensions,
+            DrawableType::Toggle(t) => t.dimensions,
+            DrawableType::RadioGroup(r) => r.dimensions,
+            DrawableType::ComboBox(c) => c.dimensions,
+            DrawableType::ScrollBar(s) => s.dimensions,
+            DrawableType::ProgressBar(p) => p.dimensions,
+            DrawableType::ScrollPanel(p) => p.dimensions,
         }
     }
 
@@ -84,8 +1128,17 @@ impl DrawableType {
         match self {
             DrawableType::Button(b) => b.resize(new_dimensions),
             DrawableType::Slider(s) => s.resize(new_dimensions),
+            DrawableType::RangeSlider(r) => r.resize(new_dimensions),
             DrawableType::Dropdown(d) => d.resize(new_dimensions),
             DrawableType::Label(l) => l.resize(new_dimensions),
+            DrawableType::TextBox(t) => t.resize(new_dimensions),
+            DrawableType::Checkbox(c) => c.resize(new_dimensions),
+            DrawableType::Toggle(t) => t.resize(new_dimensions),
+            DrawableType::RadioGroup(r) => r.resize(new_dimensions),
+            DrawableType::ComboBox(c) => c.resize(new_dimensions),
+            DrawableType::ScrollBar(s) => s.resize(new_dimensions),
+            DrawableType::ProgressBar(p) => p.resize(new_dimensions),
+            DrawableType::ScrollPanel(p) => p.resize(new_dimensions),
         }
     }
 
@@ -99,14 +1152,484 @@ impl DrawableType {
                 if s.position.0 != new_x_pos {
                     s.move_x(new_x_pos);
                 },
+            DrawableType::RangeSlider(r) =>
+                if r.position.0 != new_x_pos {
+                    r.move_x(new_x_pos);
+                },
             DrawableType::Dropdown(d) =>
                 if d.position.0 != new_x_pos {
-                    d.position.0 = new_x_pos;
+                    d.move_to((new_x_pos, d.position.1));
                 },
             DrawableType::Label(l) =>
                 if l.position.0 != new_x_pos {
                     l.position.0 = new_x_pos;
                 },
+            DrawableType::TextBox(t) =>
+                if t.position.0 != new_x_pos {
+                    t.position.0 = new_x_pos;
+                },
+            DrawableType::Checkbox(c) =>
+                if c.position.0 != new_x_pos {
+                    c.position.0 = new_x_pos;
+                },
+            DrawableType::Toggle(t) =>
+                if t.position.0 != new_x_pos {
+                    t.position.0 = new_x_pos;
+                },
+            DrawableType::RadioGroup(r) =>
+                if r.position.0 != new_x_pos {
+                    r.position.0 = new_x_pos;
+                },
+            DrawableType::ComboBox(c) =>
+                if c.position.0 != new_x_pos {
+                    c.position.0 = new_x_pos;
+                },
+            DrawableType::ScrollBar(s) =>
+                if s.position.0 != new_x_pos {
+                    s.position.0 = new_x_pos;
+                },
+            DrawableType::ProgressBar(p) =>
+                if p.position.0 != new_x_pos {
+                    p.position.0 = new_x_pos;
+                },
+            DrawableType::ScrollPanel(p) =>
+                if p.position.0 != new_x_pos {
+                    p.move_to((new_x_pos, p.position.1));
+                },
+        }
+    }
+}
+
+impl<T, E: Clone> DrawableType<T, E> {
+    /// Performs `action` on the focused component, pushing the events it
+    /// produces onto `events`. Returns `true` if the component used the
+    /// action, or `false` if it should move focus instead.
+    pub fn navigate(&mut self, action: NavAction, events: &mut Vec<GuiEvent<E>>) -> bool {
+        match self {
+            DrawableType::Button(b) => {
+                if action != NavAction::Activate {
+                    return false;
+                }
+
+                events.push(GuiEvent::ButtonClicked {
+                    button: ButtonHandle(b.get_id()),
+                    event: b.activate(),
+                });
+            },
+            DrawableType::Slider(s) => {
+                let steps = match (action, s.get_orientation()) {
+                    (NavAction::Right, Orientation::Horizontal)
+                    | (NavAction::Up, Orientation::Vertical) => 1,
+                    (NavAction::Left, Orientation::Horizontal)
+                    | (NavAction::Down, Orientation::Vertical) => -1,
+                    _ => return false,
+                };
+
+                if s.step_by(steps) {
+                    events.push(GuiEvent::SliderChanged {
+                        slider: SliderHandle(s.get_id()),
+                        value: s.get_value(),
+                    });
+                }
+            },
+            DrawableType::RangeSlider(r) => {
+                let steps = match (action, r.get_orientation()) {
+                    (NavAction::Activate, _) => {
+                        let thumb = match r.get_focused_thumb() {
+                            RangeThumb::Low => RangeThumb::High,
+                            RangeThumb::High => RangeThumb::Low,
+                        };
+                        r.set_focused_thumb(thumb);
+
+                        return true;
+                    },
+                    (NavAction::Right, Orientation::Horizontal)
+                    | (NavAction::Up, Orientation::Vertical) => 1,
+                    (NavAction::Left, Orientation::Horizontal)
+                    | (NavAction::Down, Orientation::Vertical) => -1,
+                    _ => return false,
+                };
+
+                if r.step_by(steps) {
+                    events.push(GuiEvent::RangeSliderChanged {
+                        range_slider: RangeSliderHandle(r.get_id()),
+                        low: r.get_low(),
+                        high: r.get_high(),
+                    });
+                }
+            },
+            DrawableType::Dropdown(d) => {
+                let open = match action {
+                    NavAction::Activate => !d.is_open(),
+                    NavAction::Back if d.is_open() => false,
+                    _ => return false,
+                };
+                d.set_open(open);
+
+                let dropdown = DropdownHandle(d.get_id());
+                events.push(match open {
+                    true => GuiEvent::DropdownOpened(dropdown),
+                    false => GuiEvent::DropdownClosed(dropdown),
+                });
+            },
+            DrawableType::TextBox(t) => {
+                if action != NavAction::Activate {
+                    return false;
+                }
+
+                t.set_editing(true);
+            },
+            DrawableType::Checkbox(c) => {
+                if action != NavAction::Activate {
+                    return false;
+                }
+
+                c.activate();
+                events.push(GuiEvent::CheckboxChanged {
+                    checkbox: CheckboxHandle(c.get_id()),
+                    state: c.get_check_state(),
+                });
+            },
+            DrawableType::Toggle(t) => {
+                if action != NavAction::Activate {
+                    return false;
+                }
+
+                t.activate();
+                events.push(GuiEvent::ToggleChanged {
+                    toggle: ToggleHandle(t.get_id()),
+                    value: t.get_value(),
+                });
+            },
+            DrawableType::RadioGroup(r) => {
+                let offset = match (action, r.get_orientation()) {
+                    (NavAction::Right, Orientation::Horizontal)
+                    | (NavAction::Down, Orientation::Vertical) => 1,
+                    (NavAction::Left, Orientation::Horizontal)
+                    | (NavAction::Up, Orientation::Vertical) => -1,
+                    _ => return false,
+                };
+
+                if r.select_by(offset) {
+                    events.push(GuiEvent::RadioGroupChanged {
+                        radio_group: RadioGroupHandle(r.get_id()),
+                        index: r.get_selected().unwrap_or_default(),
+                    });
+                }
+            },
+            DrawableType::ComboBox(c) => {
+                let offset = match (action, c.is_open()) {
+                    (NavAction::Activate, open) => {
+                        c.set_open(!open);
+
+                        return true;
+                    },
+                    (NavAction::Back, true) => {
+                        c.set_open(false);
+
+                        return true;
+                    },
+                    (NavAction::Down, true) | (NavAction::Right, true) => 1,
+                    (NavAction::Up, true) | (NavAction::Left, true) => -1,
+                    // Moving focus away closes the list behind it.
+                    _ => {
+                        c.set_open(false);
+
+                        return false;
+                    },
+                };
+
+                if c.select_by(offset) {
+                    events.push(GuiEvent::ComboBoxChanged {
+                        combo_box: ComboBoxHandle(c.get_id()),
+                        index: c.get_selected().unwrap_or_default(),
+                    });
+                }
+            },
+            DrawableType::ScrollPanel(p) => {
+                let lines = match action {
+                    NavAction::Up => -1,
+                    NavAction::Down => 1,
+                    _ => return false,
+                };
+
+                // Reaching either end lets focus move on to the next
+                // component.
+                if !p.scroll_lines(lines) {
+                    return false;
+                }
+
+                events.push(GuiEvent::ScrollPanelScrolled {
+                    scroll_panel: ScrollPanelHandle(p.get_id()),
+                    offset: p.get_scroll_offset(),
+                });
+            },
+            DrawableType::Label(_) | DrawableType::ScrollBar(_) | DrawableType::ProgressBar(_) =>
+                return false,
+        }
+
+        true
+    }
+
+    /// Moves a focused `Slider` or the focused box of a `RangeSlider`
+    /// `fraction` of the length of its track, pushing an event onto `events`
+    /// if its value changes. Does nothing for any other component.
+    pub fn adjust(&mut self, fraction: f32, events: &mut Vec<GuiEvent<E>>) {
+        match self {
+            DrawableType::Slider(s) =>
+                if s.adjust(fraction) {
+                    events.push(GuiEvent::SliderChanged {
+                        slider: SliderHandle(s.get_id()),
+                        value: s.get_value(),
+                    });
+                },
+            DrawableType::RangeSlider(r) =>
+                if r.adjust(fraction) {
+                    events.push(GuiEvent::RangeSliderChanged {
+                        range_slider: RangeSliderHandle(r.get_id()),
+                        low: r.get_low(),
+                        high: r.get_high(),
+                    });
+                },
+            DrawableType::Button(_)
+            | DrawableType::Dropdown(_)
+            | DrawableType::Label(_)
+            | DrawableType::TextBox(_)
+            | DrawableType::Checkbox(_)
+            | DrawableType::Toggle(_)
+            | DrawableType::RadioGroup(_)
+            | DrawableType::ComboBox(_)
+            | DrawableType::ScrollBar(_)
+            | DrawableType::ProgressBar(_)
+            | DrawableType::ScrollPanel(_) => (),
+        }
+    }
+
+    pub fn draw(&mut self, renderer: &mut dyn Renderer) {
+        if !self.is_visible() {
+            return;
+        }
+
+        match self {
+            DrawableType::Button(b) => b.draw(renderer),
+            DrawableType::Slider(s) => s.draw(renderer),
+            DrawableType::RangeSlider(r) => r.draw(renderer),
+            DrawableType::Dropdown(d) => d.draw(renderer),
+            DrawableType::Label(l) => l.draw(renderer),
+            DrawableType::TextBox(t) => t.draw(renderer),
+            DrawableType::Checkbox(c) => c.draw(renderer),
+            DrawableType::Toggle(t) => t.draw(renderer),
+            DrawableType::RadioGroup(r) => r.draw(renderer),
+            DrawableType::ComboBox(c) => c.draw(renderer),
+            DrawableType::ScrollBar(s) => s.draw(renderer),
+            DrawableType::ProgressBar(p) => p.draw(renderer),
+            DrawableType::ScrollPanel(p) => p.draw(renderer),
+        }
+    }
+
+    /// Draws the parts of the component that pop up over the others, such as
+    /// the open list of a `ComboBox`.
+    pub fn draw_popup(&mut self, renderer: &mut dyn Renderer) {
+        if !self.is_visible() {
+            return;
+        }
+
+        match self {
+            DrawableType::ComboBox(c) => c.draw_list(renderer),
+            DrawableType::Dropdown(d) => d.draw_panel(renderer),
+            _ => (),
+        }
+    }
+
+    /// Returns `true` if `mouse_position` is over a popup of the component,
+    /// such as the open list of a `ComboBox` or the components of an open
+    /// `Dropdown`.
+    pub fn is_popup_hovered(&self, mouse_position: Point) -> bool {
+        match self {
+            DrawableType::ComboBox(c) => c.is_list_hovered(mouse_position),
+            DrawableType::Dropdown(d) => d.is_panel_hovered(mouse_position),
+            DrawableType::ScrollPanel(p) => p
+                .get_components()
+                .iter()
+                .any(|c| c.is_visible() && c.is_popup_hovered(mouse_position)),
+            _ => false,
+        }
+    }
+
+    /// Returns the id of the innermost component at `mouse_position`, which
+    /// is this component, a popup of it, or one of the components of an open
+    /// `Dropdown` or a `ScrollPanel`. The empty part of an open `Dropdown`
+    /// counts as the `Dropdown`, and a `ProgressBar` only counts if it is
+    /// interactive.
+    pub fn component_at(&self, mouse_position: Point) -> Option<WidgetId> {
+        let nested = match self {
+            DrawableType::Dropdown(d) if d.is_open() => Some(d.get_panel()),
+            DrawableType::ScrollPanel(p) => Some(p),
+            // The mouse passes through a decorative `ProgressBar`.
+            DrawableType::ProgressBar(p) if !p.is_interactive() => return None,
+            _ => None,
+        };
+
+        if let Some(panel) = nested {
+            let components = panel.get_components();
+            let popup_hovered = components
+                .iter()
+                .any(|c| c.is_visible() && c.is_popup_hovered(mouse_position));
+
+            if popup_hovered || is_inside(panel.position, panel.dimensions, mouse_position) {
+                return find_component_at(components, mouse_position).or(Some(self.get_id()));
+            }
+        }
+
+        match is_inside(self.get_position(), self.get_dimensions(), mouse_position)
+            || self.is_popup_hovered(mouse_position)
+        {
+            true => Some(self.get_id()),
+            false => None,
+        }
+    }
+
+    pub fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        match self {
+            DrawableType::Button(b) => b.is_hovered(mouse_position),
+            DrawableType::Slider(s) => s.is_hovered(mouse_position),
+            DrawableType::RangeSlider(r) => r.is_hovered(mouse_position),
+            DrawableType::Dropdown(d) => d.is_hovered(mouse_position),
+            DrawableType::Label(l) => l.is_hovered(mouse_position),
+            DrawableType::TextBox(t) => t.is_hovered(mouse_position),
+            DrawableType::Checkbox(c) => c.is_hovered(mouse_position),
+            DrawableType::Toggle(t) => t.is_hovered(mouse_position),
+            DrawableType::RadioGroup(r) => r.is_hovered(mouse_position),
+            DrawableType::ComboBox(c) => c.is_hovered(mouse_position),
+            DrawableType::ScrollBar(s) => s.is_hovered(mouse_position),
+            DrawableType::ProgressBar(p) => p.is_hovered(mouse_position),
+            DrawableType::ScrollPanel(p) => p.is_hovered(mouse_position),
+        }
+    }
+
+    pub fn is_clicked(&mut self, input: &InputState, events: &mut Vec<GuiEvent<E>>) {
+        let mouse_position = input.mouse_position;
+        let pressed = input.is_button_pressed(PointerButton::Left);
+
+        match self {
+            DrawableType::Button(b) => {
+                let button = ButtonHandle(b.get_id());
+
+                for click in b.handle_input(input) {
+                    let event = b.get_event().clone();
+
+                    events.push(match click {
+                        Click::Primary => GuiEvent::ButtonClicked { button, event },
+                        Click::Double => GuiEvent::ButtonDoubleClicked { button, event },
+                        Click::Secondary => GuiEvent::ButtonRightClicked { button, event },
+                        Click::LongPress => GuiEvent::ButtonLongPressed { button, event },
+                    });
+                }
+            },
+            DrawableType::Slider(s) =>
+                if s.handle_input(input) {
+                    events.push(GuiEvent::SliderChanged {
+                        slider: SliderHandle(s.get_id()),
+                        value: s.get_value(),
+                    });
+                },
+            DrawableType::RangeSlider(r) => {
+                r.handle_input(input);
+
+                if r.was_changed() {
+                    events.push(GuiEvent::RangeSliderChanged {
+                        range_slider: RangeSliderHandle(r.get_id()),
+                        low: r.get_low(),
+                        high: r.get_high(),
+                    });
+                }
+            },
+            DrawableType::Dropdown(d) => {
+                let dropdown = DropdownHandle(d.get_id());
+
+                match d.is_clicked(mouse_position, pressed) {
+                    Some(true) => events.push(GuiEvent::DropdownOpened(dropdown)),
+                    Some(false) => events.push(GuiEvent::DropdownClosed(dropdown)),
+                    None => (),
+                }
+
+                d.update_components(input, events);
+            },
+            DrawableType::Label(l) =>
+                if l.is_clicked(mouse_position, pressed) {
+                    events.push(GuiEvent::LabelClicked(LabelHandle(l.get_id())));
+                },
+            DrawableType::Checkbox(c) =>
+                if c.is_clicked(mouse_position, pressed) {
+                    events.push(GuiEvent::CheckboxChanged {
+                        checkbox: CheckboxHandle(c.get_id()),
+                        state: c.get_check_state(),
+                    });
+                },
+            DrawableType::Toggle(t) => {
+                if t.is_clicked(mouse_position, pressed) {
+                    events.push(GuiEvent::ToggleChanged {
+                        toggle: ToggleHandle(t.get_id()),
+                        value: t.get_value(),
+                    });
+                }
+
+                t.animate(input.frame_time);
+            },
+            DrawableType::RadioGroup(r) =>
+                if r.is_clicked(mouse_position, pressed) {
+                    events.push(GuiEvent::RadioGroupChanged {
+                        radio_group: RadioGroupHandle(r.get_id()),
+                        index: r.get_selected().unwrap_or_default(),
+                    });
+                },
+            DrawableType::ComboBox(c) => {
+                c.handle_input(input);
+
+                if c.was_changed() {
+                    events.push(GuiEvent::ComboBoxChanged {
+                        combo_box: ComboBoxHandle(c.get_id()),
+                        index: c.get_selected().unwrap_or_default(),
+                    });
+                }
+            },
+            DrawableType::TextBox(t) => {
+                let text_box = TextBoxHandle(t.get_id());
+
+                t.handle_mouse(input);
+                t.handle_keys(input);
+
+                if t.was_changed() {
+                    events.push(GuiEvent::TextBoxChanged {
+                        text_box,
+                        text: t.get_text().to_string(),
+                    });
+                }
+                if t.was_submitted() {
+                    events.push(GuiEvent::TextBoxSubmitted {
+                        text_box,
+                        text: t.get_text().to_string(),
+                    });
+                }
+            },
+            DrawableType::ScrollBar(s) => {
+                s.handle_input(input);
+
+                if s.was_changed() {
+                    events.push(GuiEvent::ScrollBarChanged {
+                        scroll_bar: ScrollBarHandle(s.get_id()),
+                        offset: s.get_offset(),
+                    });
+                }
+            },
+            DrawableType::ProgressBar(p) => p.animate(input.frame_time),
+            DrawableType::ScrollPanel(p) =>
+                if p.update(input, events) {
+                    events.push(GuiEvent::ScrollPanelScrolled {
+                        scroll_panel: ScrollPanelHandle(p.get_id()),
+                        offset: p.get_scroll_offset(),
+                    });
+                },
         }
     }
 }
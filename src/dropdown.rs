@@ -1,198 +1,359 @@
 use crate::{common::*, gui_component::*, prelude::*};
-use raylib::prelude::*;
-
-#[derive(PartialEq)]
-pub struct Dropdown {
-    background_colour: Colour,
-    components: Vec<DrawableType>,
-    components_fixed_widths: bool,
-    font_size: i32,
-    pub actions: Vec<String>,
+
+pub struct Dropdown<T, E> {
+    custom_style: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    max_height: Option<i32>,
+    on_open: Option<ValueCallback<T, bool>>,
     pub dimensions: Dimensions,
+    panel: Box<ScrollPanel<T, E>>,
     pub position: Point,
+    preferred_dimensions: Dimensions,
     show: bool,
+    style: WidgetStyle,
     text: String,
-    text_colour: Colour,
+    text_measurer: TextMeasurer,
+    toggled: bool,
+    visible: bool,
 }
 
-impl Dropdown {
+impl<T, E> Dropdown<T, E> {
     /// Create a new `Dropdown`, automatically figuring out width depending on
     /// the `text` and the given `font_size`.
     pub fn new(text: &str, font_size: i32, position: Point) -> Self {
-        let dimensions = (measure_text(text, font_size) + 10, 50);
+        let mut dropdown = Self::new_with_dimensions(text, font_size, position, (0, 0));
+        dropdown.fit_text();
+
+        dropdown
+    }
+
+    /// Create a new `Dropdown` with specific `dimensions`.
+    pub fn new_with_dimensions(
+        text: &str,
+        font_size: i32,
+        position: Point,
+        dimensions: Dimensions,
+    ) -> Self {
         Self {
-            actions: Vec::new(),
-            background_colour: state_get_colour(StateColour::Default),
-            components: Vec::new(),
-            components_fixed_widths: false,
+            custom_style: false,
             dimensions,
-            font_size,
+            enabled: true,
+            focused: false,
+            hovered: false,
+            id: WidgetId::unique(),
+            max_height: None,
+            on_open: None,
+            panel: Box::new(ScrollPanel::new(position, (0, 0))),
             position,
+            preferred_dimensions: dimensions,
             show: false,
+            style: WidgetStyle {
+                font_size,
+                ..Theme::default().dropdown
+            },
             text: text.to_string(),
-            text_colour: state_get_colour(StateColour::Text),
+            text_measurer: TextMeasurer::default(),
+            toggled: false,
+            visible: true,
         }
     }
 
-    /// Add a new `DrawableType` component to the list of components to be
-    /// drawn.
-    pub fn add_component(&mut self, component: DrawableType) -> &mut Self {
-        self.components.push(component);
+    /// Returns the `WidgetId` of the `Dropdown`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `Dropdown` is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `Dropdown`, along with all of its components.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
 
         self
     }
 
-    fn get_previous_position(&self) -> Point {
-        match self.components.last() {
-            Some(c) => c.get_position(),
-            None => self.position,
-        }
+    /// Returns whether or not the `Dropdown` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `Dropdown`, closing it when it is disabled. A
+    /// disabled `Dropdown` is drawn in the disabled colours of its style and
+    /// cannot be opened.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self.show &= enabled;
+
+        self
     }
 
-    /// Makes it so that when components are drawn, that they are all drawn at
-    /// the same width so that they are uniform.
-    pub fn set_components_fix_widths(&mut self, value: bool) -> &mut Self {
-        self.components_fixed_widths = value;
+    /// Sets the text of the `Dropdown`, resizing it to fit.
+    pub fn set_text(&mut self, text: &str) -> &mut Self {
+        self.text = text.to_string();
+        self.fit_text();
 
         self
     }
 
-    fn components_fix_widths(&mut self) {
-        let mut widest = -1;
+    fn fit_text(&mut self) {
+        let text_width = self.text_measurer.measure(&self.text, self.style.font_size);
+        self.preferred_dimensions = self.style.fit_text(text_width);
+        self.resize(self.preferred_dimensions);
+    }
 
-        for component in self.components.iter() {
-            let width = component.get_dimensions().0;
-            if width > widest {
-                widest = width;
-            }
-        }
+    /// Gives the `Dropdown` its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`, resizing it to fit. This does not change the
+    /// style of its components.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
 
-        for component in self.components.iter_mut() {
-            component.resize((widest, component.get_dimensions().1));
+        self
+    }
+
+    /// Styles the `Dropdown` and its components using `theme`, unless they
+    /// have been given their own style, resizing them to fit.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.dropdown.clone();
+            self.fit_text();
         }
+
+        self.panel.apply_theme(theme);
     }
 
-    /// Resizes the `Dropdown` to given `new_dimensions`.
-    pub fn resize(&mut self, new_dimensions: Dimensions) {
-        self.dimensions = new_dimensions;
-        let new_x_pos = self.position.0 + 10 + self.dimensions.0;
+    /// Returns the state the `Dropdown` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
 
-        for component in self.components.iter_mut() {
-            component.move_x(new_x_pos);
+        match (self.hovered, self.show) {
+            (true, _) => WidgetState::Hovered,
+            (false, true) => WidgetState::Active,
+            (false, false) => WidgetState::Default,
         }
     }
 
-    /// Adds a `Button` to the `Dropdown` with automatic positioning. It's
-    /// automatic position is determined by whether or not there are
-    /// components already added. For example, if no components are present
-    /// then the first `Button` is placed at (0, 0). If a component already
-    /// exists then the `Button`s created afterwards are placed n+50 pixels
-    /// below the first component.
-    pub fn add_button(&mut self, text: &str, action: &str) -> &mut Self {
-        let previous_position = self.get_previous_position();
-        let new_x = self.position.0 + self.dimensions.0;
-
-        self.components.push(DrawableType::Button(Button::new(
-            text,
-            action,
-            20,
-            (
-                previous_position.0 + new_x,
-                previous_position.1
-                    + match self.components.first() {
-                        Some(c) => c.get_dimensions().1,
-                        None => 0,
-                    },
-            ),
-        )));
+    /// Returns `true` while the components of the `Dropdown` are shown.
+    pub fn is_open(&self) -> bool { self.show }
+
+    /// Shows or hides the components of the `Dropdown` without the mouse,
+    /// calling its open callback if this changes whether it is open.
+    pub fn set_open(&mut self, open: bool) -> &mut Self {
+        self.toggled |= self.show != open;
+        self.show = open;
 
         self
     }
 
-    /// Adds a `Slider` to the `Dropdown` with automatic positioning. It's
-    /// automatic position is determined by whether or not there are
-    /// components already added. For example, if no components are present
-    /// then the first `Slider` is placed at (0, 0). If a component already
-    /// exists then the `Slider`s created afterwards are placed n+50 pixels
-    /// below the first component.
-    pub fn add_slider(&mut self, min: i32, max: i32, initial_value: f32) -> &mut Self {
-        let previous_position = self.get_previous_position();
-        let new_x = self.position.0 + self.dimensions.0;
-
-        self.components.push(DrawableType::Slider(Slider::new(
-            min,
-            max,
-            initial_value,
-            (
-                previous_position.0 + new_x,
-                previous_position.1
-                    + match self.components.first() {
-                        Some(c) => c.get_dimensions().1,
-                        None => 0,
-                    },
-            ),
-            250,
-        )));
+    /// Returns whether or not the `Dropdown` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `Dropdown`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
 
         self
     }
 
-    /// Gets the value of a specified `Slider` via an index, returning a `f32`.
-    pub fn get_slider_value(&self, index: usize) -> Result<f32, String> {
-        let mut sliders = vec![];
-        for c in self.components.iter() {
-            if let DrawableType::Slider(s) = c {
-                sliders.push(s)
-            }
+    /// Returns the dimensions the `Dropdown` was created with, updated to fit
+    /// its text whenever it is changed with `set_text` or restyled.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Moves the `Dropdown` to `new_position`, moving its components along
+    /// with it.
+    pub fn move_to(&mut self, new_position: Point) {
+        self.position = new_position;
+        self.layout_components();
+    }
+
+    /// Stacks the components of the `Dropdown` in a column to the right of
+    /// it, scrolling them if they are taller than its maximum height.
+    fn layout_components(&mut self) {
+        self.panel.position = (self.position.0 + self.dimensions.0 + 10, self.position.1);
+        self.panel.fit_to_content(self.max_height);
+    }
+
+    /// Limits how tall the column of components is drawn, scrolling through
+    /// them if they do not fit. `None` lets the column grow to fit all of
+    /// them.
+    pub fn set_max_height(&mut self, max_height: Option<i32>) -> &mut Self {
+        self.max_height = max_height;
+        self.layout_components();
+
+        self
+    }
+
+    /// Returns the `ScrollPanel` that the components of the `Dropdown` are
+    /// shown in.
+    pub fn get_panel(&self) -> &ScrollPanel<T, E> { &self.panel }
+
+    /// Returns the `ScrollPanel` that the components of the `Dropdown` are
+    /// shown in mutably.
+    pub fn get_panel_mut(&mut self) -> &mut ScrollPanel<T, E> { &mut self.panel }
+
+    /// Returns the components of the `Dropdown`.
+    pub fn get_components(&self) -> &[DrawableType<T, E>] { self.panel.get_components() }
+
+    /// Returns the components of the `Dropdown` mutably.
+    pub fn get_components_mut(&mut self) -> &mut [DrawableType<T, E>] {
+        self.panel.get_components_mut()
+    }
+
+    /// Removes the component with the given `id` from the `Dropdown`, or from
+    /// any container inside of it, returning it if it was found.
+    pub fn remove(&mut self, id: impl Into<WidgetId>) -> Option<DrawableType<T, E>> {
+        let removed = self.panel.remove(id);
+        self.layout_components();
+
+        removed
+    }
+
+    /// Sets the function used to measure the width of text when sizing the
+    /// `Dropdown` and its components, resizing them to fit.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+        self.fit_text();
+        self.panel.set_text_measurer(self.text_measurer.clone());
+
+        self
+    }
+
+    /// Add a new `DrawableType` component to the list of components to be
+    /// drawn, styling it with the `Dropdown`'s theme and returning its
+    /// `WidgetId`.
+    pub fn add_component(&mut self, component: DrawableType<T, E>) -> WidgetId {
+        let id = self.panel.add_component(component);
+        self.layout_components();
+
+        id
+    }
+
+    /// Makes it so that when components are drawn, that they are all drawn at
+    /// the same width so that they are uniform.
+    pub fn set_components_fix_widths(&mut self, value: bool) -> &mut Self {
+        self.panel.set_components_fix_widths(value);
+
+        self
+    }
+
+    /// Resizes the `Dropdown` to given `new_dimensions`.
+    pub fn resize(&mut self, new_dimensions: Dimensions) {
+        self.dimensions = new_dimensions;
+        self.layout_components();
+    }
+
+    /// Adds a `Button` to the `Dropdown`, placing it below the component
+    /// that was added before it.
+    pub fn add_button(&mut self, text: &str, event: E) -> ButtonHandle {
+        let button = self.panel.add_button(text, event);
+        self.layout_components();
+
+        button
+    }
+
+    /// Adds a `Slider` to the `Dropdown`, placing it below the component
+    /// that was added before it.
+    pub fn add_slider(&mut self, min: f32, max: f32, initial_value: f32) -> SliderHandle {
+        let slider = self.panel.add_slider(min, max, initial_value);
+        self.layout_components();
+
+        slider
+    }
+
+    /// Gets the value of the `Slider` with the given `handle`, returning a
+    /// `f32`.
+    pub fn get_slider_value(&self, handle: SliderHandle) -> Result<f32, String> {
+        match find_component(self.get_components(), handle.id()) {
+            Some(DrawableType::Slider(s)) => Ok(s.get_value()),
+            _ => Err("Cannot return the value of a Slider that does not exist".to_string()),
         }
+    }
 
-        if index > sliders.len() {
-            return Err(
-                "Cannot return the value of a Slider that is out of index range".to_string(),
-            );
+    /// Gets the value of the `Slider` with the given `handle`, returning an
+    /// `i32`.
+    pub fn get_slider_value_i32(&self, handle: SliderHandle) -> Result<i32, String> {
+        match self.get_slider_value(handle) {
+            Ok(v) => Ok(v as i32),
+            Err(e) => Err(e),
         }
+    }
 
-        Ok(sliders[index].get_value())
+    /// Adds a `Checkbox` labelled with `text` to the `Dropdown`, placing it
+    /// below the component that was added before it.
+    pub fn add_checkbox(&mut self, text: &str, checked: bool) -> CheckboxHandle {
+        let checkbox = self.panel.add_checkbox(text, checked);
+        self.layout_components();
+
+        checkbox
     }
 
-    /// Gets the value of a specified `Slider` via an index, returning an `i32`.
-    pub fn get_slider_value_i32(&self, index: usize) -> Result<i32, String> {
-        match self.get_slider_value(index) {
-            Ok(v) => Ok(v as i32),
+    /// Gets the `CheckState` of the `Checkbox` with the given `handle`.
+    pub fn get_checkbox_state(&self, handle: CheckboxHandle) -> Result<CheckState, String> {
+        match find_component(self.get_components(), handle.id()) {
+            Some(DrawableType::Checkbox(c)) => Ok(c.get_check_state()),
+            _ => Err("Cannot return the state of a Checkbox that does not exist".to_string()),
+        }
+    }
+
+    /// Gets whether or not the `Checkbox` with the given `handle` is ticked.
+    pub fn get_checkbox_value(&self, handle: CheckboxHandle) -> Result<bool, String> {
+        match self.get_checkbox_state(handle) {
+            Ok(state) => Ok(state == CheckState::Checked),
             Err(e) => Err(e),
         }
     }
 
-    /// Adds a `Dropdown` to the `Dropdown` with automatic positioning. It's
-    /// automatic position is determined by whether or not there are
-    /// components already added. For example, if no components are present
-    /// then the first `Dropdown` is placed at (0, 0). If a component already
-    /// exists then the `Dropdown`s created afterwards are placed n+50
-    /// pixels below the first component.
-    pub fn add_dropdown(&mut self, text: &str) -> &mut Self {
-        let previous_position = self.get_previous_position();
-        let new_x = self.position.0 + self.dimensions.0;
-
-        self.components.push(DrawableType::Dropdown(Dropdown::new(
-            text,
-            20,
-            (
-                previous_position.0 + new_x,
-                previous_position.1
-                    + match self.components.first() {
-                        Some(c) => c.get_dimensions().1,
-                        None => 0,
-                    },
-            ),
-        )));
+    /// Adds a `RadioGroup` with an option for each of `options` to the
+    /// `Dropdown`, placing it below the component that was added before it.
+    pub fn add_radio_group(
+        &mut self,
+        options: &[&str],
+        selected: Option<usize>,
+    ) -> RadioGroupHandle {
+        let radio_group = self.panel.add_radio_group(options, selected);
+        self.layout_components();
+
+        radio_group
+    }
+
+    /// Gets the index of the selected option of the `RadioGroup` with the
+    /// given `handle`, which is `None` if no option is selected.
+    pub fn get_radio_group_selected(
+        &self,
+        handle: RadioGroupHandle,
+    ) -> Result<Option<usize>, String> {
+        match find_component(self.get_components(), handle.id()) {
+            Some(DrawableType::RadioGroup(r)) => Ok(r.get_selected()),
+            _ => Err("Cannot return the selection of a RadioGroup that does not exist".to_string()),
+        }
+    }
 
-        self
+    /// Adds a `Dropdown` to the `Dropdown`, placing it below the component
+    /// that was added before it.
+    pub fn add_dropdown(&mut self, text: &str) -> DropdownHandle {
+        let mut dropdown = Dropdown::new_with_dimensions(text, 20, self.position, (0, 0));
+        dropdown.set_text_measurer(self.text_measurer.clone());
+
+        DropdownHandle(self.add_component(DrawableType::Dropdown(dropdown)))
+    }
+
+    /// Gets the `Dropdown` with the given `handle`, searching inside of any
+    /// `Dropdown`s in the components vector.
+    pub fn get_dropdown_mut(&mut self, handle: DropdownHandle) -> Option<&mut Dropdown<T, E>> {
+        match find_component_mut(self.get_components_mut(), handle.id()) {
+            Some(DrawableType::Dropdown(d)) => Some(d),
+            _ => None,
+        }
     }
 
     /// Gets a vector of mutable `Dropdown` references in the components vector,
-    pub fn get_dropdowns_mut(&mut self) -> Result<Vec<&mut Dropdown>, String> {
+    pub fn get_dropdowns_mut(&mut self) -> Result<Vec<&mut Dropdown<T, E>>, String> {
         let mut dropdown = vec![];
-        for c in self.components.iter_mut() {
+        for c in self.panel.get_components_mut().iter_mut() {
             if let DrawableType::Dropdown(d) = c {
                 dropdown.push(d)
             }
@@ -202,9 +363,9 @@ impl Dropdown {
     }
 
     /// Gets a vector of `Dropdown` references in the components vector,
-    pub fn get_dropdowns(&mut self) -> Result<Vec<&Dropdown>, String> {
+    pub fn get_dropdowns(&mut self) -> Result<Vec<&Dropdown<T, E>>, String> {
         let mut dropdown = vec![];
-        for c in self.components.iter() {
+        for c in self.panel.get_components().iter() {
             if let DrawableType::Dropdown(d) = c {
                 dropdown.push(d)
             }
@@ -212,62 +373,153 @@ impl Dropdown {
 
         Ok(dropdown)
     }
-}
 
-impl GuiComponentBehaviour<()> for Dropdown {
-    /// Draw `Dropdown` to screen.
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle) {
-        let mouse_position = (draw_handler.get_mouse_x(), draw_handler.get_mouse_y());
-        self.is_hovered(mouse_position);
+    /// Sets the callback of the `Button` or `Label` with the given `id` that
+    /// is called when it is clicked.
+    pub fn on_click(&mut self, id: impl Into<WidgetId>, on_click: Callback<T>) -> &mut Self {
+        if let Some(component) = find_component_mut(self.get_components_mut(), id.into()) {
+            component.set_on_click(on_click);
+        }
 
-        self.actions.clear();
+        self
+    }
 
-        draw_handler.draw_rectangle(
-            self.position.0,
-            self.position.1,
-            self.dimensions.0 + 10,
-            self.dimensions.1,
-            self.background_colour,
-        );
+    /// Sets the callback of the `Slider` with the given `handle` that is
+    /// called with its new value when it changes.
+    pub fn on_change(
+        &mut self,
+        handle: SliderHandle,
+        on_change: ValueCallback<T, f32>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(self.get_components_mut(), handle.id()) {
+            component.set_on_change(on_change);
+        }
 
-        draw_handler.draw_text(
-            self.text.as_str(),
-            self.position.0 + 10,
-            self.position.1 + self.dimensions.1 - 30,
-            self.font_size,
-            self.text_colour,
-        );
+        self
+    }
 
-        if self.components_fixed_widths {
-            self.components_fix_widths();
+    /// Sets the callback of the `Checkbox` with the given `handle` that is
+    /// called with its new `CheckState` when it is clicked.
+    pub fn on_check(
+        &mut self,
+        handle: CheckboxHandle,
+        on_check: ValueCallback<T, CheckState>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(self.get_components_mut(), handle.id()) {
+            component.set_on_check(on_check);
         }
 
-        if self.show {
-            for component in self.components.iter_mut() {
-                component.draw(draw_handler);
+        self
+    }
+
+    /// Sets the callback of the `Dropdown` with the given `handle` that is
+    /// called with whether or not it is open when it is opened or closed.
+    pub fn on_open(
+        &mut self,
+        handle: DropdownHandle,
+        on_open: ValueCallback<T, bool>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(self.get_components_mut(), handle.id()) {
+            component.set_on_open(on_open);
+        }
+
+        self
+    }
+
+    /// Sets the callback that is called with whether or not the `Dropdown` is
+    /// open whenever it is opened or closed.
+    pub fn set_on_open(&mut self, on_open: ValueCallback<T, bool>) -> &mut Self {
+        self.on_open = Some(on_open);
+
+        self
+    }
+
+    /// Calls the callbacks of the `Dropdown` and its components for anything
+    /// that happened during the last update.
+    pub fn execute_callbacks(&mut self, state: &mut T, button_action: Option<Action<T, E>>) {
+        if std::mem::take(&mut self.toggled) {
+            if let Some(on_open) = self.on_open.as_mut() {
+                on_open(state, self.show);
             }
         }
+
+        self.panel.execute_callbacks(state, button_action);
+    }
+}
+
+impl<T, E: Clone> Dropdown<T, E> {
+    /// Draws the components of the `Dropdown` if it is open. Like the list of
+    /// a `ComboBox`, this is drawn after every other component so that it
+    /// covers them, and is not clipped by a `ScrollPanel` the `Dropdown` is
+    /// in.
+    pub fn draw_panel(&mut self, renderer: &mut dyn Renderer) {
+        if self.show {
+            self.layout_components();
+            self.panel.draw(renderer);
+        }
+    }
+
+    /// Returns `true` if `mouse_position` is over the components of the
+    /// `Dropdown` while it is open, including any popups of them.
+    pub fn is_panel_hovered(&self, mouse_position: Point) -> bool {
+        self.show
+            && (is_inside(self.panel.position, self.panel.dimensions, mouse_position)
+                || self
+                    .get_components()
+                    .iter()
+                    .any(|c| c.is_visible() && c.is_popup_hovered(mouse_position)))
+    }
+
+    /// Updates the components of the `Dropdown` with the given `input` if it
+    /// is currently open.
+    pub fn update_components(&mut self, input: &InputState, events: &mut Vec<GuiEvent<E>>) {
+        if self.show {
+            self.layout_components();
+            self.panel.update(input, events);
+        }
+    }
+}
+
+impl<T, E: Clone> GuiComponentBehaviour<Option<bool>> for Dropdown<T, E> {
+    /// Draw `Dropdown` to screen, without its components, which are drawn by
+    /// `draw_panel`.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+        draw_widget_text(
+            renderer,
+            &self.text,
+            self.position,
+            self.dimensions,
+            &self.style,
+            state,
+        );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
     }
 
     /// Checks whether or not the cursor is hovering over the `Dropdown` and
     /// returns `true` or `false`.
     fn is_hovered(&mut self, mouse_position: Point) -> bool {
-        if is_inside(self.position, self.dimensions, mouse_position) {
-            self.background_colour = state_get_colour(StateColour::Hovered);
-            true
-        } else {
-            if !self.show {
-                self.background_colour = state_get_colour(StateColour::Default);
-            }
-            false
-        }
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
     }
 
-    /// Checks whether or not the user is clicking on the `Dropdown`.
-    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) {
-        if is_inside(self.position, self.dimensions, mouse_position) && is_clicked {
-            self.background_colour = state_get_colour(StateColour::Active);
+    /// Checks whether or not the user is clicking on the `Dropdown`, toggling
+    /// it and returning whether it is now open if they are.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> Option<bool> {
+        self.toggled = is_inside(self.position, self.dimensions, mouse_position) && is_clicked;
+
+        if self.toggled {
             self.show = !self.show;
+
+            Some(self.show)
+        } else {
+            None
         }
     }
 }
@@ -0,0 +1,94 @@
+use crate::prelude::{
+    ButtonHandle, CheckState, CheckboxHandle, ComboBoxHandle, Dimensions, DropdownHandle,
+    LabelHandle, Point, RadioGroupHandle, RangeSliderHandle, ScrollBarHandle, ScrollPanelHandle,
+    SliderHandle, TextBoxHandle, ToggleHandle, WidgetId,
+};
+
+/// Something that happened to a component during `GuiHandler::update`, along
+/// with the handle of the component it happened to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GuiEvent<E> {
+    /// A `Button` was clicked, carrying the event value it was created with.
+    ButtonClicked { button: ButtonHandle, event: E },
+    /// A `Button` was clicked twice in quick succession. This comes after the
+    /// `ButtonClicked` of the second click.
+    ButtonDoubleClicked { button: ButtonHandle, event: E },
+    /// A `Button` was clicked with the right mouse button.
+    ButtonRightClicked { button: ButtonHandle, event: E },
+    /// The left mouse button was held down on a `Button` for long enough to
+    /// make a long press, which stops it from being clicked when the button
+    /// is released.
+    ButtonLongPressed { button: ButtonHandle, event: E },
+    /// A `Slider` was moved to a new `value`.
+    SliderChanged { slider: SliderHandle, value: f32 },
+    /// A box of a `RangeSlider` was moved, selecting from `low` to `high`.
+    RangeSliderChanged {
+        range_slider: RangeSliderHandle,
+        low: f32,
+        high: f32,
+    },
+    /// A `Checkbox` was clicked, moving it to a new `state`.
+    CheckboxChanged {
+        checkbox: CheckboxHandle,
+        state: CheckState,
+    },
+    /// A different option was picked from the list of a `ComboBox`,
+    /// carrying its `index`.
+    ComboBoxChanged {
+        combo_box: ComboBoxHandle,
+        index: usize,
+    },
+    /// A `Dropdown` was opened, showing its components.
+    DropdownOpened(DropdownHandle),
+    /// A `Dropdown` was closed, hiding its components.
+    DropdownClosed(DropdownHandle),
+    /// A `Label` was clicked.
+    LabelClicked(LabelHandle),
+    /// A different option of a `RadioGroup` was selected, carrying its
+    /// `index`.
+    RadioGroupChanged {
+        radio_group: RadioGroupHandle,
+        index: usize,
+    },
+    /// A `Toggle` was clicked, switching it to a new `value`.
+    ToggleChanged { toggle: ToggleHandle, value: bool },
+    /// The text of a `TextBox` was edited, carrying its new text.
+    TextBoxChanged {
+        text_box: TextBoxHandle,
+        text: String,
+    },
+    /// Enter was pressed in a focused `TextBox`, carrying its text.
+    TextBoxSubmitted {
+        text_box: TextBoxHandle,
+        text: String,
+    },
+    /// A `ScrollBar` was dragged or scrolled to a new `offset`.
+    ScrollBarChanged {
+        scroll_bar: ScrollBarHandle,
+        offset: i32,
+    },
+    /// A `ScrollPanel` was scrolled to a new `offset`.
+    ScrollPanelScrolled {
+        scroll_panel: ScrollPanelHandle,
+        offset: i32,
+    },
+    /// The mouse moved to `position` while a custom widget held the pointer
+    /// capture given to it by `GuiHandler::capture_pointer`.
+    PointerMoved { widget: WidgetId, position: Point },
+    /// The left mouse button was released at `position` while a custom
+    /// widget held the pointer capture, which ends the capture.
+    PointerReleased { widget: WidgetId, position: Point },
+    /// The action for switching to the next tab was performed, such as by
+    /// pressing the right shoulder button. `ptgui` has no tabs of its own, so
+    /// this is left for the game to handle.
+    NextTab,
+    /// The action for switching to the previous tab was performed, such as by
+    /// pressing the left shoulder button.
+    PreviousTab,
+    /// The size of the screen changed, and every `Layout` was placed again
+    /// to fit the new size.
+    ScreenResized(Dimensions),
+    /// A theme could not be applied, such as when its font could not be
+    /// loaded or a watched theme file became invalid.
+    ThemeError(String),
+}
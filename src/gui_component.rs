@@ -1,8 +1,7 @@
-use crate::prelude::Point;
-use raylib::prelude::RaylibDrawHandle;
+use crate::prelude::{Point, Renderer};
 
 pub trait GuiComponentBehaviour<T> {
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle);
+    fn draw(&mut self, renderer: &mut dyn Renderer);
     fn is_hovered(&mut self, mouse_position: Point) -> bool;
     fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> T;
 }
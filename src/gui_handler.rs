@@ -1,29 +1,72 @@
 use crate::{common::*, prelude::*};
 use raylib::prelude::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::SystemTime,
+};
+
+/// How often, in seconds, a watched theme file is checked for changes.
+const THEME_FILE_POLL_INTERVAL: f32 = 0.5;
 
 /// The default `struct` to handle the GUI system implemented by the `ptgui`
 /// crate.
-pub struct GuiHandler<T> {
-    actions: Vec<String>,
+pub struct GuiHandler<T, E> {
     additional_draws: Vec<Box<dyn Drawable>>,
-    button_action: Action<T>,
+    button_action: Option<Action<T, E>>,
     clear_colour: Colour,
+    click_timings: ClickTimings,
     components_fixed_widths: bool,
-    components: Vec<DrawableType>,
-    has_set_button_action: bool,
+    components: Vec<DrawableType<T, E>>,
+    events: Vec<GuiEvent<E>>,
+    focused: Option<WidgetId>,
+    font: Option<Rc<Font>>,
+    font_path: Option<String>,
+    input_capture: InputCapture,
+    input_map: InputMap,
+    layouts: Vec<Layout>,
+    mouse_position: Point,
+    nav_repeat: NavRepeat,
+    pointer_capture: Option<WidgetId>,
+    reference_resolution: Option<Dimensions>,
+    scale: f32,
+    screen_size: Dimensions,
+    text_measurer: TextMeasurer,
+    theme: Theme,
+    theme_file: Option<PathBuf>,
+    theme_file_modified: Option<SystemTime>,
+    theme_file_timer: f32,
 }
 
-impl<T> GuiHandler<T> {
-    /// Creates a new `GuiHandler<T>`.
+impl<T, E: Clone> GuiHandler<T, E> {
+    /// Creates a new `GuiHandler<T, E>`.
     pub fn new(clear_colour: Colour) -> Self {
         Self {
-            actions: Vec::new(),
             additional_draws: Vec::new(),
-            button_action: |_, _| {},
+            button_action: None,
             clear_colour,
+            click_timings: ClickTimings::default(),
             components: Vec::new(),
             components_fixed_widths: false,
-            has_set_button_action: false,
+            events: Vec::new(),
+            focused: None,
+            font: None,
+            font_path: None,
+            input_capture: InputCapture::default(),
+            input_map: InputMap::default(),
+            layouts: Vec::new(),
+            mouse_position: (0, 0),
+            nav_repeat: NavRepeat::default(),
+            pointer_capture: None,
+            reference_resolution: None,
+            scale: 1.0,
+            screen_size: (0, 0),
+            text_measurer: TextMeasurer::default(),
+            theme: Theme::default(),
+            theme_file: None,
+            theme_file_modified: None,
+            theme_file_timer: 0.0,
         }
     }
 
@@ -49,9 +92,9 @@ impl<T> GuiHandler<T> {
         self
     }
 
-    /// Adds an external draw call to be executed before the `GuiHandler<T>`
+    /// Adds an external draw call to be executed before the `GuiHandler<T, E>`
     /// itself is drawn. This fixes an issue where things that would be
-    /// drawn external of the `GuiHandler<T>` have to be drawn over the
+    /// drawn external of the `GuiHandler<T, E>` have to be drawn over the
     /// GuiHandler.
     pub fn add_external_draw(&mut self, external_draw: Box<dyn Drawable>) -> &mut Self {
         self.additional_draws.push(external_draw);
@@ -59,29 +102,49 @@ impl<T> GuiHandler<T> {
         self
     }
 
-    /// Sets the function that will be called everytime any button is pressed.
+    /// Sets the function that will be called with the event of every button
+    /// that is clicked when `execute_actions` is called, for buttons that do
+    /// not have a callback of their own.
     ///
     /// Example function:
-    /// ```
-    /// fn change_state(state: &mut GameStates, state_str: &str) {
-    ///     match state_str {
-    ///         "menu" => *state = GameStates::Menu,
-    ///         "paused" => *state = GameStates::Paused,
-    ///         "quit" => *state = GameStates::Quitting,
-    ///         "play" => *state = GameStates::Playing,
-    ///         "play_reset" => *state = GameStates::Resetting,
-    ///         _ => {},
+    /// ```ignore
+    /// #[derive(Clone)]
+    /// enum MenuEvent {
+    ///     Play,
+    ///     Quit,
+    /// }
+    ///
+    /// fn change_state(state: &mut GameStates, event: &MenuEvent) {
+    ///     match event {
+    ///         MenuEvent::Play => *state = GameStates::Playing,
+    ///         MenuEvent::Quit => *state = GameStates::Quitting,
     ///     }
     /// }
     /// ```
     /// Your code:
-    /// ```
+    /// ```ignore
     /// let mut g_handler = GuiHandler::new(Colour::WHITE);
+    /// g_handler.add_button("Play", MenuEvent::Play);
+    /// g_handler.add_button("Quit", MenuEvent::Quit);
     /// g_handler.set_button_action_function(change_state);
     /// ```
-    pub fn set_button_action_function(&mut self, function: Action<T>) -> &mut Self {
-        self.has_set_button_action = true;
-        self.button_action = function;
+    pub fn set_button_action_function(&mut self, function: Action<T, E>) -> &mut Self {
+        self.button_action = Some(function);
+
+        self
+    }
+
+    /// Sets the `TextMeasurer` used to measure the width of text when sizing
+    /// components, resizing the components that have already been added.
+    /// `render` sets this to the `TextMeasurer` of its renderer, so this only
+    /// needs to be called for renderers that do not have one, or to size
+    /// components before anything has been rendered.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+
+        for component in self.components.iter_mut() {
+            component.set_text_measurer(self.text_measurer.clone());
+        }
 
         self
     }
@@ -94,18 +157,116 @@ impl<T> GuiHandler<T> {
         self
     }
 
+    fn add_component(&mut self, mut component: DrawableType<T, E>) -> WidgetId {
+        component.apply_theme(&self.theme);
+        let id = component.get_id();
+        self.components.push(component);
+
+        id
+    }
+
+    fn create_button(&self, text: &str, event: E, position: Point) -> DrawableType<T, E> {
+        let mut button = Button::new_with_dimensions(text, event, 20, position, (0, 0));
+        button.set_text_measurer(self.text_measurer.clone());
+
+        DrawableType::Button(button)
+    }
+
+    /// Replaces the theme of the `GuiHandler`, restyling and resizing every
+    /// component that has not been given its own style. The theme can be
+    /// swapped at any time, such as when switching between light and dark
+    /// options.
+    pub fn set_theme(&mut self, theme: Theme) -> &mut Self {
+        self.theme = theme;
+
+        for component in self.components.iter_mut() {
+            component.apply_theme(&self.theme);
+        }
+
+        self
+    }
+
+    /// Loads the theme from the TOML file at `path` and watches it, loading it
+    /// again when it changes while the `GuiHandler` is updated. The file is
+    /// checked for changes twice a second rather than on every update. If the
+    /// file becomes invalid, the last theme that loaded is kept and a
+    /// `GuiEvent::ThemeError` describing the problem is produced.
+    pub fn watch_theme_file(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, String> {
+        let path = path.as_ref();
+        let theme = Theme::load(path)?;
+
+        self.theme_file_modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        self.theme_file = Some(path.to_path_buf());
+
+        Ok(self.set_theme(theme))
+    }
+
+    /// Stops watching the theme file given to `watch_theme_file`, keeping the
+    /// current theme.
+    pub fn stop_watching_theme_file(&mut self) -> &mut Self {
+        self.theme_file = None;
+        self.theme_file_modified = None;
+        self.theme_file_timer = 0.0;
+
+        self
+    }
+
+    /// Loads the watched theme file again if it has been modified since it
+    /// was last loaded, once `THEME_FILE_POLL_INTERVAL` has passed since it
+    /// was last checked.
+    fn reload_theme_file(&mut self, frame_time: f32) {
+        let path = match self.theme_file.as_ref() {
+            Some(path) => path,
+            None => return,
+        };
+
+        self.theme_file_timer += frame_time;
+        if self.theme_file_timer < THEME_FILE_POLL_INTERVAL {
+            return;
+        }
+
+        self.theme_file_timer = 0.0;
+
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        if modified == self.theme_file_modified {
+            return;
+        }
+
+        self.theme_file_modified = modified;
+
+        match Theme::load(path) {
+            Ok(theme) => {
+                self.set_theme(theme);
+            },
+            Err(error) => self.events.push(GuiEvent::ThemeError(error)),
+        }
+    }
+
+    /// Returns the current theme of the `GuiHandler`.
+    pub fn get_theme(&self) -> &Theme { &self.theme }
+
+    /// Gives the component with the given `id` its own `style`, which is used
+    /// instead of the theme of the `GuiHandler`.
+    pub fn set_style(&mut self, id: impl Into<WidgetId>, style: WidgetStyle) -> Result<(), String> {
+        match self.get_component_mut(id) {
+            Some(component) => {
+                component.set_style(style);
+                Ok(())
+            },
+            None => Err("Cannot set the style of a component that does not exist".to_string()),
+        }
+    }
+
     /// Adds a button to the `GuiHandler` with a given `position`.
     pub fn add_button_with_position(
         &mut self,
         text: &str,
-        action: &str,
+        event: E,
         position: Point,
-    ) -> &mut Self {
-        self.components.push(DrawableType::Button(Button::new(
-            text, action, 20, position,
-        )));
+    ) -> ButtonHandle {
+        let button = self.create_button(text, event, position);
 
-        self
+        ButtonHandle(self.add_component(button))
     }
 
     /// Adds a `Button` to the `GuiHandler` with automatic positioning. It's
@@ -114,49 +275,165 @@ impl<T> GuiHandler<T> {
     /// then the first `Button` is placed at (0, 0). If a component already
     /// exists then the `Button`s created afterwards are placed n+50 pixels
     /// below the first component.
-    pub fn add_button(&mut self, text: &str, action: &str) -> &mut Self {
+    pub fn add_button(&mut self, text: &str, event: E) -> ButtonHandle {
         let first_dimensions = self.get_first_dimensions();
         let previous_position = self.get_previous_position();
 
-        self.components.push(DrawableType::Button(Button::new(
+        self.add_button_with_position(
             text,
-            action,
-            20,
+            event,
             (
                 previous_position.0,
                 previous_position.1 + first_dimensions.1,
             ),
-        )));
+        )
+    }
+
+    /// Calls the callbacks of every component for anything that happened
+    /// during the last update. Buttons without a callback of their own call
+    /// the function set by `set_button_action_function` with their event.
+    pub fn execute_actions(&mut self, state: &mut T) -> &mut Self {
+        for component in self.components.iter_mut() {
+            component.execute_callbacks(state, self.button_action);
+        }
 
         self
     }
 
-    /// Executes the actions of the buttons
-    pub fn execute_actions(&mut self, state: &mut T) -> &mut Self {
-        for action in self.actions.iter() {
-            (self.button_action)(state, action.as_str());
+    /// Sets the callback of the `Button` or `Label` with the given `id` that
+    /// is called when it is clicked.
+    ///
+    /// ```ignore
+    /// let mute = g_handler.add_button("Mute", MenuEvent::Mute);
+    /// g_handler.on_click(mute, Box::new(move |settings| settings.volume = 0.0));
+    /// ```
+    pub fn on_click(&mut self, id: impl Into<WidgetId>, on_click: Callback<T>) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, id.into()) {
+            component.set_on_click(on_click);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `Slider` with the given `handle` that is
+    /// called with its new value when it changes.
+    pub fn on_change(
+        &mut self,
+        handle: SliderHandle,
+        on_change: ValueCallback<T, f32>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_change(on_change);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `RangeSlider` with the given `handle` that is
+    /// called with its new low and high values when either of them changes.
+    pub fn on_range_change(
+        &mut self,
+        handle: RangeSliderHandle,
+        on_range_change: ValueCallback<T, (f32, f32)>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_range_change(on_range_change);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `Checkbox` with the given `handle` that is
+    /// called with its new `CheckState` when it is clicked.
+    pub fn on_check(
+        &mut self,
+        handle: CheckboxHandle,
+        on_check: ValueCallback<T, CheckState>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_check(on_check);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `Toggle` with the given `handle` that is
+    /// called with its new value when it is clicked.
+    pub fn on_toggle(
+        &mut self,
+        handle: ToggleHandle,
+        on_toggle: ValueCallback<T, bool>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_toggle(on_toggle);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `RadioGroup` or `ComboBox` with the given `id`
+    /// that is called with the index of the newly selected option when the
+    /// selection changes.
+    pub fn on_select(
+        &mut self,
+        id: impl Into<WidgetId>,
+        on_select: ValueCallback<T, usize>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, id.into()) {
+            component.set_on_select(on_select);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `TextBox` with the given `handle` that is
+    /// called with its text when Enter is pressed while it is focused.
+    pub fn on_submit(
+        &mut self,
+        handle: TextBoxHandle,
+        on_submit: ValueCallback<T, String>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_submit(on_submit);
+        }
+
+        self
+    }
+
+    /// Sets the callback of the `Dropdown` with the given `handle` that is
+    /// called with whether or not it is open when it is opened or closed.
+    pub fn on_open(
+        &mut self,
+        handle: DropdownHandle,
+        on_open: ValueCallback<T, bool>,
+    ) -> &mut Self {
+        if let Some(component) = find_component_mut(&mut self.components, handle.id()) {
+            component.set_on_open(on_open);
         }
 
         self
     }
 
+    /// Returns the events produced by the components during the last update.
+    pub fn events(&self) -> &[GuiEvent<E>] { &self.events }
+
+    /// Removes and returns the events produced by the components during the
+    /// last update. Events that are not drained are discarded at the start of
+    /// the next update.
+    pub fn drain_events(&mut self) -> Vec<GuiEvent<E>> { std::mem::take(&mut self.events) }
+
     /// Adds a `Slider` to the `GuiHandler` with a given `position`.
     pub fn add_slider_with_position(
         &mut self,
-        min: i32,
-        max: i32,
+        min: f32,
+        max: f32,
         initial_value: f32,
         position: Point,
-    ) -> &mut Self {
-        self.components.push(DrawableType::Slider(Slider::new(
-            min,
-            max,
-            initial_value,
-            position,
-            100,
-        )));
+    ) -> SliderHandle {
+        let mut slider = Slider::new(min, max, initial_value, position, 100);
+        slider.set_text_measurer(self.text_measurer.clone());
 
-        self
+        SliderHandle(self.add_component(DrawableType::Slider(slider)))
     }
 
     /// Adds a `Slider` to the `GuiHandler` with automatic positioning. It's
@@ -165,11 +442,10 @@ impl<T> GuiHandler<T> {
     /// then the first `Slider` is placed at (0, 0). If a component already
     /// exists then the `Slider`s created afterwards are placed n+50 pixels
     /// below the first component.
-    pub fn add_slider(&mut self, min: i32, max: i32, initial_value: f32) -> &mut Self {
+    pub fn add_slider(&mut self, min: f32, max: f32, initial_value: f32) -> SliderHandle {
         let first_dimensions = self.get_first_dimensions();
         let previous_position = self.get_previous_position();
-
-        self.components.push(DrawableType::Slider(Slider::new(
+        let mut slider = Slider::new(
             min,
             max,
             initial_value,
@@ -178,39 +454,86 @@ impl<T> GuiHandler<T> {
                 previous_position.1 + first_dimensions.1,
             ),
             250,
-        )));
+        );
+        slider.set_text_measurer(self.text_measurer.clone());
 
-        self
+        SliderHandle(self.add_component(DrawableType::Slider(slider)))
     }
 
-    /// Gets the value of a specified `Slider` via an index, returning a `f32`.
-    pub fn get_slider_value(&self, index: usize) -> Result<f32, String> {
-        let mut sliders = vec![];
-        for c in self.components.iter() {
-            if let DrawableType::Slider(s) = c {
-                sliders.push(s)
-            }
-        }
+    /// Adds a `RangeSlider` that selects from `low` to `high` to the
+    /// `GuiHandler` with a given `position`.
+    pub fn add_range_slider_with_position(
+        &mut self,
+        min: f32,
+        max: f32,
+        low: f32,
+        high: f32,
+        position: Point,
+    ) -> RangeSliderHandle {
+        let mut range_slider = RangeSlider::new(min, max, low, high, position, 100);
+        range_slider.set_text_measurer(self.text_measurer.clone());
 
-        if index > sliders.len() {
-            return Err(
-                "Cannot return the value of a Slider that is out of index range".to_string(),
-            );
+        RangeSliderHandle(self.add_component(DrawableType::RangeSlider(range_slider)))
+    }
+
+    /// Adds a `RangeSlider` that selects from `low` to `high` to the
+    /// `GuiHandler` with automatic positioning. It's automatic position is
+    /// determined in the same way as for `add_slider`.
+    pub fn add_range_slider(
+        &mut self,
+        min: f32,
+        max: f32,
+        low: f32,
+        high: f32,
+    ) -> RangeSliderHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+        let mut range_slider = RangeSlider::new(
+            min,
+            max,
+            low,
+            high,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+            250,
+        );
+        range_slider.set_text_measurer(self.text_measurer.clone());
+
+        RangeSliderHandle(self.add_component(DrawableType::RangeSlider(range_slider)))
+    }
+
+    /// Gets the low and high values of the `RangeSlider` with the given
+    /// `handle`.
+    pub fn get_range_slider_values(&self, handle: RangeSliderHandle) -> Result<(f32, f32), String> {
+        match self.get_range_slider(handle) {
+            Some(r) => Ok(r.get_values()),
+            None =>
+                Err("Cannot return the values of a RangeSlider that does not exist".to_string()),
         }
+    }
 
-        Ok(sliders[index].get_value())
+    /// Gets the value of the `Slider` with the given `handle`, returning a
+    /// `f32`. This also finds `Slider`s that were added to a `Dropdown`.
+    pub fn get_slider_value(&self, handle: SliderHandle) -> Result<f32, String> {
+        match self.get_slider(handle) {
+            Some(s) => Ok(s.get_value()),
+            None => Err("Cannot return the value of a Slider that does not exist".to_string()),
+        }
     }
 
-    /// Gets the value of a specified `Slider` via an index, returning an `i32`.
-    pub fn get_slider_value_i32(&self, index: usize) -> Result<i32, String> {
-        match self.get_slider_value(index) {
+    /// Gets the value of the `Slider` with the given `handle`, returning an
+    /// `i32`.
+    pub fn get_slider_value_i32(&self, handle: SliderHandle) -> Result<i32, String> {
+        match self.get_slider_value(handle) {
             Ok(v) => Ok(v as i32),
             Err(e) => Err(e),
         }
     }
 
     fn get_first_dimensions(&self) -> Dimensions {
-        match self.components.get(0) {
+        match self.components.first() {
             Some(c) => c.get_dimensions(),
             None => (0, 50),
         }
@@ -229,32 +552,29 @@ impl<T> GuiHandler<T> {
     /// then the first `Dropdown` is placed at (0, 0). If a component
     /// already exists then the `Dropdown`s created afterwards are placed n+50
     /// pixels below the first component.
-    pub fn add_dropdown(&mut self, text: &str) -> &mut Self {
+    pub fn add_dropdown(&mut self, text: &str) -> DropdownHandle {
         let first_dimensions = self.get_first_dimensions();
         let previous_position = self.get_previous_position();
 
-        self.components.push(DrawableType::Dropdown(Dropdown::new(
+        self.add_dropdown_with_position(
             text,
-            20,
             (
                 previous_position.0,
                 previous_position.1 + first_dimensions.1,
             ),
-        )));
-
-        self
+        )
     }
 
     /// Adds a `Dropdown` to the `GuiHandler` with a given `position`.
-    pub fn add_dropdown_with_position(&mut self, text: &str, position: Point) -> &mut Self {
-        self.components
-            .push(DrawableType::Dropdown(Dropdown::new(text, 20, position)));
+    pub fn add_dropdown_with_position(&mut self, text: &str, position: Point) -> DropdownHandle {
+        let mut dropdown = Dropdown::new_with_dimensions(text, 20, position, (0, 0));
+        dropdown.set_text_measurer(self.text_measurer.clone());
 
-        self
+        DropdownHandle(self.add_component(DrawableType::Dropdown(dropdown)))
     }
 
     /// Gets a vector of mutable `Dropdown` references in the components vector,
-    pub fn get_dropdowns_mut(&mut self) -> Result<Vec<&mut Dropdown>, String> {
+    pub fn get_dropdowns_mut(&mut self) -> Result<Vec<&mut Dropdown<T, E>>, String> {
         let mut dropdown = vec![];
         for c in self.components.iter_mut() {
             if let DrawableType::Dropdown(d) = c {
@@ -266,7 +586,7 @@ impl<T> GuiHandler<T> {
     }
 
     /// Gets a vector of `Dropdown` references in the components vector,
-    pub fn get_dropdowns(&mut self) -> Result<Vec<&Dropdown>, String> {
+    pub fn get_dropdowns(&mut self) -> Result<Vec<&Dropdown<T, E>>, String> {
         let mut dropdown = vec![];
         for c in self.components.iter() {
             if let DrawableType::Dropdown(d) = c {
@@ -283,73 +603,2121 @@ impl<T> GuiHandler<T> {
     /// then the first `Label` is placed at (0, 0). If a component already
     /// exists then the `Label`s created afterwards are placed n+50 pixels below
     /// the first component.
-    pub fn add_label(&mut self, text: &str) -> &mut Self {
+    pub fn add_label(&mut self, text: &str) -> LabelHandle {
         let first_dimensions = self.get_first_dimensions();
         let previous_position = self.get_previous_position();
 
-        self.components.push(DrawableType::Label(Label::new(
+        self.add_label_with_position(
             text,
-            20,
             (
                 previous_position.0,
                 previous_position.1 + first_dimensions.1,
             ),
-        )));
-
-        self
+        )
     }
 
     /// Adds a `Label` to the `GuiHandler` with a given `position`.
-    pub fn add_label_with_position(&mut self, text: &str, position: Point) -> &mut Self {
-        self.components
-            .push(DrawableType::Label(Label::new(text, 20, position)));
+    pub fn add_label_with_position(&mut self, text: &str, position: Point) -> LabelHandle {
+        let mut label = Label::new_with_dimensions(text, 20, position, (0, 0));
+        label.set_text_measurer(self.text_measurer.clone());
 
-        self
+        LabelHandle(self.add_component(DrawableType::Label(label)))
     }
 
-    /// Draws the `GuiHandler` to the screen.
-    pub fn draw<'a>(
+    /// Adds a `Checkbox` labelled with `text` to the `GuiHandler` with
+    /// automatic positioning. It's automatic position is determined in the
+    /// same way as for `add_button`.
+    pub fn add_checkbox(&mut self, text: &str, checked: bool) -> CheckboxHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_checkbox_with_position(
+            text,
+            checked,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds a `Checkbox` labelled with `text` to the `GuiHandler` with a given
+    /// `position`.
+    pub fn add_checkbox_with_position(
         &mut self,
-        rl_handler: &mut RaylibHandle,
-        rl_thread: &RaylibThread,
-    ) -> Result<RaylibDrawHandle<'a>, &str> {
-        let mut draw_handler = rl_handler.begin_drawing(&rl_thread);
+        text: &str,
+        checked: bool,
+        position: Point,
+    ) -> CheckboxHandle {
+        let mut checkbox = Checkbox::new(text, checked, position);
+        checkbox.set_text_measurer(self.text_measurer.clone());
+
+        CheckboxHandle(self.add_component(DrawableType::Checkbox(checkbox)))
+    }
 
-        self.actions.clear();
+    /// Gets the `CheckState` of the `Checkbox` with the given `handle`. This
+    /// also finds `Checkbox`es that were added to a `Dropdown`.
+    pub fn get_checkbox_state(&self, handle: CheckboxHandle) -> Result<CheckState, String> {
+        match self.get_checkbox(handle) {
+            Some(c) => Ok(c.get_check_state()),
+            None => Err("Cannot return the state of a Checkbox that does not exist".to_string()),
+        }
+    }
 
-        let mouse_position = (draw_handler.get_mouse_x(), draw_handler.get_mouse_y());
+    /// Gets whether or not the `Checkbox` with the given `handle` is ticked.
+    pub fn get_checkbox_value(&self, handle: CheckboxHandle) -> Result<bool, String> {
+        match self.get_checkbox_state(handle) {
+            Ok(state) => Ok(state == CheckState::Checked),
+            Err(e) => Err(e),
+        }
+    }
 
-        let buttons = self
-            .components
-            .iter()
-            .filter(|c| match c {
-                DrawableType::Button(_) => true,
-                _ => false,
-            })
-            .count();
+    /// Adds a `Toggle` labelled with `text` to the `GuiHandler` with automatic
+    /// positioning. It's automatic position is determined in the same way as
+    /// for `add_button`.
+    pub fn add_toggle(&mut self, text: &str, value: bool) -> ToggleHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_toggle_with_position(
+            text,
+            value,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
 
-        if !self.has_set_button_action && !buttons.eq(&0) {
-            return Err("Cannot draw. Actions function for buttons has not been set.");
+    /// Adds a `Toggle` labelled with `text` to the `GuiHandler` with a given
+    /// `position`.
+    pub fn add_toggle_with_position(
+        &mut self,
+        text: &str,
+        value: bool,
+        position: Point,
+    ) -> ToggleHandle {
+        let mut toggle = Toggle::new(text, value, position);
+        toggle.set_text_measurer(self.text_measurer.clone());
+
+        ToggleHandle(self.add_component(DrawableType::Toggle(toggle)))
+    }
+
+    /// Gets whether or not the `Toggle` with the given `handle` is on. This
+    /// also finds `Toggle`s that were added to a `Dropdown`.
+    pub fn get_toggle_value(&self, handle: ToggleHandle) -> Result<bool, String> {
+        match self.get_toggle(handle) {
+            Some(t) => Ok(t.get_value()),
+            None => Err("Cannot return the value of a Toggle that does not exist".to_string()),
         }
+    }
 
-        if self.components_fixed_widths {
-            self.components_fix_widths();
+    /// Adds a `RadioGroup` with an option for each of `options` to the
+    /// `GuiHandler` with automatic positioning. It's automatic position is
+    /// determined in the same way as for `add_button`.
+    pub fn add_radio_group(
+        &mut self,
+        options: &[&str],
+        selected: Option<usize>,
+    ) -> RadioGroupHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_radio_group_with_position(
+            options,
+            selected,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds a `RadioGroup` with an option for each of `options` to the
+    /// `GuiHandler` with a given `position`.
+    pub fn add_radio_group_with_position(
+        &mut self,
+        options: &[&str],
+        selected: Option<usize>,
+        position: Point,
+    ) -> RadioGroupHandle {
+        let mut radio_group = RadioGroup::new(options, selected, position);
+        radio_group.set_text_measurer(self.text_measurer.clone());
+
+        RadioGroupHandle(self.add_component(DrawableType::RadioGroup(radio_group)))
+    }
+
+    /// Gets the index of the selected option of the `RadioGroup` with the
+    /// given `handle`, which is `None` if no option is selected.
+    pub fn get_radio_group_selected(
+        &self,
+        handle: RadioGroupHandle,
+    ) -> Result<Option<usize>, String> {
+        match self.get_radio_group(handle) {
+            Some(r) => Ok(r.get_selected()),
+            None =>
+                Err("Cannot return the selection of a RadioGroup that does not exist".to_string()),
         }
+    }
 
-        draw_handler.clear_background(self.clear_colour);
+    /// Adds a closed `ComboBox` with an option for each of `options` to the
+    /// `GuiHandler` with automatic positioning. It's automatic position is
+    /// determined in the same way as for `add_button`.
+    pub fn add_combo_box(&mut self, options: &[&str], selected: Option<usize>) -> ComboBoxHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
 
-        for drawable in self.additional_draws.iter_mut() {
-            drawable.draw(&mut draw_handler);
+        self.add_combo_box_with_position(
+            options,
+            selected,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds a closed `ComboBox` with an option for each of `options` to the
+    /// `GuiHandler` with a given `position`.
+    pub fn add_combo_box_with_position(
+        &mut self,
+        options: &[&str],
+        selected: Option<usize>,
+        position: Point,
+    ) -> ComboBoxHandle {
+        let mut combo_box = ComboBox::new(options, selected, position);
+        combo_box.set_text_measurer(self.text_measurer.clone());
+
+        ComboBoxHandle(self.add_component(DrawableType::ComboBox(combo_box)))
+    }
+
+    /// Gets the index of the selected option of the `ComboBox` with the given
+    /// `handle`, which is `None` if no option is selected.
+    pub fn get_combo_box_selected(&self, handle: ComboBoxHandle) -> Result<Option<usize>, String> {
+        match self.get_combo_box(handle) {
+            Some(c) => Ok(c.get_selected()),
+            None =>
+                Err("Cannot return the selection of a ComboBox that does not exist".to_string()),
         }
+    }
 
-        for component in self.components.iter_mut() {
-            component.draw(&mut draw_handler);
-            component.is_clicked(mouse_position, &mut self.actions, &draw_handler);
+    /// Gets the label of the selected option of the `ComboBox` with the given
+    /// `handle`, which is `None` if no option is selected.
+    pub fn get_combo_box_text(&self, handle: ComboBoxHandle) -> Result<Option<&str>, String> {
+        match self.get_combo_box(handle) {
+            Some(c) => Ok(c.get_selected_text()),
+            None =>
+                Err("Cannot return the selection of a ComboBox that does not exist".to_string()),
         }
+    }
 
-        // SAFETY: makes sure that the draw_handler is returned to the correct scope.
-        Ok(unsafe {
-            std::mem::transmute::<RaylibDrawHandle<'_>, RaylibDrawHandle<'a>>(draw_handler)
-        })
+    /// Adds an empty `TextBox` that is `width` pixels wide to the
+    /// `GuiHandler` with automatic positioning. It's automatic position is
+    /// determined in the same way as for `add_button`.
+    pub fn add_text_box(&mut self, width: i32) -> TextBoxHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_text_box_with_position(
+            width,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds an empty `TextBox` that is `width` pixels wide to the
+    /// `GuiHandler` with a given `position`.
+    pub fn add_text_box_with_position(&mut self, width: i32, position: Point) -> TextBoxHandle {
+        let mut text_box = TextBox::new(position, width);
+        text_box.set_text_measurer(self.text_measurer.clone());
+
+        TextBoxHandle(self.add_component(DrawableType::TextBox(text_box)))
+    }
+
+    /// Adds a `ScrollBar` that is `length` pixels long to the `GuiHandler`
+    /// with automatic positioning. It's automatic position is determined in
+    /// the same way as for `add_button`.
+    pub fn add_scroll_bar(&mut self, length: i32, orientation: Orientation) -> ScrollBarHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_scroll_bar_with_position(
+            length,
+            orientation,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds a `ScrollBar` that is `length` pixels long to the `GuiHandler`
+    /// with a given `position`. Use `get_scroll_bar_mut` to set the length of
+    /// the content it scrolls through.
+    pub fn add_scroll_bar_with_position(
+        &mut self,
+        length: i32,
+        orientation: Orientation,
+        position: Point,
+    ) -> ScrollBarHandle {
+        ScrollBarHandle(self.add_component(DrawableType::ScrollBar(ScrollBar::new(
+            position,
+            length,
+            orientation,
+        ))))
+    }
+
+    /// Adds an empty `ScrollPanel` with the given `dimensions` to the
+    /// `GuiHandler` with automatic positioning. It's automatic position is
+    /// determined in the same way as for `add_button`.
+    pub fn add_scroll_panel(&mut self, dimensions: Dimensions) -> ScrollPanelHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_scroll_panel_with_position(
+            dimensions,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds an empty `ScrollPanel` with the given `dimensions` to the
+    /// `GuiHandler` with a given `position`. Use `get_scroll_panel_mut` to add
+    /// components to it.
+    pub fn add_scroll_panel_with_position(
+        &mut self,
+        dimensions: Dimensions,
+        position: Point,
+    ) -> ScrollPanelHandle {
+        let mut scroll_panel = ScrollPanel::new(position, dimensions);
+        scroll_panel.set_text_measurer(self.text_measurer.clone());
+
+        ScrollPanelHandle(self.add_component(DrawableType::ScrollPanel(scroll_panel)))
+    }
+
+    /// Adds a `ProgressBar` with the given `dimensions`, showing `value`
+    /// between `min` and `max`, to the `GuiHandler` with automatic
+    /// positioning. It's automatic position is determined in the same way as
+    /// for `add_button`.
+    pub fn add_progress_bar(
+        &mut self,
+        min: f32,
+        max: f32,
+        value: f32,
+        dimensions: Dimensions,
+    ) -> ProgressBarHandle {
+        let first_dimensions = self.get_first_dimensions();
+        let previous_position = self.get_previous_position();
+
+        self.add_progress_bar_with_position(
+            min,
+            max,
+            value,
+            dimensions,
+            (
+                previous_position.0,
+                previous_position.1 + first_dimensions.1,
+            ),
+        )
+    }
+
+    /// Adds a `ProgressBar` with the given `dimensions`, showing `value`
+    /// between `min` and `max`, to the `GuiHandler` with a given `position`.
+    pub fn add_progress_bar_with_position(
+        &mut self,
+        min: f32,
+        max: f32,
+        value: f32,
+        dimensions: Dimensions,
+        position: Point,
+    ) -> ProgressBarHandle {
+        let mut progress_bar = ProgressBar::new(min, max, value, position, dimensions);
+        progress_bar.set_text_measurer(self.text_measurer.clone());
+
+        ProgressBarHandle(self.add_component(DrawableType::ProgressBar(progress_bar)))
+    }
+
+    /// Gets the value of the `ProgressBar` with the given `handle`.
+    pub fn get_progress_bar_value(&self, handle: ProgressBarHandle) -> Result<f32, String> {
+        match self.get_progress_bar(handle) {
+            Some(p) => Ok(p.get_value()),
+            None => Err("Cannot return the value of a ProgressBar that does not exist".to_string()),
+        }
+    }
+
+    /// Sets the value of the `ProgressBar` with the given `handle`, which it
+    /// moves towards smoothly if it has been given a smoothing time.
+    pub fn set_progress_bar_value(
+        &mut self,
+        handle: ProgressBarHandle,
+        value: f32,
+    ) -> Result<(), String> {
+        match self.get_progress_bar_mut(handle) {
+            Some(p) => {
+                p.set_value(value);
+                Ok(())
+            },
+            None => Err("Cannot set the value of a ProgressBar that does not exist".to_string()),
+        }
+    }
+
+    /// Gets the text of the `TextBox` with the given `handle`.
+    pub fn get_text_box_text(&self, handle: TextBoxHandle) -> Result<&str, String> {
+        match self.get_text_box(handle) {
+            Some(t) => Ok(t.get_text()),
+            None => Err("Cannot return the text of a TextBox that does not exist".to_string()),
+        }
+    }
+
+    /// Gets the component with the given `id`, searching inside of any
+    /// `Dropdown`s and `ScrollPanel`s as well.
+    pub fn get_component(&self, id: impl Into<WidgetId>) -> Option<&DrawableType<T, E>> {
+        find_component(&self.components, id.into())
+    }
+
+    /// Gets the component with the given `id` mutably, searching inside of
+    /// any `Dropdown`s and `ScrollPanel`s as well.
+    pub fn get_component_mut(
+        &mut self,
+        id: impl Into<WidgetId>,
+    ) -> Option<&mut DrawableType<T, E>> {
+        find_component_mut(&mut self.components, id.into())
+    }
+
+    /// Gets the `Button` with the given `handle`.
+    pub fn get_button_mut(&mut self, handle: ButtonHandle) -> Option<&mut Button<T, E>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Button(b)) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Slider` with the given `handle`.
+    pub fn get_slider(&self, handle: SliderHandle) -> Option<&Slider<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::Slider(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Slider` with the given `handle` mutably, so that its
+    /// orientation, step, scale and formatting can be set.
+    pub fn get_slider_mut(&mut self, handle: SliderHandle) -> Option<&mut Slider<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Slider(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ComboBox` with the given `handle`.
+    pub fn get_combo_box(&self, handle: ComboBoxHandle) -> Option<&ComboBox<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::ComboBox(c)) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ComboBox` with the given `handle` mutably, so that options
+    /// can be added to it.
+    pub fn get_combo_box_mut(&mut self, handle: ComboBoxHandle) -> Option<&mut ComboBox<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::ComboBox(c)) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Dropdown` with the given `handle`.
+    pub fn get_dropdown(&self, handle: DropdownHandle) -> Option<&Dropdown<T, E>> {
+        match self.get_component(handle) {
+            Some(DrawableType::Dropdown(d)) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Dropdown` with the given `handle` mutably, so that components
+    /// can be added to it.
+    pub fn get_dropdown_mut(&mut self, handle: DropdownHandle) -> Option<&mut Dropdown<T, E>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Dropdown(d)) => Some(d),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Label` with the given `handle` mutably.
+    pub fn get_label_mut(&mut self, handle: LabelHandle) -> Option<&mut Label<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Label(l)) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Checkbox` with the given `handle`.
+    pub fn get_checkbox(&self, handle: CheckboxHandle) -> Option<&Checkbox<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::Checkbox(c)) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Checkbox` with the given `handle` mutably.
+    pub fn get_checkbox_mut(&mut self, handle: CheckboxHandle) -> Option<&mut Checkbox<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Checkbox(c)) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Toggle` with the given `handle`.
+    pub fn get_toggle(&self, handle: ToggleHandle) -> Option<&Toggle<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::Toggle(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Toggle` with the given `handle` mutably, so that the duration
+    /// of its animation can be set.
+    pub fn get_toggle_mut(&mut self, handle: ToggleHandle) -> Option<&mut Toggle<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::Toggle(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ProgressBar` with the given `handle`.
+    pub fn get_progress_bar(&self, handle: ProgressBarHandle) -> Option<&ProgressBar> {
+        match self.get_component(handle) {
+            Some(DrawableType::ProgressBar(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ProgressBar` with the given `handle` mutably, so that its
+    /// fill direction, segments and smoothing can be set.
+    pub fn get_progress_bar_mut(&mut self, handle: ProgressBarHandle) -> Option<&mut ProgressBar> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::ProgressBar(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Gets the `RadioGroup` with the given `handle`.
+    pub fn get_radio_group(&self, handle: RadioGroupHandle) -> Option<&RadioGroup<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::RadioGroup(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Gets the `RadioGroup` with the given `handle` mutably, so that options
+    /// can be added to it.
+    pub fn get_radio_group_mut(&mut self, handle: RadioGroupHandle) -> Option<&mut RadioGroup<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::RadioGroup(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Gets the `RangeSlider` with the given `handle`.
+    pub fn get_range_slider(&self, handle: RangeSliderHandle) -> Option<&RangeSlider<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::RangeSlider(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Gets the `RangeSlider` with the given `handle` mutably, so that its
+    /// orientation, step, scale and formatting can be set.
+    pub fn get_range_slider_mut(
+        &mut self,
+        handle: RangeSliderHandle,
+    ) -> Option<&mut RangeSlider<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::RangeSlider(r)) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ScrollBar` with the given `handle`.
+    pub fn get_scroll_bar(&self, handle: ScrollBarHandle) -> Option<&ScrollBar> {
+        match self.get_component(handle) {
+            Some(DrawableType::ScrollBar(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ScrollBar` with the given `handle` mutably, so that the
+    /// length of the content it scrolls through can be set.
+    pub fn get_scroll_bar_mut(&mut self, handle: ScrollBarHandle) -> Option<&mut ScrollBar> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::ScrollBar(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ScrollPanel` with the given `handle`.
+    pub fn get_scroll_panel(&self, handle: ScrollPanelHandle) -> Option<&ScrollPanel<T, E>> {
+        match self.get_component(handle) {
+            Some(DrawableType::ScrollPanel(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Gets the `ScrollPanel` with the given `handle` mutably, so that
+    /// components can be added to it.
+    pub fn get_scroll_panel_mut(
+        &mut self,
+        handle: ScrollPanelHandle,
+    ) -> Option<&mut ScrollPanel<T, E>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::ScrollPanel(p)) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Gets the `TextBox` with the given `handle`.
+    pub fn get_text_box(&self, handle: TextBoxHandle) -> Option<&TextBox<T>> {
+        match self.get_component(handle) {
+            Some(DrawableType::TextBox(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Gets the `TextBox` with the given `handle` mutably, so that its
+    /// placeholder, maximum length and masking can be set.
+    pub fn get_text_box_mut(&mut self, handle: TextBoxHandle) -> Option<&mut TextBox<T>> {
+        match self.get_component_mut(handle) {
+            Some(DrawableType::TextBox(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Sets the text of the `Button`, `Checkbox`, `Dropdown`, `Label`,
+    /// `TextBox` or `Toggle` with the given `id`.
+    pub fn set_text(&mut self, id: impl Into<WidgetId>, text: &str) -> Result<(), String> {
+        match self.get_component_mut(id) {
+            Some(component) => {
+                component.set_text(text);
+                Ok(())
+            },
+            None => Err("Cannot set the text of a component that does not exist".to_string()),
+        }
+    }
+
+    /// Shows or hides the component with the given `id`. Hidden components are
+    /// not drawn, do not respond to input and are left out of `Layout`s, so
+    /// the components around them close the gap.
+    pub fn set_visible(&mut self, id: impl Into<WidgetId>, visible: bool) -> Result<(), String> {
+        match self.get_component_mut(id) {
+            Some(component) => {
+                component.set_visible(visible);
+                Ok(())
+            },
+            None => Err("Cannot show or hide a component that does not exist".to_string()),
+        }
+    }
+
+    /// Enables or disables the component with the given `id`. Disabled
+    /// components are drawn in the disabled colours of the theme, ignore
+    /// input and are skipped when focus is moved.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// let continue_game = g_handler.add_button("Continue", MenuEvent::Continue);
+    /// g_handler.set_enabled(continue_game, save_file.exists())?;
+    /// ```
+    pub fn set_enabled(&mut self, id: impl Into<WidgetId>, enabled: bool) -> Result<(), String> {
+        match self.get_component_mut(id) {
+            Some(component) => {
+                component.set_enabled(enabled);
+                Ok(())
+            },
+            None => Err("Cannot enable or disable a component that does not exist".to_string()),
+        }
+    }
+
+    /// Returns whether or not the component with the given `id` responds to
+    /// input.
+    pub fn is_enabled(&self, id: impl Into<WidgetId>) -> Result<bool, String> {
+        match self.get_component(id) {
+            Some(component) => Ok(component.is_enabled()),
+            None => Err("Cannot check a component that does not exist".to_string()),
+        }
+    }
+
+    /// Moves the component with the given `id` to `position`.
+    pub fn set_position(&mut self, id: impl Into<WidgetId>, position: Point) -> Result<(), String> {
+        match self.get_component_mut(id) {
+            Some(component) => {
+                component.set_position(position);
+                Ok(())
+            },
+            None => Err("Cannot move a component that does not exist".to_string()),
+        }
+    }
+
+    /// Removes the component with the given `id`, including components that
+    /// were added to a `Dropdown` or `ScrollPanel`, returning it if it was found.
+    pub fn remove(&mut self, id: impl Into<WidgetId>) -> Option<DrawableType<T, E>> {
+        let id = id.into();
+
+        for layout in self.layouts.iter_mut() {
+            layout.remove(id);
+        }
+
+        remove_component(&mut self.components, id)
+    }
+
+    /// Adds a `Layout` that positions and sizes the components it refers to
+    /// every frame, overriding the positions they were added with.
+    pub fn add_layout(&mut self, layout: Layout) -> LayoutHandle {
+        let id = layout.get_id();
+        self.layouts.push(layout);
+        self.layout_components();
+
+        LayoutHandle(id)
+    }
+
+    /// Gets the `Layout` with the given `handle`.
+    pub fn get_layout(&self, handle: LayoutHandle) -> Option<&Layout> {
+        self.layouts.iter().find(|l| l.get_id() == handle.id())
+    }
+
+    /// Gets the `Layout` with the given `handle` mutably.
+    pub fn get_layout_mut(&mut self, handle: LayoutHandle) -> Option<&mut Layout> {
+        self.layouts.iter_mut().find(|l| l.get_id() == handle.id())
+    }
+
+    /// Removes the `Layout` with the given `handle`, leaving its components
+    /// where they were last placed.
+    pub fn remove_layout(&mut self, handle: LayoutHandle) -> Option<Layout> {
+        let index = self
+            .layouts
+            .iter()
+            .position(|l| l.get_id() == handle.id())?;
+
+        Some(self.layouts.remove(index))
+    }
+
+    fn layout_components(&mut self) {
+        let scale = self.get_scale();
+        let screen_size = (
+            (self.screen_size.0 as f32 / scale) as i32,
+            (self.screen_size.1 as f32 / scale) as i32,
+        );
+
+        for layout in self.layouts.iter_mut() {
+            layout.apply((0, 0), screen_size, &mut self.components);
+        }
+    }
+
+    /// Returns the size of the screen as of the last update, which anchored
+    /// `Layout`s are placed relative to.
+    pub fn get_screen_size(&self) -> Dimensions { self.screen_size }
+
+    /// Sets the factor that every component is scaled by when it is drawn,
+    /// including its font size, and that the mouse position is scaled by when
+    /// checking whether a component is hovered or clicked. Components are
+    /// still added and positioned as if the scale were 1.0.
+    pub fn set_scale(&mut self, scale: f32) -> &mut Self {
+        self.scale = scale.max(f32::EPSILON);
+
+        self
+    }
+
+    /// Makes the scale follow the size of the screen, so that a GUI designed
+    /// for `reference_resolution` fills the same proportion of any screen. The
+    /// scale set with `set_scale` is applied on top of this.
+    pub fn set_reference_resolution(&mut self, reference_resolution: Dimensions) -> &mut Self {
+        self.reference_resolution = Some(reference_resolution);
+
+        self
+    }
+
+    /// Stops the scale from following the size of the screen.
+    pub fn clear_reference_resolution(&mut self) -> &mut Self {
+        self.reference_resolution = None;
+
+        self
+    }
+
+    /// Returns the factor that every component is currently scaled by.
+    pub fn get_scale(&self) -> f32 {
+        match self.reference_resolution {
+            Some(reference) if self.screen_size.0 > 0 && self.screen_size.1 > 0 => {
+                let horizontal = self.screen_size.0 as f32 / reference.0.max(1) as f32;
+                let vertical = self.screen_size.1 as f32 / reference.1.max(1) as f32;
+
+                self.scale * horizontal.min(vertical)
+            },
+            _ => self.scale,
+        }
+    }
+
+    /// Returns whether or not the GUI used the mouse and keyboard during the
+    /// last update, so that the game can ignore them when it did.
+    ///
+    /// ```ignore
+    /// let draw_handler = g_handler.draw(&mut rl_handler, &rl_thread);
+    ///
+    /// if !g_handler.get_input_capture().mouse
+    ///     && draw_handler.is_mouse_button_pressed(MouseButton::MOUSE_LEFT_BUTTON)
+    /// {
+    ///     fire_weapon();
+    /// }
+    /// ```
+    pub fn get_input_capture(&self) -> InputCapture { self.input_capture }
+
+    /// Sets how quickly a second click has to follow the first to make a
+    /// double-click, and how long the mouse has to be held to make a long
+    /// press.
+    pub fn set_click_timings(&mut self, click_timings: ClickTimings) -> &mut Self {
+        self.click_timings = click_timings;
+
+        self
+    }
+
+    /// Returns how quickly clicks have to follow each other to make a
+    /// double-click, and how long the mouse has to be held to make a long
+    /// press.
+    pub fn get_click_timings(&self) -> ClickTimings { self.click_timings }
+
+    /// Returns the id of whatever holds the pointer capture, which is the
+    /// component the left mouse button was pressed on, or a custom widget
+    /// that took it with `capture_pointer`.
+    pub fn get_pointer_capture(&self) -> Option<WidgetId> { self.pointer_capture }
+
+    /// Gives the mouse to the component or custom widget with the given `id`
+    /// until the left mouse button is released, so that it can keep being
+    /// dragged wherever the mouse goes. Every other component is given a
+    /// mouse that is nowhere near it in the meantime. Custom widgets can get
+    /// an id to use with `WidgetId::unique`, and are sent a
+    /// `GuiEvent::PointerMoved` whenever the mouse moves and a
+    /// `GuiEvent::PointerReleased` when the button is released.
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // A custom widget that is pressed takes the mouse, so that the
+    /// // components underneath it do not respond while it is dragged.
+    /// if minimap.is_pressed(&input) {
+    ///     g_handler.capture_pointer(minimap.id);
+    /// }
+    ///
+    /// for event in g_handler.events() {
+    ///     match event {
+    ///         GuiEvent::PointerMoved { widget, position } if *widget == minimap.id =>
+    ///             minimap.drag_to(*position),
+    ///         GuiEvent::PointerReleased { widget, .. } if *widget == minimap.id =>
+    ///             minimap.drop(),
+    ///         _ => (),
+    ///     }
+    /// }
+    /// ```
+    pub fn capture_pointer(&mut self, id: impl Into<WidgetId>) -> &mut Self {
+        self.pointer_capture = Some(id.into());
+
+        self
+    }
+
+    /// Takes the pointer capture away from whatever holds it, giving the
+    /// mouse back to every component.
+    pub fn release_pointer(&mut self) -> &mut Self {
+        self.pointer_capture = None;
+
+        self
+    }
+
+    /// Returns the id of the component that has keyboard or gamepad focus, if
+    /// there is one.
+    pub fn get_focused(&self) -> Option<WidgetId> { self.focused }
+
+    /// Gives keyboard or gamepad focus to the component with the given `id`,
+    /// including components that were added to a `Dropdown` or
+    /// `ScrollPanel`, scrolling it into view.
+    pub fn set_focus(&mut self, id: impl Into<WidgetId>) -> Result<(), String> {
+        let id = id.into();
+
+        match find_component(&self.components, id) {
+            Some(component) if component.is_focusable() => {
+                self.move_focus(Some(id));
+
+                Ok(())
+            },
+            Some(_) => Err("Cannot focus a component that does not respond to input".to_string()),
+            None => Err("Cannot focus a component that does not exist".to_string()),
+        }
+    }
+
+    /// Takes keyboard or gamepad focus away from the focused component.
+    pub fn clear_focus(&mut self) -> &mut Self {
+        self.move_focus(None);
+
+        self
+    }
+
+    /// Sets which keys and gamepad buttons move focus and use the focused
+    /// component.
+    pub fn set_input_map(&mut self, input_map: InputMap) -> &mut Self {
+        self.input_map = input_map;
+
+        self
+    }
+
+    /// Returns the keys and gamepad buttons that move focus and use the
+    /// focused component.
+    pub fn get_input_map(&self) -> &InputMap { &self.input_map }
+
+    /// Returns the keys and gamepad buttons that move focus and use the
+    /// focused component mutably, so that they can be rebound.
+    pub fn get_input_map_mut(&mut self) -> &mut InputMap { &mut self.input_map }
+
+    /// Returns the ids of the components that can be focused, in the order
+    /// that focus moves through them. Components in a `Layout` come first in
+    /// the order they are placed, followed by the rest in the order they were
+    /// added.
+    pub fn get_focus_order(&self) -> Vec<WidgetId> {
+        let mut ids = Vec::new();
+
+        for id in self.layouts.iter().flat_map(|l| l.get_widget_ids()) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        for component in self.components.iter() {
+            if !ids.contains(&component.get_id()) {
+                ids.push(component.get_id());
+            }
+        }
+
+        let mut order = Vec::new();
+        for id in ids {
+            if let Some(component) = self.components.iter().find(|c| c.get_id() == id) {
+                collect_component_focus_order(component, &mut order);
+            }
+        }
+
+        order
+    }
+
+    fn move_focus(&mut self, id: Option<WidgetId>) {
+        if self.focused == id {
+            return;
+        }
+
+        if let Some(component) = self
+            .focused
+            .and_then(|focused| find_component_mut(&mut self.components, focused))
+        {
+            component.set_focused(false);
+        }
+
+        self.focused = id;
+
+        if let Some(id) = id {
+            if let Some(component) = find_component_mut(&mut self.components, id) {
+                component.set_focused(true);
+            }
+
+            scroll_into_view(&mut self.components, id);
+        }
+    }
+
+    /// Moves focus `offset` places through the focus order, wrapping around
+    /// at either end. If nothing is focused, the first or last component is
+    /// focused instead.
+    fn move_focus_by(&mut self, offset: i32) {
+        let order = self.get_focus_order();
+        if order.is_empty() {
+            return;
+        }
+
+        let len = order.len() as i32;
+        let index = match self
+            .focused
+            .and_then(|focused| order.iter().position(|&id| id == focused))
+        {
+            Some(index) => (index as i32 + offset).rem_euclid(len),
+            None if offset < 0 => len - 1,
+            None => 0,
+        };
+
+        self.move_focus(Some(order[index as usize]));
+    }
+
+    /// Focuses the component that the mouse is pressed on, or takes focus
+    /// away if it is pressed anywhere else.
+    fn focus_under_mouse(&mut self, input: &InputState) {
+        if !input.is_button_pressed(PointerButton::Left) {
+            return;
+        }
+
+        let mouse_position = input.mouse_position;
+        let pressed = self.get_focus_order().into_iter().rev().find(|&id| {
+            find_component(&self.components, id).is_some_and(|c| {
+                is_inside(c.get_position(), c.get_dimensions(), mouse_position)
+                    || c.is_popup_hovered(mouse_position)
+            })
+        });
+
+        self.move_focus(pressed);
+    }
+
+    /// Moves focus and uses the focused component with the keys and gamepad
+    /// buttons in `input`. While a `TextBox` was being typed into at the
+    /// start of the update, only the actions that move focus up, down or
+    /// through the focus order are performed. Returns `true` if any action
+    /// moved focus, was used by the focused component or switched tabs.
+    fn navigate(&mut self, input: &InputState, editing: bool) -> bool {
+        // Focus is lost when the focused component is removed, hidden or shut
+        // inside of a closed `Dropdown`.
+        if let Some(focused) = self.focused {
+            if !self.get_focus_order().contains(&focused) {
+                self.move_focus(None);
+            }
+        }
+
+        let adjustable = self
+            .focused
+            .and_then(|focused| find_component(&self.components, focused))
+            .is_some_and(|c| c.is_adjustable());
+        let actions = self
+            .input_map
+            .actions(input, &mut self.nav_repeat, !adjustable);
+
+        if let Some(focused) = self.focused.filter(|_| adjustable) {
+            let fraction = self.input_map.slider_adjustment(input);
+
+            if let Some(component) = find_component_mut(&mut self.components, focused) {
+                component.adjust(fraction, &mut self.events);
+            }
+        }
+
+        let mut consumed = false;
+        for action in actions {
+            if editing
+                && matches!(
+                    action,
+                    NavAction::Left | NavAction::Right | NavAction::Activate | NavAction::Back
+                )
+            {
+                continue;
+            }
+
+            let component = match self.focused {
+                Some(focused) => find_component_mut(&mut self.components, focused),
+                None => None,
+            };
+            let used = match component {
+                Some(component) => component.navigate(action, &mut self.events),
+                None => false,
+            };
+            if used {
+                consumed = true;
+                continue;
+            }
+
+            let focused = self.focused;
+            match action {
+                NavAction::Up | NavAction::Left | NavAction::Previous => self.move_focus_by(-1),
+                NavAction::Down | NavAction::Right | NavAction::Next => self.move_focus_by(1),
+                NavAction::Back => {
+                    let dropdown = self
+                        .focused
+                        .and_then(|focused| find_parent_dropdown(&self.components, focused));
+
+                    if let Some(dropdown) = dropdown {
+                        self.move_focus(Some(dropdown));
+
+                        if let Some(component) = find_component_mut(&mut self.components, dropdown)
+                        {
+                            component.navigate(NavAction::Back, &mut self.events);
+                        }
+                    }
+                },
+                NavAction::NextTab => self.events.push(GuiEvent::NextTab),
+                NavAction::PreviousTab => self.events.push(GuiEvent::PreviousTab),
+                NavAction::Activate => (),
+            }
+
+            consumed |= self.focused != focused
+                || matches!(action, NavAction::NextTab | NavAction::PreviousTab);
+        }
+
+        consumed
+    }
+
+    /// Sends the mouse to the custom widget that holds the pointer capture, if
+    /// there is one, as a `GuiEvent::PointerMoved` or a
+    /// `GuiEvent::PointerReleased`. Components are given the mouse when they
+    /// are updated instead.
+    fn send_pointer_events(&mut self, input: &InputState) {
+        let position = input.mouse_position;
+        let custom_capture = self
+            .pointer_capture
+            .filter(|&id| find_component(&self.components, id).is_none());
+
+        if let Some(widget) = custom_capture {
+            if !input.is_button_down(PointerButton::Left) {
+                self.events
+                    .push(GuiEvent::PointerReleased { widget, position });
+            } else if position != self.mouse_position {
+                self.events
+                    .push(GuiEvent::PointerMoved { widget, position });
+            }
+        }
+
+        self.mouse_position = position;
+    }
+
+    /// Returns `true` if the focused component is a `TextBox` that is being
+    /// typed into.
+    fn is_editing_text(&self) -> bool {
+        matches!(
+            self.focused.and_then(|focused| find_component(&self.components, focused)),
+            Some(DrawableType::TextBox(t)) if t.is_editing()
+        )
+    }
+
+    /// Updates every component of the `GuiHandler` with the given `input`,
+    /// replacing the events from the previous update with the ones produced.
+    /// If the size of the screen has changed, a `GuiEvent::ScreenResized` is
+    /// produced and every `Layout` is placed again to fit it. Focus is then
+    /// moved and the focused component is used with the keys and gamepad
+    /// buttons of the `InputMap`, and whether the GUI used the mouse and
+    /// keyboard is stored for `get_input_capture`. Pressing the left mouse
+    /// button gives the pointer capture to the component under it, which then
+    /// gets the mouse to itself until the button is released. A custom widget
+    /// holding the pointer capture is told what the mouse does through
+    /// events instead.
+    pub fn update(&mut self, input: &InputState) -> &mut Self {
+        self.events.clear();
+
+        if input.screen_size != self.screen_size {
+            self.screen_size = input.screen_size;
+            self.events.push(GuiEvent::ScreenResized(self.screen_size));
+        }
+
+        self.reload_theme_file(input.frame_time);
+
+        let scale = self.get_scale();
+        let mut input = input.clone();
+        input.mouse_position = (
+            (input.mouse_position.0 as f32 / scale) as i32,
+            (input.mouse_position.1 as f32 / scale) as i32,
+        );
+
+        let editing = self.is_editing_text();
+
+        self.layout_components();
+        // Checked before the components are updated, so that a press that
+        // closes a popup still counts as being over it.
+        let mouse_over = is_mouse_over_components(&self.components, input.mouse_position);
+        self.send_pointer_events(&input);
+        if !input.is_button_down(PointerButton::Left) {
+            self.pointer_capture = None;
+        } else if input.is_button_pressed(PointerButton::Left) && self.pointer_capture.is_none() {
+            self.pointer_capture = find_component_at(&self.components, input.mouse_position);
+        }
+        input.pointer_capture = self.pointer_capture;
+        input.click_timings = self.click_timings;
+
+        self.focus_under_mouse(&input);
+        input.focused = self.focused;
+        update_components(&mut self.components, &input, &mut self.events);
+        let navigated = self.navigate(&input, editing);
+
+        self.input_capture = InputCapture {
+            mouse: mouse_over || self.pointer_capture.is_some() || has_open_popup(&self.components),
+            keyboard: navigated || has_editing_text_box(&self.components),
+        };
+
+        self
+    }
+
+    /// Executes the external draws and then draws every component of the
+    /// `GuiHandler` through the given `renderer`, without clearing the
+    /// background. If the renderer measures text differently to the
+    /// components, they are resized with its `TextMeasurer` first.
+    pub fn render(&mut self, renderer: &mut dyn Renderer) -> &mut Self {
+        if let Some(text_measurer) = renderer.text_measurer() {
+            if text_measurer != self.text_measurer {
+                self.set_text_measurer(text_measurer);
+            }
+        }
+
+        if self.components_fixed_widths {
+            self.components_fix_widths();
+        }
+
+        self.layout_components();
+
+        for drawable in self.additional_draws.iter_mut() {
+            drawable.draw(renderer);
+        }
+
+        let mut renderer = ScaledRenderer::new(renderer, self.get_scale());
+        draw_components(&mut self.components, &mut renderer);
+
+        self
+    }
+
+    /// Loads the font of the theme if it has changed since it was last loaded.
+    fn load_theme_font(
+        &mut self,
+        rl_handler: &mut RaylibHandle,
+        rl_thread: &RaylibThread,
+    ) -> Result<(), String> {
+        if self.theme.font == self.font_path {
+            return Ok(());
+        }
+
+        self.font_path = self.theme.font.clone();
+        self.font = None;
+
+        if let Some(path) = self.font_path.as_ref() {
+            self.font = Some(Rc::new(rl_handler.load_font(rl_thread, path)?));
+        }
+
+        Ok(())
+    }
+
+    /// Draws the `GuiHandler` to the screen, returning the draw handle of the
+    /// frame so that more can be drawn over the top of it. If the font of the
+    /// theme cannot be loaded, raylib's default font is used instead and a
+    /// `GuiEvent::ThemeError` is produced.
+    pub fn draw<'a>(
+        &mut self,
+        rl_handler: &mut RaylibHandle,
+        rl_thread: &RaylibThread,
+    ) -> RaylibDrawHandle<'a> {
+        let input = InputState::from_raylib(rl_handler);
+        let font_error = self.load_theme_font(rl_handler, rl_thread).err();
+        let mut draw_handler = rl_handler.begin_drawing(rl_thread);
+
+        draw_handler.clear_background(self.clear_colour);
+
+        self.update(&input);
+        if let Some(error) = font_error {
+            self.events.push(GuiEvent::ThemeError(error));
+        }
+
+        self.render(&mut RaylibRenderer::new(&mut draw_handler).with_font(self.font.clone()));
+
+        // SAFETY: makes sure that the draw_handler is returned to the correct scope.
+        unsafe { std::mem::transmute::<RaylibDrawHandle<'_>, RaylibDrawHandle<'a>>(draw_handler) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `GuiHandler` that measures text without raylib, which has already
+    /// seen the size of the screen so that it does not report it again.
+    fn handler() -> GuiHandler<(), &'static str> {
+        let mut g_handler = GuiHandler::new(Colour::WHITE);
+        g_handler
+            .set_text_measurer(TextMeasurer::new(measure_text_monospace))
+            .update(&input());
+
+        g_handler
+    }
+
+    fn input() -> InputState { InputState::new().with_screen_size((800, 600)) }
+
+    #[test]
+    fn render_sizes_components_with_the_renderers_text_measurer() {
+        let mut g_handler = GuiHandler::<(), &str>::new(Colour::WHITE);
+        let button = g_handler.add_button("Hello", "hello");
+        let style = g_handler.get_theme().button.clone();
+
+        g_handler.update(&input());
+        g_handler.render(&mut RecordingRenderer::new());
+
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_dimensions(),
+            style.fit_text(measure_text_monospace("Hello", style.font_size))
+        );
+    }
+
+    #[test]
+    fn pressing_and_releasing_over_a_button_clicks_it() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button_with_position("Hello", "hello", (100, 100));
+        let over = input().with_mouse_position((105, 105));
+
+        g_handler.update(&over.clone().with_button_pressed(PointerButton::Left));
+        assert_eq!(g_handler.events(), &[]);
+        assert!(g_handler.get_input_capture().mouse);
+
+        g_handler.update(&over.with_button_released(PointerButton::Left));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::ButtonClicked {
+                button,
+                event: "hello"
+            }]
+        );
+    }
+
+    #[test]
+    fn releasing_off_of_a_button_does_not_click_it() {
+        let mut g_handler = handler();
+        g_handler.add_button_with_position("Hello", "hello", (100, 100));
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((105, 105))
+                .with_button_pressed(PointerButton::Left),
+        );
+        g_handler.update(
+            &input()
+                .with_mouse_position((5, 5))
+                .with_button_released(PointerButton::Left),
+        );
+        assert_eq!(g_handler.events(), &[]);
+    }
+
+    fn large_theme() -> Theme {
+        let mut theme = Theme::light();
+        theme.button.font_size = 40;
+        theme.slider.font_size = 64;
+
+        theme
+    }
+
+    #[test]
+    fn components_added_after_set_theme_use_it() {
+        let mut g_handler = handler();
+        g_handler.set_theme(large_theme());
+
+        let button = g_handler.add_button("Hello", "hello");
+        let dropdown = g_handler.add_dropdown("More");
+        let nested = g_handler
+            .get_dropdown_mut(dropdown)
+            .unwrap()
+            .add_button("Nested", "nested");
+
+        let style = &g_handler.get_theme().button;
+        for (id, text) in [(button, "Hello"), (nested, "Nested")].iter() {
+            assert_eq!(
+                g_handler
+                    .get_component(*id)
+                    .unwrap()
+                    .get_preferred_dimensions(),
+                style.fit_text(measure_text_monospace(text, 40))
+            );
+        }
+    }
+
+    #[test]
+    fn sliders_fit_the_readout_to_the_font_of_the_theme() {
+        let mut g_handler = handler();
+        let slider = g_handler.add_slider(0.0, 1.0, 0.5);
+        let range_slider = g_handler.add_range_slider(0.0, 1.0, 0.25, 0.75);
+        let default_dimensions = [
+            g_handler
+                .get_component(slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+            g_handler
+                .get_component(range_slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+        ];
+
+        g_handler.set_theme(large_theme());
+
+        assert_eq!(
+            g_handler
+                .get_component(slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+            (250 + 240, 82)
+        );
+        assert_eq!(
+            g_handler
+                .get_component(range_slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+            (default_dimensions[1].0 + 240, 82)
+        );
+
+        g_handler.set_theme(Theme::default());
+
+        assert_eq!(
+            g_handler
+                .get_component(slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+            default_dimensions[0]
+        );
+        assert_eq!(
+            g_handler
+                .get_component(range_slider)
+                .unwrap()
+                .get_preferred_dimensions(),
+            default_dimensions[1]
+        );
+    }
+
+    /// Presses and releases the left mouse button at `position`, returning
+    /// the events of both frames.
+    fn click(
+        g_handler: &mut GuiHandler<(), &'static str>,
+        position: Point,
+    ) -> Vec<GuiEvent<&'static str>> {
+        let over = input().with_mouse_position(position);
+
+        g_handler.update(&over.clone().with_button_pressed(PointerButton::Left));
+        let mut events = g_handler.drain_events();
+        g_handler.update(&over.with_button_released(PointerButton::Left));
+        events.extend(g_handler.drain_events());
+
+        events
+    }
+
+    #[test]
+    fn buttons_in_a_dropdown_inside_a_dropdown_can_be_clicked() {
+        let mut g_handler = handler();
+        let outer = g_handler.add_dropdown_with_position("Outer", (0, 0));
+        let inner = g_handler
+            .get_dropdown_mut(outer)
+            .unwrap()
+            .add_dropdown("Inner");
+        let button = g_handler
+            .get_dropdown_mut(outer)
+            .unwrap()
+            .get_dropdown_mut(inner)
+            .unwrap()
+            .add_button("Nested", "nested");
+
+        click(&mut g_handler, (5, 5));
+        let inner_position = g_handler.get_component(inner).unwrap().get_position();
+        assert_eq!(
+            click(&mut g_handler, (inner_position.0 + 5, inner_position.1 + 5)),
+            vec![GuiEvent::DropdownOpened(inner)]
+        );
+
+        let outer_panel = g_handler.get_dropdown(outer).unwrap().get_panel();
+        let button_position = g_handler.get_component(button).unwrap().get_position();
+        let mouse_position = (button_position.0 + 5, button_position.1 + 5);
+        assert!(!is_inside(
+            outer_panel.position,
+            outer_panel.dimensions,
+            mouse_position
+        ));
+        assert_eq!(
+            find_component_at(&g_handler.components, mouse_position),
+            Some(button.id())
+        );
+
+        assert_eq!(
+            click(&mut g_handler, mouse_position),
+            vec![GuiEvent::ButtonClicked {
+                button,
+                event: "nested"
+            }]
+        );
+    }
+
+    #[test]
+    fn dropdowns_inside_a_dropdown_are_drawn_outside_of_its_clip() {
+        let mut g_handler = handler();
+        let outer = g_handler.add_dropdown_with_position("Outer", (0, 0));
+        let inner = g_handler
+            .get_dropdown_mut(outer)
+            .unwrap()
+            .add_dropdown("Inner");
+        g_handler
+            .get_dropdown_mut(outer)
+            .unwrap()
+            .get_dropdown_mut(inner)
+            .unwrap()
+            .add_button("Nested", "nested");
+        g_handler.get_dropdown_mut(outer).unwrap().set_open(true);
+        g_handler
+            .get_dropdown_mut(outer)
+            .unwrap()
+            .get_dropdown_mut(inner)
+            .unwrap()
+            .set_open(true);
+
+        let mut renderer = RecordingRenderer::new();
+        g_handler.update(&input()).render(&mut renderer);
+
+        let mut clips = 0;
+        for command in renderer.display_list().commands.iter() {
+            match command {
+                DrawCommand::PushClip { .. } => clips += 1,
+                DrawCommand::PopClip => clips -= 1,
+                DrawCommand::Text { text, .. } if text == "Inner" => assert_eq!(clips, 1),
+                DrawCommand::Text { text, .. } if text == "Nested" => {
+                    assert_eq!(clips, 1);
+                    return;
+                },
+                _ => (),
+            }
+        }
+
+        panic!("The button inside of the inner Dropdown was not drawn");
+    }
+
+    #[test]
+    fn the_mouse_passes_through_progress_bars_unless_they_are_interactive() {
+        let mut g_handler = handler();
+        let progress_bar =
+            g_handler.add_progress_bar_with_position(0.0, 1.0, 0.5, (200, 20), (100, 100));
+        let over = input().with_mouse_position((150, 110));
+
+        g_handler.update(&over.clone().with_button_pressed(PointerButton::Left));
+        assert_eq!(g_handler.get_input_capture(), InputCapture::default());
+        assert_eq!(g_handler.get_pointer_capture(), None);
+        assert_eq!(
+            g_handler
+                .get_progress_bar(progress_bar)
+                .unwrap()
+                .get_state(),
+            WidgetState::Default
+        );
+
+        g_handler.update(&over.clone().with_button_released(PointerButton::Left));
+        g_handler
+            .get_progress_bar_mut(progress_bar)
+            .unwrap()
+            .set_interactive(true);
+
+        g_handler.update(&over.with_button_pressed(PointerButton::Left));
+        assert!(g_handler.get_input_capture().mouse);
+        assert_eq!(g_handler.get_pointer_capture(), Some(progress_bar.id()));
+        assert_eq!(
+            g_handler
+                .get_progress_bar(progress_bar)
+                .unwrap()
+                .get_state(),
+            WidgetState::Hovered
+        );
+    }
+
+    /// Adds a row of buttons named after `names`, spaced out so that none of
+    /// them overlap.
+    fn add_buttons(
+        g_handler: &mut GuiHandler<(), &'static str>,
+        names: &[&'static str],
+    ) -> Vec<WidgetId> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| {
+                g_handler
+                    .add_button_with_position(name, name, (0, i as i32 * 100))
+                    .id()
+            })
+            .collect()
+    }
+
+    fn press(key: Key) -> InputState { input().with_key_pressed(key) }
+
+    #[test]
+    fn next_and_previous_wrap_around_the_focus_order() {
+        let mut g_handler = handler();
+        let buttons = add_buttons(&mut g_handler, &["a", "b", "c"]);
+        let previous = press(Key::Tab).with_key_down(Key::LeftShift);
+
+        g_handler.update(&previous);
+        assert_eq!(g_handler.get_focused(), Some(buttons[2]));
+
+        for &button in buttons.iter().chain(buttons.iter()) {
+            g_handler.update(&press(Key::Tab));
+            assert_eq!(g_handler.get_focused(), Some(button));
+        }
+
+        g_handler.update(&press(Key::Tab));
+        g_handler.update(&previous);
+        assert_eq!(g_handler.get_focused(), Some(buttons[2]));
+    }
+
+    #[test]
+    fn next_skips_hidden_and_disabled_components() {
+        let mut g_handler = handler();
+        let buttons = add_buttons(&mut g_handler, &["a", "b", "c", "d"]);
+        g_handler.set_visible(buttons[1], false).unwrap();
+        g_handler.set_enabled(buttons[2], false).unwrap();
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(buttons[3]));
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+    }
+
+    #[test]
+    fn held_directions_repeat_after_the_delay_and_then_every_interval() {
+        let mut g_handler = handler();
+        let buttons = add_buttons(&mut g_handler, &["a", "b", "c", "d"]);
+        g_handler.get_input_map_mut().set_repeat(0.5, 0.25);
+        let held = input().with_key_down(Key::Down).with_frame_time(0.25);
+
+        g_handler.update(&press(Key::Down).with_frame_time(0.25));
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+
+        g_handler.update(&held);
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+
+        g_handler.update(&held);
+        assert_eq!(g_handler.get_focused(), Some(buttons[1]));
+
+        g_handler.update(&held);
+        assert_eq!(g_handler.get_focused(), Some(buttons[2]));
+
+        // Letting go and pressing again moves straight away.
+        g_handler.update(&input());
+        g_handler.update(&press(Key::Down).with_frame_time(0.25));
+        assert_eq!(g_handler.get_focused(), Some(buttons[3]));
+    }
+
+    #[test]
+    fn the_stick_only_moves_focus_past_the_dead_zone() {
+        let mut g_handler = handler();
+        let buttons = add_buttons(&mut g_handler, &["a", "b"]);
+        g_handler.get_input_map_mut().set_stick_dead_zone(0.25);
+
+        g_handler.update(&input().with_left_stick((0.0, 0.2)));
+        assert_eq!(g_handler.get_focused(), None);
+
+        g_handler.update(&input().with_left_stick((0.0, 0.5)));
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+
+        g_handler.update(&input().with_left_stick((0.2, 0.0)));
+        g_handler.update(&input().with_left_stick((0.5, 0.0)));
+        assert_eq!(g_handler.get_focused(), Some(buttons[1]));
+    }
+
+    #[test]
+    fn left_and_right_adjust_a_focused_slider() {
+        let mut g_handler = handler();
+        let slider = g_handler.add_slider_with_position(0.0, 100.0, 50.0, (0, 0));
+        add_buttons(&mut g_handler, &["", "after"]);
+        g_handler.set_focus(slider).unwrap();
+
+        g_handler.update(&press(Key::Right));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::SliderChanged {
+                slider,
+                value: 55.0
+            }]
+        );
+
+        g_handler.update(&press(Key::Left));
+        g_handler.update(&press(Key::Left));
+        assert_eq!(g_handler.get_slider_value(slider), Ok(45.0));
+        assert_eq!(g_handler.get_focused(), Some(slider.id()));
+
+        // The stick adjusts the `Slider` instead of moving focus, but only
+        // once it is pushed past the dead zone.
+        g_handler.update(&input().with_left_stick((0.2, 0.0)).with_frame_time(1.0));
+        assert_eq!(g_handler.events(), &[]);
+        assert_eq!(g_handler.get_focused(), Some(slider.id()));
+    }
+
+    #[test]
+    fn focusing_a_text_box_by_navigation_does_not_start_editing_it() {
+        let mut g_handler = handler();
+        let text_box = g_handler.add_text_box_with_position(100, (200, 0));
+        let buttons = add_buttons(&mut g_handler, &["a"]);
+        g_handler.set_focus(text_box).unwrap();
+
+        g_handler.update(&press(Key::Right));
+        assert_eq!(g_handler.get_focused(), Some(buttons[0]));
+
+        g_handler.update(&press(Key::Left));
+        assert_eq!(g_handler.get_focused(), Some(text_box.id()));
+        assert!(!g_handler.get_text_box(text_box).unwrap().is_editing());
+
+        g_handler.update(&input());
+        assert!(!g_handler.get_input_capture().keyboard);
+
+        g_handler.update(&press(Key::Enter));
+        assert!(g_handler.get_text_box(text_box).unwrap().is_editing());
+        assert!(g_handler.get_input_capture().keyboard);
+
+        g_handler.update(&press(Key::Left).with_characters("hi"));
+        assert_eq!(g_handler.get_text_box_text(text_box), Ok("hi"));
+        assert_eq!(g_handler.get_focused(), Some(text_box.id()));
+
+        g_handler.update(&press(Key::Escape));
+        assert!(!g_handler.get_text_box(text_box).unwrap().is_editing());
+        assert_eq!(g_handler.get_focused(), Some(text_box.id()));
+    }
+
+    #[test]
+    fn keys_used_by_navigation_capture_the_keyboard() {
+        let mut g_handler = handler();
+
+        g_handler.update(&press(Key::Tab));
+        assert!(!g_handler.get_input_capture().keyboard);
+
+        let slider = g_handler.add_slider_with_position(0.0, 100.0, 50.0, (0, 0));
+        let combo_box = g_handler.add_combo_box_with_position(&["a", "b"], Some(0), (0, 200));
+        g_handler.set_focus(slider).unwrap();
+
+        g_handler.update(&press(Key::Right));
+        assert!(g_handler.get_input_capture().keyboard);
+
+        // A `Slider` has no use for Enter, and nothing else happens.
+        g_handler.update(&press(Key::Enter));
+        assert!(!g_handler.get_input_capture().keyboard);
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(combo_box.id()));
+        assert!(g_handler.get_input_capture().keyboard);
+
+        g_handler.update(&press(Key::Enter));
+        assert!(g_handler.get_input_capture().keyboard);
+
+        g_handler.update(&input());
+        assert!(!g_handler.get_input_capture().keyboard);
+    }
+
+    #[test]
+    fn disabled_buttons_are_not_clicked_or_focused() {
+        let mut g_handler = handler();
+        let buttons = add_buttons(&mut g_handler, &["a", "b"]);
+        g_handler.set_enabled(buttons[0], false).unwrap();
+
+        assert_eq!(click(&mut g_handler, (5, 5)), &[]);
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(buttons[1]));
+
+        g_handler.update(&press(Key::Tab));
+        assert_eq!(g_handler.get_focused(), Some(buttons[1]));
+        assert!(g_handler.set_focus(buttons[0]).is_err());
+
+        g_handler.set_enabled(buttons[0], true).unwrap();
+        assert_eq!(
+            click(&mut g_handler, (5, 5)),
+            &[GuiEvent::ButtonClicked {
+                button: ButtonHandle(buttons[0]),
+                event: "a"
+            }]
+        );
+    }
+
+    #[test]
+    fn anchored_layouts_are_placed_again_when_the_screen_is_resized() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button("Hello", "hello");
+        let mut layout = Layout::vbox();
+        layout.set_anchor(Anchor::BottomRight).add_widget(button);
+        g_handler.add_layout(layout);
+
+        let dimensions = g_handler.get_component(button).unwrap().get_dimensions();
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_position(),
+            (800 - dimensions.0, 600 - dimensions.1)
+        );
+
+        g_handler.update(&InputState::new().with_screen_size((1024, 768)));
+        assert_eq!(g_handler.events(), &[GuiEvent::ScreenResized((1024, 768))]);
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_position(),
+            (1024 - dimensions.0, 768 - dimensions.1)
+        );
+    }
+
+    #[test]
+    fn scaled_components_are_drawn_and_hit_tested_at_the_scale() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button_with_position("Hello", "hello", (100, 100));
+        let dimensions = g_handler.get_component(button).unwrap().get_dimensions();
+        let font_size = g_handler.get_theme().button.font_size;
+        g_handler.set_scale(2.0);
+
+        assert_eq!(click(&mut g_handler, (105, 105)), &[]);
+        assert_eq!(
+            click(&mut g_handler, (210, 210)),
+            &[GuiEvent::ButtonClicked {
+                button,
+                event: "hello"
+            }]
+        );
+
+        let mut renderer = RecordingRenderer::new();
+        g_handler.update(&input()).render(&mut renderer);
+        let commands = &renderer.display_list().commands;
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            DrawCommand::Rectangle { position: (200, 200), dimensions: d, .. }
+                | DrawCommand::RoundedRectangle { position: (200, 200), dimensions: d, .. }
+                if *d == (dimensions.0 * 2, dimensions.1 * 2)
+        )));
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            DrawCommand::Text { text, font_size: size, .. }
+                if text == "Hello" && *size == font_size * 2
+        )));
+    }
+
+    #[test]
+    fn the_scale_follows_the_screen_at_a_reference_resolution() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button_with_position("Hello", "hello", (100, 100));
+        g_handler.set_reference_resolution((400, 300));
+        assert_eq!(g_handler.get_scale(), 2.0);
+
+        g_handler.set_scale(1.5);
+        assert_eq!(g_handler.get_scale(), 3.0);
+
+        g_handler.update(&InputState::new().with_screen_size((1600, 600)));
+        assert_eq!(g_handler.get_scale(), 3.0);
+
+        g_handler.set_scale(1.0).update(&input());
+        assert_eq!(g_handler.get_scale(), 2.0);
+        assert_eq!(click(&mut g_handler, (105, 105)), &[]);
+        assert_eq!(
+            click(&mut g_handler, (210, 210)),
+            &[GuiEvent::ButtonClicked {
+                button,
+                event: "hello"
+            }]
+        );
+
+        g_handler.clear_reference_resolution();
+        assert_eq!(g_handler.get_scale(), 1.0);
+    }
+
+    #[test]
+    fn anchored_layouts_fill_the_reference_resolution() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button("Hello", "hello");
+        let dimensions = g_handler.get_component(button).unwrap().get_dimensions();
+        g_handler.set_reference_resolution((400, 300));
+
+        let mut layout = Layout::vbox();
+        layout.set_anchor(Anchor::BottomRight).add_widget(button);
+        g_handler.add_layout(layout);
+
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_position(),
+            (400 - dimensions.0, 300 - dimensions.1)
+        );
+    }
+
+    struct Backdrop;
+
+    impl Drawable for Backdrop {
+        fn draw(&mut self, renderer: &mut dyn Renderer) {
+            renderer.draw_rectangle((0, 0), (800, 600), Colour::GREEN);
+        }
+    }
+
+    #[test]
+    fn external_draws_are_rendered_before_the_components() {
+        let mut g_handler = handler();
+        g_handler.add_button("Hello", "hello");
+        g_handler.add_external_draw(Box::new(Backdrop));
+
+        let mut renderer = RecordingRenderer::new();
+        g_handler.render(&mut renderer);
+
+        let commands = &renderer.display_list().commands;
+        assert_eq!(
+            commands[0],
+            DrawCommand::Rectangle {
+                position: (0, 0),
+                dimensions: (800, 600),
+                colour: Colour::GREEN,
+            }
+        );
+        assert!(commands.len() > 1);
+    }
+
+    #[test]
+    fn rendering_again_with_the_same_text_measurer_keeps_the_sizes() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button("Hello", "hello");
+
+        g_handler.render(&mut RecordingRenderer::new());
+        g_handler
+            .get_component_mut(button)
+            .unwrap()
+            .resize((300, 80));
+        g_handler.render(&mut RecordingRenderer::new());
+
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_dimensions(),
+            (300, 80)
+        );
+    }
+
+    #[test]
+    fn a_watched_theme_file_keeps_the_last_good_theme_when_it_breaks() {
+        let path = std::env::temp_dir().join(format!("ptgui-theme-{}.toml", std::process::id()));
+        fs::write(&path, "[button]\nfont_size = 30").unwrap();
+
+        let mut g_handler = handler();
+        g_handler.watch_theme_file(&path).unwrap();
+        assert_eq!(g_handler.get_theme().button.font_size, 30);
+
+        fs::write(&path, "[button]\nfont_size = -1").unwrap();
+        // Forgets when the file was loaded, so that the test does not depend
+        // on the precision of modification times.
+        g_handler.theme_file_modified = None;
+
+        g_handler.update(&input().with_frame_time(0.1));
+        assert_eq!(g_handler.events(), &[]);
+
+        g_handler.update(&input().with_frame_time(THEME_FILE_POLL_INTERVAL));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::ThemeError(format!(
+                "Invalid theme file {}: `button.font_size` must be a whole number of at least 0, \
+                 found -1",
+                path.display()
+            ))]
+        );
+        assert_eq!(g_handler.get_theme().button.font_size, 30);
+
+        g_handler.update(&input().with_frame_time(THEME_FILE_POLL_INTERVAL));
+        assert_eq!(g_handler.events(), &[]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn radio_groups_are_navigated_along_their_orientation() {
+        let mut g_handler = handler();
+        let column = g_handler.add_radio_group_with_position(&["A", "B", "C"], Some(0), (0, 0));
+        let row = g_handler.add_radio_group_with_position(&["A", "B", "C"], Some(0), (0, 300));
+        g_handler
+            .get_radio_group_mut(row)
+            .unwrap()
+            .set_orientation(Orientation::Horizontal);
+
+        g_handler.set_focus(column).unwrap();
+        g_handler.update(&press(Key::Down));
+        g_handler.update(&press(Key::Right));
+        assert_eq!(g_handler.get_radio_group_selected(column), Ok(Some(1)));
+        assert_eq!(g_handler.get_focused(), Some(row.id()));
+
+        g_handler.update(&press(Key::Right));
+        assert_eq!(g_handler.get_radio_group_selected(row), Ok(Some(1)));
+        assert_eq!(g_handler.get_focused(), Some(row.id()));
+
+        g_handler.update(&press(Key::Up));
+        assert_eq!(g_handler.get_focused(), Some(column.id()));
+        g_handler.update(&press(Key::Up));
+        assert_eq!(g_handler.get_radio_group_selected(column), Ok(Some(0)));
+    }
+
+    #[test]
+    fn a_custom_widget_holding_the_pointer_capture_is_sent_the_mouse() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button_with_position("Hello", "hello", (100, 100));
+        let widget = WidgetId::unique();
+        let over_button = input().with_mouse_position((105, 105));
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((500, 500))
+                .with_button_pressed(PointerButton::Left),
+        );
+        g_handler.capture_pointer(widget);
+
+        g_handler.update(&over_button.clone().with_button_down(PointerButton::Left));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::PointerMoved {
+                widget,
+                position: (105, 105),
+            }]
+        );
+        assert_eq!(
+            g_handler.get_button_mut(button).unwrap().get_state(),
+            WidgetState::Default
+        );
+
+        g_handler.update(&over_button.clone().with_button_down(PointerButton::Left));
+        assert_eq!(g_handler.events(), &[]);
+
+        g_handler.update(&over_button.with_button_released(PointerButton::Left));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::PointerReleased {
+                widget,
+                position: (105, 105),
+            }]
+        );
+        assert_eq!(g_handler.get_pointer_capture(), None);
+    }
+
+    #[test]
+    fn a_slider_keeps_the_mouse_when_it_is_dragged_past_its_end() {
+        let mut g_handler = handler();
+        let slider = g_handler.add_slider_with_position(0.0, 100.0, 0.0, (0, 0));
+        let press = input()
+            .with_mouse_position((25, 20))
+            .with_button_pressed(PointerButton::Left);
+
+        g_handler.update(&press);
+        assert_eq!(g_handler.get_pointer_capture(), Some(slider.id()));
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((700, 500))
+                .with_button_down(PointerButton::Left),
+        );
+        assert_eq!(g_handler.get_slider_value(slider), Ok(100.0));
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((-300, 500))
+                .with_button_down(PointerButton::Left),
+        );
+        assert_eq!(g_handler.get_slider_value(slider), Ok(0.0));
+    }
+
+    #[test]
+    fn the_mouse_is_captured_while_a_widget_is_hovered_or_dragged() {
+        let mut g_handler = handler();
+        g_handler.add_slider_with_position(0.0, 100.0, 0.0, (0, 0));
+
+        g_handler.update(&input().with_mouse_position((25, 20)));
+        assert!(g_handler.get_input_capture().mouse);
+
+        g_handler.update(&input().with_mouse_position((400, 300)));
+        assert_eq!(g_handler.get_input_capture(), InputCapture::default());
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((25, 20))
+                .with_button_pressed(PointerButton::Left),
+        );
+        g_handler.update(
+            &input()
+                .with_mouse_position((400, 300))
+                .with_button_down(PointerButton::Left),
+        );
+        assert!(g_handler.get_input_capture().mouse);
+
+        g_handler.update(
+            &input()
+                .with_mouse_position((400, 300))
+                .with_button_released(PointerButton::Left),
+        );
+        assert_eq!(g_handler.get_input_capture(), InputCapture::default());
+    }
+
+    #[test]
+    fn components_are_changed_and_removed_by_handle() {
+        let mut g_handler = handler();
+        let button = g_handler.add_button_with_position("Hi", "hi", (100, 100));
+        let mut layout = Layout::vbox();
+        layout.add_widget(button);
+        let layout = g_handler.add_layout(layout);
+        let width = g_handler.get_component(button).unwrap().get_dimensions().0;
+
+        g_handler.set_text(button, "Hello there").unwrap();
+        assert!(g_handler.get_component(button).unwrap().get_dimensions().0 > width);
+
+        g_handler.set_position(button, (300, 200)).unwrap();
+        assert_eq!(
+            g_handler.get_component(button).unwrap().get_position(),
+            (300, 200)
+        );
+
+        g_handler.set_visible(button, false).unwrap();
+        assert!(!g_handler.get_component(button).unwrap().is_visible());
+        assert_eq!(click(&mut g_handler, (305, 205)), &[]);
+
+        assert!(g_handler.remove(button).is_some());
+        assert!(g_handler.get_component(button).is_none());
+        assert!(g_handler
+            .get_layout(layout)
+            .unwrap()
+            .get_widget_ids()
+            .is_empty());
+        assert!(g_handler.remove(button).is_none());
+        assert!(g_handler.set_text(button, "Gone").is_err());
+        assert!(g_handler.set_visible(button, true).is_err());
+        assert!(g_handler.set_position(button, (0, 0)).is_err());
+    }
+
+    #[test]
+    fn components_in_a_dropdown_or_scroll_panel_are_changed_and_removed_by_handle() {
+        let mut g_handler = handler();
+        let dropdown = g_handler.add_dropdown_with_position("Menu", (0, 0));
+        let scroll_panel = g_handler.add_scroll_panel_with_position((200, 200), (300, 0));
+        let nested = [
+            g_handler
+                .get_dropdown_mut(dropdown)
+                .unwrap()
+                .add_button("Hi", "hi")
+                .id(),
+            g_handler
+                .get_scroll_panel_mut(scroll_panel)
+                .unwrap()
+                .add_button("Hi", "hi")
+                .id(),
+        ];
+
+        for id in nested {
+            let width = g_handler.get_component(id).unwrap().get_dimensions().0;
+            g_handler.set_text(id, "Hello there").unwrap();
+            assert!(g_handler.get_component(id).unwrap().get_dimensions().0 > width);
+
+            g_handler.set_position(id, (350, 50)).unwrap();
+            assert_eq!(
+                g_handler.get_component(id).unwrap().get_position(),
+                (350, 50)
+            );
+
+            g_handler.set_visible(id, false).unwrap();
+            assert!(!g_handler.get_component(id).unwrap().is_visible());
+
+            assert!(g_handler.remove(id).is_some());
+            assert!(g_handler.get_component(id).is_none());
+        }
+
+        assert!(g_handler.get_dropdown_mut(dropdown).is_some());
+        assert!(g_handler.get_scroll_panel_mut(scroll_panel).is_some());
+    }
+
+    #[test]
+    fn a_focused_combo_box_is_opened_and_picked_from_with_the_keys() {
+        let mut g_handler = handler();
+        let combo_box = g_handler.add_combo_box_with_position(&["a", "b", "c"], Some(0), (0, 0));
+        g_handler.set_focus(combo_box).unwrap();
+
+        g_handler.update(&press(Key::Enter));
+        assert!(g_handler.get_combo_box(combo_box).unwrap().is_open());
+
+        g_handler.update(&press(Key::Down));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::ComboBoxChanged {
+                combo_box,
+                index: 1
+            }]
+        );
+
+        g_handler.update(&press(Key::Down));
+        g_handler.update(&press(Key::Down));
+        assert_eq!(g_handler.events(), &[]);
+        assert_eq!(g_handler.get_combo_box_selected(combo_box), Ok(Some(2)));
+
+        g_handler.update(&press(Key::Escape));
+        assert!(!g_handler.get_combo_box(combo_box).unwrap().is_open());
+        assert_eq!(g_handler.get_combo_box_text(combo_box), Ok(Some("c")));
+        assert_eq!(g_handler.get_focused(), Some(combo_box.id()));
     }
 }
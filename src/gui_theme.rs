@@ -1,16 +1,360 @@
-use crate::prelude::Colour;
+use crate::prelude::{Colour, Dimensions};
 
-pub enum StateColour {
+/// The state a component is drawn in, which decides which of its colours are
+/// used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WidgetState {
+    Default,
     Hovered,
     Active,
-    Default,
-    Text,
+    Disabled,
+    Focused,
+}
+
+/// The colour of one part of a component in each `WidgetState`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StateColours {
+    pub default: Colour,
+    pub hovered: Colour,
+    pub active: Colour,
+    pub disabled: Colour,
+    pub focused: Colour,
+}
+
+impl StateColours {
+    /// Creates a new `StateColours` that uses `colour` in every state.
+    pub fn uniform(colour: Colour) -> Self {
+        Self {
+            default: colour,
+            hovered: colour,
+            active: colour,
+            disabled: colour,
+            focused: colour,
+        }
+    }
+
+    /// Returns the colour to use in the given `state`.
+    pub fn get(&self, state: WidgetState) -> Colour {
+        match state {
+            WidgetState::Default => self.default,
+            WidgetState::Hovered => self.hovered,
+            WidgetState::Active => self.active,
+            WidgetState::Disabled => self.disabled,
+            WidgetState::Focused => self.focused,
+        }
+    }
+}
+
+/// Everything that decides how one kind of component looks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WidgetStyle {
+    /// The colour of the area behind the component.
+    pub background: StateColours,
+    /// The colour of the parts drawn on top of the background, such as the box
+    /// of a `Slider` or the line under a `Label`.
+    pub foreground: StateColours,
+    /// The colour of secondary parts, such as the track of a `Slider`.
+    pub accent: StateColours,
+    pub text: StateColours,
+    pub border: StateColours,
+    /// The width of the border, where 0 draws no border at all.
+    pub border_width: i32,
+    /// The radius of the corners of the background, where 0 draws square
+    /// corners.
+    pub corner_radius: i32,
+    pub font_size: i32,
+    /// The horizontal and vertical space between the edge of the component
+    /// and its text.
+    pub padding: Dimensions,
 }
 
-pub fn state_get_colour(state: StateColour) -> Colour {
-    match state {
-        StateColour::Hovered | StateColour::Active => Colour::DARKGRAY,
-        StateColour::Default => Colour::GRAY,
-        StateColour::Text => Colour::RAYWHITE,
+impl WidgetStyle {
+    /// Returns the dimensions that fit text that is `text_width` pixels wide
+    /// with this style's font size and padding.
+    pub fn fit_text(&self, text_width: i32) -> Dimensions {
+        (
+            text_width + self.padding.0 * 2,
+            self.font_size + self.padding.1 * 2,
+        )
     }
 }
+
+/// The look of every component in a `GuiHandler`. The theme can be swapped
+/// at any time with `GuiHandler::set_theme`, and single components can be
+/// given their own `WidgetStyle` to override it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// The path of a font file to draw text with, using raylib's default font
+    /// if this is `None`. Components are sized to fit text in this font once
+    /// the `GuiHandler` draws with it.
+    pub font: Option<String>,
+    pub button: WidgetStyle,
+    /// The style of a `Checkbox`, where the accent fills the box and the
+    /// foreground is the tick.
+    pub checkbox: WidgetStyle,
+    /// The style of a `ComboBox`, where the foreground is the arrow and the
+    /// accent highlights the selected option in its open list.
+    pub combo_box: WidgetStyle,
+    pub dropdown: WidgetStyle,
+    pub label: WidgetStyle,
+    /// The style of a `ProgressBar`, where the accent is the track and the
+    /// foreground is the filled part.
+    pub progress_bar: WidgetStyle,
+    /// The style of a `RadioGroup`, where the accent fills the circle of each
+    /// option, the border is drawn around it and the foreground is the dot of
+    /// the selected option.
+    pub radio_group: WidgetStyle,
+    /// The style of a `ScrollBar`, where the accent is the track and the
+    /// foreground is the thumb.
+    pub scroll_bar: WidgetStyle,
+    /// The style of a `ScrollPanel`, which only draws its background and
+    /// border behind its components.
+    pub scroll_panel: WidgetStyle,
+    pub slider: WidgetStyle,
+    /// The style of a `TextBox`, where the foreground is the caret and the
+    /// accent is the selection. Placeholder text uses the disabled text
+    /// colour.
+    pub text_box: WidgetStyle,
+    /// The style of a `Toggle`, where the accent is the track, drawn in its
+    /// active colour while the `Toggle` is on, and the foreground is the
+    /// knob.
+    pub toggle: WidgetStyle,
+}
+
+impl Theme {
+    /// The default, dark grey theme.
+    pub fn dark() -> Self {
+        let button = WidgetStyle {
+            background: StateColours {
+                default: Colour::GRAY,
+                hovered: Colour::DARKGRAY,
+                active: Colour::new(50, 50, 50, 255),
+                disabled: Colour::LIGHTGRAY,
+                focused: Colour::DARKGRAY,
+            },
+            foreground: StateColours::uniform(Colour::RAYWHITE),
+            accent: StateColours::uniform(Colour::LIGHTGRAY),
+            text: StateColours {
+                disabled: Colour::GRAY,
+                ..StateColours::uniform(Colour::RAYWHITE)
+            },
+            border: StateColours {
+                focused: Colour::GOLD,
+                ..StateColours::uniform(Colour::BLACK)
+            },
+            border_width: 0,
+            corner_radius: 0,
+            font_size: 20,
+            padding: (10, 15),
+        };
+
+        Self {
+            font: None,
+            checkbox: WidgetStyle {
+                background: StateColours::uniform(Colour::DARKGRAY),
+                foreground: StateColours::uniform(Colour::RAYWHITE),
+                accent: StateColours {
+                    hovered: Colour::GRAY,
+                    ..StateColours::uniform(Colour::new(30, 30, 30, 255))
+                },
+                border: StateColours {
+                    hovered: Colour::RAYWHITE,
+                    focused: Colour::GOLD,
+                    ..StateColours::uniform(Colour::GRAY)
+                },
+                ..button.clone()
+            },
+            combo_box: WidgetStyle {
+                accent: StateColours::uniform(Colour::DARKBLUE),
+                ..button.clone()
+            },
+            dropdown: button.clone(),
+            label: WidgetStyle {
+                background: StateColours::uniform(Colour::DARKGRAY),
+                foreground: StateColours::uniform(Colour::BLACK),
+                ..button.clone()
+            },
+            progress_bar: WidgetStyle {
+                background: StateColours::uniform(Colour::DARKGRAY),
+                foreground: StateColours::uniform(Colour::LIME),
+                accent: StateColours::uniform(Colour::new(30, 30, 30, 255)),
+                border_width: 2,
+                ..button.clone()
+            },
+            radio_group: WidgetStyle {
+                background: StateColours::uniform(Colour::DARKGRAY),
+                foreground: StateColours::uniform(Colour::RAYWHITE),
+                accent: StateColours {
+                    hovered: Colour::GRAY,
+                    ..StateColours::uniform(Colour::new(30, 30, 30, 255))
+                },
+                border: StateColours {
+                    hovered: Colour::RAYWHITE,
+                    focused: Colour::GOLD,
+                    ..StateColours::uniform(Colour::GRAY)
+                },
+                ..button.clone()
+            },
+            scroll_bar: WidgetStyle {
+                foreground: StateColours {
+                    hovered: Colour::LIGHTGRAY,
+                    active: Colour::RAYWHITE,
+                    ..StateColours::uniform(Colour::GRAY)
+                },
+                accent: StateColours::uniform(Colour::new(30, 30, 30, 255)),
+                ..button.clone()
+            },
+            scroll_panel: WidgetStyle {
+                background: StateColours::uniform(Colour::BLANK),
+                ..button.clone()
+            },
+            slider: WidgetStyle {
+                foreground: StateColours {
+                    hovered: Colour::DARKGRAY,
+                    active: Colour::DARKGRAY,
+                    disabled: Colour::GRAY,
+                    ..StateColours::uniform(Colour::RAYWHITE)
+                },
+                font_size: 32,
+                ..button.clone()
+            },
+            text_box: WidgetStyle {
+                background: StateColours {
+                    hovered: Colour::new(40, 40, 40, 255),
+                    focused: Colour::new(40, 40, 40, 255),
+                    ..StateColours::uniform(Colour::new(30, 30, 30, 255))
+                },
+                foreground: StateColours::uniform(Colour::RAYWHITE),
+                accent: StateColours::uniform(Colour::DARKBLUE),
+                border: StateColours {
+                    focused: Colour::GOLD,
+                    ..StateColours::uniform(Colour::GRAY)
+                },
+                border_width: 1,
+                padding: (10, 10),
+                ..button.clone()
+            },
+            toggle: WidgetStyle {
+                background: StateColours::uniform(Colour::DARKGRAY),
+                foreground: StateColours::uniform(Colour::RAYWHITE),
+                accent: StateColours {
+                    hovered: Colour::new(100, 100, 100, 255),
+                    active: Colour::LIME,
+                    ..StateColours::uniform(Colour::new(30, 30, 30, 255))
+                },
+                ..button.clone()
+            },
+            button,
+        }
+    }
+
+    /// A light theme with dark text.
+    pub fn light() -> Self {
+        let button = WidgetStyle {
+            background: StateColours {
+                default: Colour::LIGHTGRAY,
+                hovered: Colour::new(230, 230, 230, 255),
+                active: Colour::SKYBLUE,
+                disabled: Colour::new(240, 240, 240, 255),
+                focused: Colour::new(230, 230, 230, 255),
+            },
+            foreground: StateColours::uniform(Colour::DARKGRAY),
+            accent: StateColours::uniform(Colour::new(245, 245, 245, 255)),
+            text: StateColours {
+                disabled: Colour::GRAY,
+                ..StateColours::uniform(Colour::BLACK)
+            },
+            border: StateColours {
+                focused: Colour::BLUE,
+                disabled: Colour::LIGHTGRAY,
+                ..StateColours::uniform(Colour::GRAY)
+            },
+            border_width: 1,
+            corner_radius: 0,
+            font_size: 20,
+            padding: (10, 15),
+        };
+
+        Self {
+            font: None,
+            checkbox: WidgetStyle {
+                background: StateColours::uniform(Colour::RAYWHITE),
+                foreground: StateColours::uniform(Colour::BLUE),
+                accent: StateColours::uniform(Colour::WHITE),
+                border_width: 0,
+                ..button.clone()
+            },
+            combo_box: WidgetStyle {
+                accent: StateColours::uniform(Colour::SKYBLUE),
+                ..button.clone()
+            },
+            dropdown: button.clone(),
+            label: WidgetStyle {
+                background: StateColours::uniform(Colour::RAYWHITE),
+                foreground: StateColours::uniform(Colour::GRAY),
+                border_width: 0,
+                ..button.clone()
+            },
+            progress_bar: WidgetStyle {
+                foreground: StateColours::uniform(Colour::BLUE),
+                accent: StateColours::uniform(Colour::new(230, 230, 230, 255)),
+                ..button.clone()
+            },
+            radio_group: WidgetStyle {
+                background: StateColours::uniform(Colour::RAYWHITE),
+                foreground: StateColours::uniform(Colour::BLUE),
+                accent: StateColours::uniform(Colour::WHITE),
+                border_width: 0,
+                ..button.clone()
+            },
+            scroll_bar: WidgetStyle {
+                foreground: StateColours {
+                    hovered: Colour::DARKGRAY,
+                    active: Colour::BLUE,
+                    ..StateColours::uniform(Colour::GRAY)
+                },
+                accent: StateColours::uniform(Colour::new(230, 230, 230, 255)),
+                border_width: 0,
+                ..button.clone()
+            },
+            scroll_panel: WidgetStyle {
+                background: StateColours::uniform(Colour::BLANK),
+                border_width: 0,
+                ..button.clone()
+            },
+            slider: WidgetStyle {
+                foreground: StateColours {
+                    hovered: Colour::BLUE,
+                    active: Colour::BLUE,
+                    disabled: Colour::GRAY,
+                    ..StateColours::uniform(Colour::DARKGRAY)
+                },
+                font_size: 32,
+                ..button.clone()
+            },
+            text_box: WidgetStyle {
+                background: StateColours::uniform(Colour::WHITE),
+                foreground: StateColours::uniform(Colour::BLACK),
+                accent: StateColours::uniform(Colour::SKYBLUE),
+                padding: (10, 10),
+                ..button.clone()
+            },
+            toggle: WidgetStyle {
+                background: StateColours::uniform(Colour::RAYWHITE),
+                foreground: StateColours::uniform(Colour::WHITE),
+                accent: StateColours {
+                    hovered: Colour::GRAY,
+                    active: Colour::BLUE,
+                    ..StateColours::uniform(Colour::LIGHTGRAY)
+                },
+                border_width: 0,
+                ..button.clone()
+            },
+            button,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self { Self::dark() }
+}
@@ -0,0 +1,90 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Identifies a single component for as long as it exists, no matter where it
+/// is in the `GuiHandler` or whether the components around it are reordered or
+/// removed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WidgetId(usize);
+
+impl WidgetId {
+    /// Creates a new `WidgetId` that is different from every other `WidgetId`.
+    pub fn unique() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+macro_rules! widget_handle {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(pub(crate) WidgetId);
+
+        impl $name {
+            /// Returns the `WidgetId` of the component this handle refers to.
+            pub fn id(&self) -> WidgetId { self.0 }
+        }
+
+        impl From<$name> for WidgetId {
+            fn from(handle: $name) -> Self { handle.0 }
+        }
+    };
+}
+
+widget_handle!(
+    /// A handle to a `Button`, returned when it is added.
+    ButtonHandle
+);
+widget_handle!(
+    /// A handle to a `Checkbox`, returned when it is added.
+    CheckboxHandle
+);
+widget_handle!(
+    /// A handle to a `ComboBox`, returned when it is added.
+    ComboBoxHandle
+);
+widget_handle!(
+    /// A handle to a `Dropdown`, returned when it is added.
+    DropdownHandle
+);
+widget_handle!(
+    /// A handle to a `Label`, returned when it is added.
+    LabelHandle
+);
+widget_handle!(
+    /// A handle to a `Layout`, returned when it is added.
+    LayoutHandle
+);
+widget_handle!(
+    /// A handle to a `ProgressBar`, returned when it is added.
+    ProgressBarHandle
+);
+widget_handle!(
+    /// A handle to a `RadioGroup`, returned when it is added.
+    RadioGroupHandle
+);
+widget_handle!(
+    /// A handle to a `RangeSlider`, returned when it is added.
+    RangeSliderHandle
+);
+widget_handle!(
+    /// A handle to a `ScrollBar`, returned when it is added.
+    ScrollBarHandle
+);
+widget_handle!(
+    /// A handle to a `ScrollPanel`, returned when it is added.
+    ScrollPanelHandle
+);
+widget_handle!(
+    /// A handle to a `Slider`, returned when it is added.
+    SliderHandle
+);
+widget_handle!(
+    /// A handle to a `TextBox`, returned when it is added.
+    TextBoxHandle
+);
+widget_handle!(
+    /// A handle to a `Toggle`, returned when it is added.
+    ToggleHandle
+);
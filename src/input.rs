@@ -0,0 +1,428 @@
+use crate::prelude::{ClickTimings, Dimensions, Point, WidgetId};
+use raylib::prelude::*;
+
+/// The mouse buttons that `ptgui` components respond to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl PointerButton {
+    /// Every `PointerButton`, in the order they are polled.
+    pub const ALL: [PointerButton; 3] = [
+        PointerButton::Left,
+        PointerButton::Right,
+        PointerButton::Middle,
+    ];
+
+    fn to_raylib(self) -> MouseButton {
+        match self {
+            PointerButton::Left => MouseButton::MOUSE_LEFT_BUTTON,
+            PointerButton::Right => MouseButton::MOUSE_RIGHT_BUTTON,
+            PointerButton::Middle => MouseButton::MOUSE_MIDDLE_BUTTON,
+        }
+    }
+}
+
+/// The keyboard keys that `ptgui` components respond to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    Backspace,
+    Delete,
+    Down,
+    End,
+    Enter,
+    Escape,
+    Home,
+    Left,
+    LeftControl,
+    LeftShift,
+    PageDown,
+    PageUp,
+    Right,
+    RightControl,
+    RightShift,
+    Space,
+    Tab,
+    Up,
+}
+
+impl Key {
+    /// Every `Key`, in the order they are polled.
+    pub const ALL: [Key; 18] = [
+        Key::Backspace,
+        Key::Delete,
+        Key::Down,
+        Key::End,
+        Key::Enter,
+        Key::Escape,
+        Key::Home,
+        Key::Left,
+        Key::LeftControl,
+        Key::LeftShift,
+        Key::PageDown,
+        Key::PageUp,
+        Key::Right,
+        Key::RightControl,
+        Key::RightShift,
+        Key::Space,
+        Key::Tab,
+        Key::Up,
+    ];
+
+    fn to_raylib(self) -> KeyboardKey {
+        match self {
+            Key::Backspace => KeyboardKey::KEY_BACKSPACE,
+            Key::Delete => KeyboardKey::KEY_DELETE,
+            Key::Down => KeyboardKey::KEY_DOWN,
+            Key::End => KeyboardKey::KEY_END,
+            Key::Enter => KeyboardKey::KEY_ENTER,
+            Key::Escape => KeyboardKey::KEY_ESCAPE,
+            Key::Home => KeyboardKey::KEY_HOME,
+            Key::Left => KeyboardKey::KEY_LEFT,
+            Key::LeftControl => KeyboardKey::KEY_LEFT_CONTROL,
+            Key::LeftShift => KeyboardKey::KEY_LEFT_SHIFT,
+            Key::PageDown => KeyboardKey::KEY_PAGE_DOWN,
+            Key::PageUp => KeyboardKey::KEY_PAGE_UP,
+            Key::Right => KeyboardKey::KEY_RIGHT,
+            Key::RightControl => KeyboardKey::KEY_RIGHT_CONTROL,
+            Key::RightShift => KeyboardKey::KEY_RIGHT_SHIFT,
+            Key::Space => KeyboardKey::KEY_SPACE,
+            Key::Tab => KeyboardKey::KEY_TAB,
+            Key::Up => KeyboardKey::KEY_UP,
+        }
+    }
+}
+
+/// The gamepad buttons that `ptgui` components respond to, named after where
+/// they are on the controller rather than what is printed on them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    DpadUp,
+    DpadDown,
+    DpadLeft,
+    DpadRight,
+    /// The bottom face button, such as A on an Xbox controller.
+    FaceDown,
+    /// The right face button, such as B on an Xbox controller.
+    FaceRight,
+    /// The left face button, such as X on an Xbox controller.
+    FaceLeft,
+    /// The top face button, such as Y on an Xbox controller.
+    FaceUp,
+    LeftShoulder,
+    RightShoulder,
+    Select,
+    Start,
+}
+
+impl GamepadButton {
+    /// Every `GamepadButton`, in the order they are polled.
+    pub const ALL: [GamepadButton; 12] = [
+        GamepadButton::DpadUp,
+        GamepadButton::DpadDown,
+        GamepadButton::DpadLeft,
+        GamepadButton::DpadRight,
+        GamepadButton::FaceDown,
+        GamepadButton::FaceRight,
+        GamepadButton::FaceLeft,
+        GamepadButton::FaceUp,
+        GamepadButton::LeftShoulder,
+        GamepadButton::RightShoulder,
+        GamepadButton::Select,
+        GamepadButton::Start,
+    ];
+
+    fn to_raylib(self) -> consts::GamepadButton {
+        match self {
+            GamepadButton::DpadUp => consts::GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP,
+            GamepadButton::DpadDown => consts::GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN,
+            GamepadButton::DpadLeft => consts::GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT,
+            GamepadButton::DpadRight => consts::GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT,
+            GamepadButton::FaceDown => consts::GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN,
+            GamepadButton::FaceRight => consts::GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_RIGHT,
+            GamepadButton::FaceLeft => consts::GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_LEFT,
+            GamepadButton::FaceUp => consts::GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_UP,
+            GamepadButton::LeftShoulder => consts::GamepadButton::GAMEPAD_BUTTON_LEFT_TRIGGER_1,
+            GamepadButton::RightShoulder => consts::GamepadButton::GAMEPAD_BUTTON_RIGHT_TRIGGER_1,
+            GamepadButton::Select => consts::GamepadButton::GAMEPAD_BUTTON_MIDDLE_LEFT,
+            GamepadButton::Start => consts::GamepadButton::GAMEPAD_BUTTON_MIDDLE_RIGHT,
+        }
+    }
+}
+
+/// Whether or not the GUI used the mouse and keyboard during an update, so
+/// that the game can skip its own handling of them. Returned by
+/// `GuiHandler::get_input_capture`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputCapture {
+    /// The mouse is over a component, a `Dropdown` or the list of a
+    /// `ComboBox` is open, or something holds the pointer capture.
+    pub mouse: bool,
+    /// A `TextBox` is being typed into, or a key or gamepad button of the
+    /// `InputMap` moved focus or was used by the focused component.
+    pub keyboard: bool,
+}
+
+/// A snapshot of the input for a single frame. The `GuiHandler` only ever
+/// reads input through this type, so it can be filled in from raylib with
+/// `InputState::from_raylib`, from another windowing library, or by hand to
+/// drive the GUI with synthetic input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputState {
+    pub buttons_down: Vec<PointerButton>,
+    pub buttons_pressed: Vec<PointerButton>,
+    pub buttons_released: Vec<PointerButton>,
+    pub characters: Vec<char>,
+    /// How quickly clicks have to follow each other and how long presses
+    /// have to be held to make double-clicks and long presses. This is
+    /// filled in by `GuiHandler::update`.
+    pub click_timings: ClickTimings,
+    /// The component that has keyboard or gamepad focus. This is filled in by
+    /// `GuiHandler::update`.
+    pub focused: Option<WidgetId>,
+    /// The time in seconds since the last frame, which animations use to move
+    /// at the same speed at any frame rate.
+    pub frame_time: f32,
+    pub gamepad_buttons_down: Vec<GamepadButton>,
+    pub gamepad_buttons_pressed: Vec<GamepadButton>,
+    pub keys_down: Vec<Key>,
+    pub keys_pressed: Vec<Key>,
+    pub keys_released: Vec<Key>,
+    /// How far the left analog stick of the gamepad is pushed, from -1 to 1 on
+    /// each axis, where positive values are right and down.
+    pub left_stick: (f32, f32),
+    pub mouse_position: Point,
+    pub mouse_wheel: f32,
+    /// The component the left mouse button was pressed on, which is the only
+    /// component given the mouse until the button is released. This is
+    /// filled in by `GuiHandler::update`.
+    pub pointer_capture: Option<WidgetId>,
+    pub screen_size: Dimensions,
+}
+
+impl InputState {
+    /// Creates a new `InputState` with the mouse at (0, 0) and nothing
+    /// pressed.
+    pub fn new() -> Self { Self::default() }
+
+    /// Reads the current frame's input from raylib, including the first
+    /// gamepad if one is connected. This consumes raylib's queue of typed
+    /// characters, so `get_char_pressed` will return nothing for the rest of
+    /// the frame.
+    pub fn from_raylib(rl_handler: &mut RaylibHandle) -> Self {
+        let mut input = Self {
+            frame_time: rl_handler.get_frame_time(),
+            mouse_position: (rl_handler.get_mouse_x(), rl_handler.get_mouse_y()),
+            mouse_wheel: rl_handler.get_mouse_wheel_move(),
+            screen_size: (
+                rl_handler.get_screen_width(),
+                rl_handler.get_screen_height(),
+            ),
+            ..Self::default()
+        };
+
+        for button in PointerButton::ALL.iter().copied() {
+            if rl_handler.is_mouse_button_down(button.to_raylib()) {
+                input.buttons_down.push(button);
+            }
+            if rl_handler.is_mouse_button_pressed(button.to_raylib()) {
+                input.buttons_pressed.push(button);
+            }
+            if rl_handler.is_mouse_button_released(button.to_raylib()) {
+                input.buttons_released.push(button);
+            }
+        }
+
+        for key in Key::ALL.iter().copied() {
+            if rl_handler.is_key_down(key.to_raylib()) {
+                input.keys_down.push(key);
+            }
+            if rl_handler.is_key_pressed(key.to_raylib()) {
+                input.keys_pressed.push(key);
+            }
+            if rl_handler.is_key_released(key.to_raylib()) {
+                input.keys_released.push(key);
+            }
+        }
+
+        if rl_handler.is_gamepad_available(0) {
+            for button in GamepadButton::ALL.iter().copied() {
+                if rl_handler.is_gamepad_button_down(0, button.to_raylib()) {
+                    input.gamepad_buttons_down.push(button);
+                }
+                if rl_handler.is_gamepad_button_pressed(0, button.to_raylib()) {
+                    input.gamepad_buttons_pressed.push(button);
+                }
+            }
+
+            input.left_stick = (
+                rl_handler.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_X),
+                rl_handler.get_gamepad_axis_movement(0, GamepadAxis::GAMEPAD_AXIS_LEFT_Y),
+            );
+        }
+
+        // SAFETY: `GetCharPressed` only reads raylib's input queue, which is
+        // valid for as long as the `RaylibHandle` exists.
+        loop {
+            let codepoint = unsafe { ffi::GetCharPressed() };
+            if codepoint <= 0 {
+                break;
+            }
+
+            if let Some(c) = std::char::from_u32(codepoint as u32) {
+                input.characters.push(c);
+            }
+        }
+
+        input
+    }
+
+    /// Gives the mouse to the component with the given `id` only, as if the
+    /// left mouse button had been pressed on it.
+    pub fn with_pointer_capture(mut self, id: impl Into<WidgetId>) -> Self {
+        self.pointer_capture = Some(id.into());
+
+        self
+    }
+
+    /// Gives keyboard and gamepad focus to the component with the given `id`.
+    pub fn with_focused(mut self, id: impl Into<WidgetId>) -> Self {
+        self.focused = Some(id.into());
+
+        self
+    }
+
+    /// Sets the mouse position of this `InputState`.
+    pub fn with_mouse_position(mut self, mouse_position: Point) -> Self {
+        self.mouse_position = mouse_position;
+
+        self
+    }
+
+    /// Marks `button` as having been pressed this frame, which also marks it
+    /// as being held down.
+    pub fn with_button_pressed(mut self, button: PointerButton) -> Self {
+        self.buttons_pressed.push(button);
+        self.buttons_down.push(button);
+
+        self
+    }
+
+    /// Marks `button` as being held down.
+    pub fn with_button_down(mut self, button: PointerButton) -> Self {
+        self.buttons_down.push(button);
+
+        self
+    }
+
+    /// Marks `button` as having been released this frame.
+    pub fn with_button_released(mut self, button: PointerButton) -> Self {
+        self.buttons_released.push(button);
+
+        self
+    }
+
+    /// Marks `key` as having been pressed this frame, which also marks it as
+    /// being held down.
+    pub fn with_key_pressed(mut self, key: Key) -> Self {
+        self.keys_pressed.push(key);
+        self.keys_down.push(key);
+
+        self
+    }
+
+    /// Marks `key` as being held down.
+    pub fn with_key_down(mut self, key: Key) -> Self {
+        self.keys_down.push(key);
+
+        self
+    }
+
+    /// Marks `button` on the gamepad as having been pressed this frame, which
+    /// also marks it as being held down.
+    pub fn with_gamepad_button_pressed(mut self, button: GamepadButton) -> Self {
+        self.gamepad_buttons_pressed.push(button);
+        self.gamepad_buttons_down.push(button);
+
+        self
+    }
+
+    /// Marks `button` on the gamepad as being held down.
+    pub fn with_gamepad_button_down(mut self, button: GamepadButton) -> Self {
+        self.gamepad_buttons_down.push(button);
+
+        self
+    }
+
+    /// Sets how far the left analog stick of the gamepad is pushed.
+    pub fn with_left_stick(mut self, left_stick: (f32, f32)) -> Self {
+        self.left_stick = left_stick;
+
+        self
+    }
+
+    /// Adds `text` to the characters typed this frame.
+    pub fn with_characters(mut self, text: &str) -> Self {
+        self.characters.extend(text.chars());
+
+        self
+    }
+
+    /// Sets how far the mouse wheel moved this frame.
+    pub fn with_mouse_wheel(mut self, mouse_wheel: f32) -> Self {
+        self.mouse_wheel = mouse_wheel;
+
+        self
+    }
+
+    /// Sets the time in seconds since the last frame.
+    pub fn with_frame_time(mut self, frame_time: f32) -> Self {
+        self.frame_time = frame_time;
+
+        self
+    }
+
+    /// Sets the size of the screen the GUI is being drawn to.
+    pub fn with_screen_size(mut self, screen_size: Dimensions) -> Self {
+        self.screen_size = screen_size;
+
+        self
+    }
+
+    /// Returns `true` if `button` was pressed this frame.
+    pub fn is_button_pressed(&self, button: PointerButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    /// Returns `true` if `button` is being held down.
+    pub fn is_button_down(&self, button: PointerButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    /// Returns `true` if `button` was released this frame.
+    pub fn is_button_released(&self, button: PointerButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    /// Returns `true` if `key` was pressed this frame.
+    pub fn is_key_pressed(&self, key: Key) -> bool { self.keys_pressed.contains(&key) }
+
+    /// Returns `true` if `key` is being held down.
+    pub fn is_key_down(&self, key: Key) -> bool { self.keys_down.contains(&key) }
+
+    /// Returns `true` if `key` was released this frame.
+    pub fn is_key_released(&self, key: Key) -> bool { self.keys_released.contains(&key) }
+
+    /// Returns `true` if `button` on the gamepad was pressed this frame.
+    pub fn is_gamepad_button_pressed(&self, button: GamepadButton) -> bool {
+        self.gamepad_buttons_pressed.contains(&button)
+    }
+
+    /// Returns `true` if `button` on the gamepad is being held down.
+    pub fn is_gamepad_button_down(&self, button: GamepadButton) -> bool {
+        self.gamepad_buttons_down.contains(&button)
+    }
+}
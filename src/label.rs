@@ -1,32 +1,33 @@
 use crate::{
     common::*,
     gui_component::GuiComponentBehaviour,
-    prelude::{state_get_colour, Colour, Dimensions, Point, StateColour},
+    prelude::{Callback, Dimensions, Point, Renderer, TextMeasurer, Theme, WidgetId, WidgetStyle},
 };
-use raylib::prelude::{text::measure_text, *};
 use std::iter::FromIterator;
 
-#[derive(PartialEq)]
-pub struct Label {
-    colour: Colour,
-    font_size: i32,
+pub struct Label<T> {
+    clicked: bool,
+    custom_style: bool,
+    enabled: bool,
+    id: WidgetId,
+    on_click: Option<Callback<T>>,
     pub dimensions: Dimensions,
     pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
     text: String,
+    text_measurer: TextMeasurer,
+    visible: bool,
 }
 
-impl Label {
+impl<T> Label<T> {
     /// Create a new button, automatically figuring out width depending on the
     /// `text` and the given `font_size`.
     pub fn new(text: &str, font_size: i32, position: Point) -> Self {
-        let dimensions = (measure_text(text, font_size) + 10, 50);
-        Self {
-            text: text.to_string(),
-            font_size,
-            position,
-            dimensions,
-            colour: state_get_colour(StateColour::Active),
-        }
+        let mut label = Self::new_with_dimensions(text, font_size, position, (0, 0));
+        label.fit_text();
+
+        label
     }
 
     /// Create a new button with specific `dimensions`.
@@ -38,59 +39,139 @@ impl Label {
     ) -> Self {
         Self {
             text: text.to_string(),
-            font_size,
             position,
             dimensions,
-            colour: state_get_colour(StateColour::Active),
+            preferred_dimensions: dimensions,
+            clicked: false,
+            custom_style: false,
+            enabled: true,
+            style: WidgetStyle {
+                font_size,
+                ..Theme::default().label
+            },
+            id: WidgetId::unique(),
+            on_click: None,
+            text_measurer: TextMeasurer::default(),
+            visible: true,
         }
     }
 
-    /// Set the text of a button, automatically resizing it's width to
-    /// compensate for the change.
+    /// Returns the `WidgetId` of the label.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Sets the function used to measure the width of the label's text,
+    /// resizing it to fit.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+        self.fit_text();
+
+        self
+    }
+
+    /// Set the text of a label, automatically resizing it to compensate for
+    /// the change.
     pub fn set_text(&mut self, text: &str) -> &Self {
-        let new_width = measure_text(text, self.font_size);
-        self.resize((new_width, self.dimensions.1));
+        self.text = text.to_string();
+        self.fit_text();
 
         &*self
     }
 
-    /// Resizes the button to the given `new_dimensions`.
+    fn fit_text(&mut self) {
+        let text_width = self.text_measurer.measure(&self.text, self.style.font_size);
+        self.preferred_dimensions = self.style.fit_text(text_width);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Gives the label its own `style`, which is used instead of the theme of
+    /// the `GuiHandler`, resizing it to fit.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
+
+        self
+    }
+
+    /// Styles the label using `theme`, unless it has been given its own style
+    /// with `set_style`, resizing it to fit.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.label.clone();
+            self.fit_text();
+        }
+    }
+
+    /// Returns whether or not the label is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the label.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the label responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the label. A disabled label is drawn in the disabled
+    /// colours of its style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Returns the dimensions the label was created with, updated to fit its
+    /// text whenever it is changed with `set_text` or restyled. Layouts use
+    /// this as the size the label would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the label to the given `new_dimensions`.
     pub fn resize(&mut self, new_dimensions: Dimensions) { self.dimensions = new_dimensions; }
+
+    /// Sets the callback that is called when the label has been clicked.
+    pub fn set_on_click(&mut self, on_click: Callback<T>) -> &mut Self {
+        self.on_click = Some(on_click);
+
+        self
+    }
+
+    /// Calls the label's callback if it was clicked during the last update.
+    pub fn execute_callbacks(&mut self, state: &mut T) {
+        if std::mem::take(&mut self.clicked) {
+            if let Some(on_click) = self.on_click.as_mut() {
+                on_click(state);
+            }
+        }
+    }
 }
 
-impl GuiComponentBehaviour<()> for Label {
+impl<T> GuiComponentBehaviour<bool> for Label<T> {
     /// Draw `Label` to screen.
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle) {
-        let mouse_position = (draw_handler.get_mouse_x(), draw_handler.get_mouse_y());
-        self.is_hovered(mouse_position);
-
-        draw_handler.draw_rectangle(
-            self.position.0,
-            self.position.1,
-            self.dimensions.0 + 10,
-            self.dimensions.1,
-            self.colour,
-        );
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = resting_state(self.enabled);
 
-        draw_handler.draw_line_ex(
-            Vector2::new(
-                self.position.0 as f32,
-                (self.position.1 + self.dimensions.1) as f32,
-            ),
-            Vector2::new(
-                (self.position.0 + self.dimensions.0 + 10) as f32,
-                (self.position.1 + self.dimensions.1) as f32,
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        renderer.draw_line(
+            (self.position.0, self.position.1 + self.dimensions.1),
+            (
+                self.position.0 + self.dimensions.0,
+                self.position.1 + self.dimensions.1,
             ),
             3.5,
-            Colour::BLACK,
+            self.style.foreground.get(state),
         );
 
-        draw_handler.draw_text(
-            self.text.as_str(),
-            self.position.0 + 10,
-            self.position.1 + self.dimensions.1 - 30,
-            self.font_size,
-            state_get_colour(StateColour::Text),
+        draw_widget_text(
+            renderer,
+            &self.text,
+            self.position,
+            self.dimensions,
+            &self.style,
+            state,
         );
     }
 
@@ -99,14 +180,17 @@ impl GuiComponentBehaviour<()> for Label {
     /// being hovered over.
     fn is_hovered(&mut self, _mouse_position: Point) -> bool { false }
 
-    /// Would check whether or not the user is clicking on the label, however
-    /// since the label requires no funcionality when being clicked, the
-    /// function always implicitly returns `()`.
-    fn is_clicked(&mut self, _mouse_position: Point, _is_clicked: bool) {}
+    /// Checks whether or not the user is clicking on the label. Clicking a
+    /// label does nothing to the label itself, but is reported as an event.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        self.clicked = is_inside(self.position, self.dimensions, mouse_position) && is_clicked;
+
+        self.clicked
+    }
 }
 
-impl FromIterator<DrawableType> for Vec<Label> {
-    fn from_iter<T: IntoIterator<Item = DrawableType>>(iter: T) -> Self {
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<Label<T>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
         let mut c = Vec::new();
 
         for i in iter {
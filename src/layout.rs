@@ -0,0 +1,703 @@
+use crate::{common::*, prelude::*};
+
+/// Where a component is placed inside of the space a `Layout` gives it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    Start,
+    Centre,
+    End,
+    /// Stretches the component to fill the space, up to its maximum size.
+    Fill,
+}
+
+/// The point of the screen or parent `Layout` that an anchored `Layout` is
+/// placed relative to. The same point of the anchored `Layout` is placed on
+/// it, so a `Layout` anchored to `BottomRight` sits in the bottom-right corner.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Centre,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns how far across and down the anchor point is, from 0.0 to 1.0.
+    fn fractions(self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::Top => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Centre => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::Bottom => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// How far an anchored `Layout` is moved away from its anchor point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Offset {
+    Pixels(i32),
+    /// A percentage of the width or height of the screen or parent `Layout`.
+    Percent(f32),
+}
+
+impl Offset {
+    fn resolve(self, length: i32) -> i32 {
+        match self {
+            Offset::Pixels(pixels) => pixels,
+            Offset::Percent(percent) => (length as f32 * percent / 100.0) as i32,
+        }
+    }
+}
+
+/// How a `Layout` arranges its items.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LayoutDirection {
+    /// Items are stacked from top to bottom.
+    Vertical,
+    /// Items are placed side by side from left to right.
+    Horizontal,
+    /// Items fill rows of the given number of columns from left to right.
+    Grid(usize),
+}
+
+/// Something that is positioned by a `Layout`.
+#[derive(Clone, Debug)]
+pub enum LayoutItem {
+    /// A component, sized to its preferred dimensions clamped between
+    /// `min_dimensions` and `max_dimensions`. Hidden components are left out,
+    /// as if they were not in the `Layout`.
+    Widget {
+        id: WidgetId,
+        min_dimensions: Dimensions,
+        max_dimensions: Option<Dimensions>,
+    },
+    /// A nested `Layout`.
+    Layout(Layout),
+}
+
+fn clamp_dimensions(
+    dimensions: Dimensions,
+    min_dimensions: Dimensions,
+    max_dimensions: Option<Dimensions>,
+) -> Dimensions {
+    let (width, height) = match max_dimensions {
+        Some(max) => (dimensions.0.min(max.0), dimensions.1.min(max.1)),
+        None => dimensions,
+    };
+
+    (width.max(min_dimensions.0), height.max(min_dimensions.1))
+}
+
+/// Returns the offset and size of something that would like to be `size` long
+/// inside of `available` space.
+fn align(size: i32, available: i32, alignment: Alignment, max: Option<i32>) -> (i32, i32) {
+    match alignment {
+        Alignment::Start => (0, size),
+        Alignment::Centre => ((available - size) / 2, size),
+        Alignment::End => (available - size, size),
+        Alignment::Fill => (0, max.map_or(available, |max| available.min(max)).max(size)),
+    }
+}
+
+impl LayoutItem {
+    fn measure<T, E>(&self, components: &[DrawableType<T, E>]) -> Option<Dimensions> {
+        match self {
+            LayoutItem::Widget {
+                id,
+                min_dimensions,
+                max_dimensions,
+            } => find_component(components, *id)
+                .filter(|c| c.is_visible())
+                .map(|c| {
+                    clamp_dimensions(
+                        c.get_preferred_dimensions(),
+                        *min_dimensions,
+                        *max_dimensions,
+                    )
+                }),
+            LayoutItem::Layout(l) => match l.anchor {
+                Some(_) => None,
+                None => Some(l.measure(components)),
+            },
+        }
+    }
+
+    fn max_dimensions(&self) -> Option<Dimensions> {
+        match self {
+            LayoutItem::Widget { max_dimensions, .. } => *max_dimensions,
+            LayoutItem::Layout(l) => l.max_dimensions,
+        }
+    }
+
+    fn place<T, E>(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        components: &mut [DrawableType<T, E>],
+    ) {
+        match self {
+            LayoutItem::Widget { id, .. } =>
+                if let Some(component) = find_component_mut(components, *id) {
+                    component.set_position(position);
+                    component.resize(dimensions);
+                },
+            LayoutItem::Layout(l) => l.arrange(position, dimensions, components),
+        }
+    }
+}
+
+/// A container that positions and sizes components every frame, so that
+/// changing the text of a component or the size of the window reflows
+/// everything around it. `Layout`s refer to components by their `WidgetId` and
+/// are given to `GuiHandler::add_layout` once they have been built.
+///
+/// ```ignore
+/// let play = g_handler.add_button("Play", MenuEvent::Play);
+/// let quit = g_handler.add_button("Quit", MenuEvent::Quit);
+///
+/// let mut menu = Layout::vbox();
+/// menu.set_position((100, 100))
+///     .set_padding(10)
+///     .set_spacing(5)
+///     .set_alignment(Alignment::Fill)
+///     .add_widget(play)
+///     .add_widget(quit);
+/// g_handler.add_layout(menu);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Layout {
+    alignment: Alignment,
+    anchor: Option<Anchor>,
+    dimensions: Dimensions,
+    direction: LayoutDirection,
+    id: WidgetId,
+    items: Vec<LayoutItem>,
+    max_dimensions: Option<Dimensions>,
+    min_dimensions: Dimensions,
+    offset: (Offset, Offset),
+    padding: i32,
+    pub position: Point,
+    spacing: i32,
+}
+
+impl Layout {
+    /// Creates a new, empty `Layout` that arranges its items in the given
+    /// `direction`.
+    pub fn new(direction: LayoutDirection) -> Self {
+        Self {
+            alignment: Alignment::Start,
+            anchor: None,
+            dimensions: (0, 0),
+            direction,
+            id: WidgetId::unique(),
+            items: Vec::new(),
+            max_dimensions: None,
+            min_dimensions: (0, 0),
+            offset: (Offset::Pixels(0), Offset::Pixels(0)),
+            padding: 0,
+            position: (0, 0),
+            spacing: 0,
+        }
+    }
+
+    /// Creates a new `Layout` that stacks its items from top to bottom.
+    pub fn vbox() -> Self { Self::new(LayoutDirection::Vertical) }
+
+    /// Creates a new `Layout` that places its items from left to right.
+    pub fn hbox() -> Self { Self::new(LayoutDirection::Horizontal) }
+
+    /// Creates a new `Layout` that places its items in rows of `columns`.
+    pub fn grid(columns: usize) -> Self { Self::new(LayoutDirection::Grid(columns.max(1))) }
+
+    /// Returns the `WidgetId` of the `Layout`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns the dimensions the `Layout` took up the last time it was
+    /// applied.
+    pub fn get_dimensions(&self) -> Dimensions { self.dimensions }
+
+    /// Sets the position of the top-left corner of the `Layout`. This is
+    /// ignored for anchored `Layout`s and `Layout`s nested inside of another
+    /// `Layout`.
+    pub fn set_position(&mut self, position: Point) -> &mut Self {
+        self.position = position;
+
+        self
+    }
+
+    /// Anchors the `Layout` to a point of the screen, or of its parent if it
+    /// is nested inside of another `Layout`, so that it stays in place when
+    /// the window is resized. Anchored `Layout`s that are nested are placed
+    /// on top of the other items of their parent rather than next to them.
+    pub fn set_anchor(&mut self, anchor: Anchor) -> &mut Self {
+        self.anchor = Some(anchor);
+
+        self
+    }
+
+    /// Sets how far the `Layout` is moved away from its anchor point.
+    pub fn set_offset(&mut self, x: Offset, y: Offset) -> &mut Self {
+        self.offset = (x, y);
+
+        self
+    }
+
+    /// Sets the space between the edge of the `Layout` and its items.
+    pub fn set_padding(&mut self, padding: i32) -> &mut Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// Sets the space between each of the items of the `Layout`.
+    pub fn set_spacing(&mut self, spacing: i32) -> &mut Self {
+        self.spacing = spacing;
+
+        self
+    }
+
+    /// Sets where the items are placed inside of the space they are given.
+    pub fn set_alignment(&mut self, alignment: Alignment) -> &mut Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Sets the smallest dimensions the `Layout` can have.
+    pub fn set_min_dimensions(&mut self, min_dimensions: Dimensions) -> &mut Self {
+        self.min_dimensions = min_dimensions;
+
+        self
+    }
+
+    /// Sets the largest dimensions the `Layout` can have.
+    pub fn set_max_dimensions(&mut self, max_dimensions: Dimensions) -> &mut Self {
+        self.max_dimensions = Some(max_dimensions);
+
+        self
+    }
+
+    /// Adds the component with the given `id` to the `Layout`.
+    pub fn add_widget(&mut self, id: impl Into<WidgetId>) -> &mut Self {
+        self.add_widget_with_limits(id, (0, 0), None)
+    }
+
+    /// Adds the component with the given `id` to the `Layout`, keeping its
+    /// size between `min_dimensions` and `max_dimensions`.
+    pub fn add_widget_with_limits(
+        &mut self,
+        id: impl Into<WidgetId>,
+        min_dimensions: Dimensions,
+        max_dimensions: Option<Dimensions>,
+    ) -> &mut Self {
+        self.items.push(LayoutItem::Widget {
+            id: id.into(),
+            min_dimensions,
+            max_dimensions,
+        });
+
+        self
+    }
+
+    /// Adds a nested `Layout` to the `Layout`.
+    pub fn add_layout(&mut self, layout: Layout) -> &mut Self {
+        self.items.push(LayoutItem::Layout(layout));
+
+        self
+    }
+
+    /// Removes the component or nested `Layout` with the given `id` from the
+    /// `Layout` and any `Layout`s inside of it.
+    pub fn remove(&mut self, id: impl Into<WidgetId>) {
+        let id = id.into();
+
+        self.items.retain(|item| match item {
+            LayoutItem::Widget { id: item_id, .. } => *item_id != id,
+            LayoutItem::Layout(l) => l.id != id,
+        });
+
+        for item in self.items.iter_mut() {
+            if let LayoutItem::Layout(l) = item {
+                l.remove(id);
+            }
+        }
+    }
+
+    /// Returns the ids of the components in the `Layout` and the `Layout`s
+    /// inside of it, in the order that they are placed.
+    pub fn get_widget_ids(&self) -> Vec<WidgetId> {
+        let mut ids = Vec::new();
+
+        for item in self.items.iter() {
+            match item {
+                LayoutItem::Widget { id, .. } => ids.push(*id),
+                LayoutItem::Layout(l) => ids.extend(l.get_widget_ids()),
+            }
+        }
+
+        ids
+    }
+
+    /// Returns the dimensions the `Layout` needs to fit all of its items.
+    pub fn measure<T, E>(&self, components: &[DrawableType<T, E>]) -> Dimensions {
+        let sizes = self.measure_items(components);
+        let spacing = |count: usize| self.spacing * (count.max(1) as i32 - 1);
+
+        let content = match self.direction {
+            LayoutDirection::Vertical => (
+                sizes.iter().map(|(_, s)| s.0).max().unwrap_or(0),
+                sizes.iter().map(|(_, s)| s.1).sum::<i32>() + spacing(sizes.len()),
+            ),
+            LayoutDirection::Horizontal => (
+                sizes.iter().map(|(_, s)| s.0).sum::<i32>() + spacing(sizes.len()),
+                sizes.iter().map(|(_, s)| s.1).max().unwrap_or(0),
+            ),
+            LayoutDirection::Grid(columns) => {
+                let (widths, heights) = Self::grid_tracks(&sizes, columns);
+
+                (
+                    widths.iter().sum::<i32>() + spacing(widths.len()),
+                    heights.iter().sum::<i32>() + spacing(heights.len()),
+                )
+            },
+        };
+
+        clamp_dimensions(
+            (content.0 + self.padding * 2, content.1 + self.padding * 2),
+            self.min_dimensions,
+            self.max_dimensions,
+        )
+    }
+
+    fn measure_items<T, E>(&self, components: &[DrawableType<T, E>]) -> Vec<(usize, Dimensions)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| item.measure(components).map(|size| (index, size)))
+            .collect()
+    }
+
+    /// Returns the width of each column and height of each row of a grid.
+    fn grid_tracks(sizes: &[(usize, Dimensions)], columns: usize) -> (Vec<i32>, Vec<i32>) {
+        let rows = sizes.len().div_ceil(columns);
+        let mut widths = vec![0; columns.min(sizes.len())];
+        let mut heights = vec![0; rows];
+
+        for (cell, (_, size)) in sizes.iter().enumerate() {
+            widths[cell % columns] = widths[cell % columns].max(size.0);
+            heights[cell / columns] = heights[cell / columns].max(size.1);
+        }
+
+        (widths, heights)
+    }
+
+    /// Positions and sizes every item of the `Layout` with the dimensions it
+    /// needs, placing it relative to the given parent area if it is anchored
+    /// or at its own position otherwise.
+    pub fn apply<T, E>(
+        &mut self,
+        parent_position: Point,
+        parent_dimensions: Dimensions,
+        components: &mut [DrawableType<T, E>],
+    ) {
+        let dimensions = self.measure(components);
+
+        let position = match self.anchor {
+            Some(anchor) => {
+                let fractions = anchor.fractions();
+
+                (
+                    parent_position.0
+                        + ((parent_dimensions.0 - dimensions.0) as f32 * fractions.0) as i32
+                        + self.offset.0.resolve(parent_dimensions.0),
+                    parent_position.1
+                        + ((parent_dimensions.1 - dimensions.1) as f32 * fractions.1) as i32
+                        + self.offset.1.resolve(parent_dimensions.1),
+                )
+            },
+            None => self.position,
+        };
+
+        self.arrange(position, dimensions, components);
+    }
+
+    /// Positions and sizes every item of the `Layout` to fit inside of the
+    /// given area.
+    pub fn arrange<T, E>(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        components: &mut [DrawableType<T, E>],
+    ) {
+        self.position = position;
+        self.dimensions = dimensions;
+
+        let sizes = self.measure_items(components);
+        let inner_position = (position.0 + self.padding, position.1 + self.padding);
+        let inner_dimensions = (
+            dimensions.0 - self.padding * 2,
+            dimensions.1 - self.padding * 2,
+        );
+        let content_length = |length: fn(&Dimensions) -> i32| {
+            sizes.iter().map(|(_, s)| length(s)).sum::<i32>()
+                + self.spacing * (sizes.len().max(1) as i32 - 1)
+        };
+
+        match self.direction {
+            LayoutDirection::Vertical => {
+                let (offset, _) = align(
+                    content_length(|s| s.1),
+                    inner_dimensions.1,
+                    self.alignment,
+                    None,
+                );
+                let mut y = inner_position.1 + offset.max(0);
+
+                for (index, size) in sizes {
+                    let item = &mut self.items[index];
+                    let max_width = item.max_dimensions().map(|max| max.0);
+                    let (x, width) = align(size.0, inner_dimensions.0, self.alignment, max_width);
+
+                    item.place((inner_position.0 + x, y), (width, size.1), components);
+                    y += size.1 + self.spacing;
+                }
+            },
+            LayoutDirection::Horizontal => {
+                let (offset, _) = align(
+                    content_length(|s| s.0),
+                    inner_dimensions.0,
+                    self.alignment,
+                    None,
+                );
+                let mut x = inner_position.0 + offset.max(0);
+
+                for (index, size) in sizes {
+                    let item = &mut self.items[index];
+                    let max_height = item.max_dimensions().map(|max| max.1);
+                    let (y, height) = align(size.1, inner_dimensions.1, self.alignment, max_height);
+
+                    item.place((x, inner_position.1 + y), (size.0, height), components);
+                    x += size.0 + self.spacing;
+                }
+            },
+            LayoutDirection::Grid(columns) => {
+                let (widths, heights) = Self::grid_tracks(&sizes, columns);
+
+                for (cell, (index, size)) in sizes.into_iter().enumerate() {
+                    let (column, row) = (cell % columns, cell / columns);
+                    let cell_position = (
+                        inner_position.0
+                            + widths[..column].iter().sum::<i32>()
+                            + self.spacing * column as i32,
+                        inner_position.1
+                            + heights[..row].iter().sum::<i32>()
+                            + self.spacing * row as i32,
+                    );
+
+                    let item = &mut self.items[index];
+                    let max = item.max_dimensions();
+                    let (x, width) =
+                        align(size.0, widths[column], self.alignment, max.map(|m| m.0));
+                    let (y, height) = align(size.1, heights[row], self.alignment, max.map(|m| m.1));
+
+                    item.place(
+                        (cell_position.0 + x, cell_position.1 + y),
+                        (width, height),
+                        components,
+                    );
+                }
+            },
+        }
+
+        for item in self.items.iter_mut() {
+            if let LayoutItem::Layout(l) = item {
+                if l.anchor.is_some() {
+                    l.apply(inner_position, inner_dimensions, components);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buttons(count: usize) -> Vec<DrawableType<(), ()>> { sized_buttons(&vec![(60, 30); count]) }
+
+    fn sized_buttons(dimensions: &[Dimensions]) -> Vec<DrawableType<(), ()>> {
+        dimensions
+            .iter()
+            .map(|&dimensions| {
+                DrawableType::Button(Button::new_with_dimensions("", (), 20, (0, 0), dimensions))
+            })
+            .collect()
+    }
+
+    fn layout_of(mut layout: Layout, components: &[DrawableType<(), ()>]) -> Layout {
+        for component in components.iter() {
+            layout.add_widget(component.get_id());
+        }
+
+        layout
+    }
+
+    #[test]
+    fn hidden_widgets_are_left_out_without_a_gap() {
+        let mut components = buttons(3);
+        let mut layout = Layout::vbox();
+        layout.set_spacing(10);
+        for component in components.iter() {
+            layout.add_widget(component.get_id());
+        }
+        components[1].set_visible(false);
+
+        assert_eq!(layout.measure(&components), (60, 70));
+
+        layout.arrange((5, 5), (60, 70), &mut components);
+        assert_eq!(components[0].get_position(), (5, 5));
+        assert_eq!(components[2].get_position(), (5, 45));
+    }
+
+    #[test]
+    fn grid_cells_are_sized_to_the_largest_item_in_their_row_and_column() {
+        let mut components = sized_buttons(&[(60, 30), (40, 50), (80, 20)]);
+        let mut layout = layout_of(Layout::grid(2), &components);
+        layout.set_padding(5).set_spacing(10);
+
+        assert_eq!(layout.measure(&components), (140, 90));
+
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(layout.get_dimensions(), (140, 90));
+        assert_eq!(components[0].get_position(), (5, 5));
+        assert_eq!(components[1].get_position(), (95, 5));
+        assert_eq!(components[2].get_position(), (5, 65));
+        assert_eq!(components[2].get_dimensions(), (80, 20));
+    }
+
+    #[test]
+    fn items_are_aligned_across_the_layout() {
+        let mut components = sized_buttons(&[(60, 30), (100, 30)]);
+        let mut layout = layout_of(Layout::vbox(), &components);
+
+        layout.set_alignment(Alignment::Centre);
+        layout.arrange((0, 0), (100, 100), &mut components);
+        assert_eq!(components[0].get_position(), (20, 20));
+        assert_eq!(components[1].get_position(), (0, 50));
+
+        layout.set_alignment(Alignment::End);
+        layout.arrange((0, 0), (100, 100), &mut components);
+        assert_eq!(components[0].get_position(), (40, 40));
+
+        layout.set_alignment(Alignment::Fill);
+        layout.arrange((0, 0), (100, 60), &mut components);
+        assert_eq!(components[0].get_position(), (0, 0));
+        assert_eq!(components[0].get_dimensions(), (100, 30));
+    }
+
+    #[test]
+    fn filled_items_stop_growing_at_their_maximum_size() {
+        let mut components = sized_buttons(&[(60, 30), (100, 30)]);
+        let mut layout = Layout::vbox();
+        layout
+            .set_alignment(Alignment::Fill)
+            .add_widget_with_limits(components[0].get_id(), (0, 0), Some((80, 30)))
+            .add_widget(components[1].get_id());
+
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_dimensions(), (80, 30));
+        assert_eq!(components[1].get_dimensions(), (100, 30));
+    }
+
+    #[test]
+    fn widgets_and_layouts_are_kept_between_their_size_limits() {
+        let mut components = buttons(2);
+        let mut layout = Layout::hbox();
+        layout
+            .add_widget_with_limits(components[0].get_id(), (80, 40), None)
+            .add_widget_with_limits(components[1].get_id(), (0, 0), Some((50, 20)));
+
+        assert_eq!(layout.measure(&components), (130, 40));
+
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_dimensions(), (80, 40));
+        assert_eq!(components[1].get_dimensions(), (50, 20));
+
+        layout.set_min_dimensions((200, 100));
+        assert_eq!(layout.measure(&components), (200, 100));
+
+        layout
+            .set_min_dimensions((0, 0))
+            .set_max_dimensions((100, 30));
+        assert_eq!(layout.measure(&components), (100, 30));
+    }
+
+    #[test]
+    fn anchored_layouts_are_placed_on_their_anchor_point() {
+        let mut components = buttons(1);
+        let mut layout = layout_of(Layout::vbox(), &components);
+
+        layout.set_anchor(Anchor::BottomRight);
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (740, 570));
+
+        layout.set_anchor(Anchor::Centre);
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (370, 285));
+
+        layout.set_anchor(Anchor::TopRight);
+        layout.apply((100, 50), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (840, 50));
+    }
+
+    #[test]
+    fn offsets_move_layouts_away_from_their_anchor() {
+        let mut components = buttons(1);
+        let mut layout = layout_of(Layout::vbox(), &components);
+
+        layout
+            .set_anchor(Anchor::BottomRight)
+            .set_offset(Offset::Pixels(-10), Offset::Percent(-10.0));
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (730, 510));
+
+        layout
+            .set_anchor(Anchor::TopLeft)
+            .set_offset(Offset::Percent(25.0), Offset::Percent(50.0));
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (200, 300));
+    }
+
+    #[test]
+    fn nested_anchored_layouts_are_placed_inside_of_their_parent() {
+        let mut components = buttons(2);
+        let mut corner = Layout::vbox();
+        corner
+            .set_anchor(Anchor::TopRight)
+            .add_widget(components[1].get_id());
+        let mut layout = Layout::vbox();
+        layout
+            .set_position((100, 100))
+            .set_padding(10)
+            .set_min_dimensions((300, 200))
+            .add_widget(components[0].get_id())
+            .add_layout(corner);
+
+        assert_eq!(layout.measure(&components), (300, 200));
+
+        layout.apply((0, 0), (800, 600), &mut components);
+        assert_eq!(components[0].get_position(), (110, 110));
+        assert_eq!(components[1].get_position(), (330, 110));
+    }
+}
@@ -1,11 +1,29 @@
 pub mod button;
+pub mod checkbox;
+pub mod click;
+pub mod combo_box;
 pub mod common;
 pub mod dropdown;
+pub mod event;
 pub mod gui_component;
 pub mod gui_handler;
 pub mod gui_theme;
+pub mod handle;
+pub mod input;
 pub mod label;
+pub mod layout;
+pub mod navigation;
 pub mod prelude;
+pub mod progress_bar;
+pub mod radio_group;
+pub mod range_slider;
+pub mod recording;
+pub mod renderer;
+pub mod scroll_bar;
+pub mod scroll_panel;
 pub mod slider;
+pub mod text_box;
+pub mod theme_file;
+pub mod toggle;
 pub mod traits;
 pub mod types;
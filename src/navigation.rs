@@ -0,0 +1,287 @@
+use crate::prelude::{GamepadButton, InputState, Key};
+
+/// Something that moves focus between components, or uses the focused
+/// component, without the mouse.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NavAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Moves focus to the next component. Holding shift turns this into
+    /// `Previous`, so that Shift+Tab moves backwards.
+    Next,
+    Previous,
+    /// Clicks a `Button`, switches a `Checkbox` or `Toggle`, opens a
+    /// `Dropdown` or `ComboBox`, or starts editing a `TextBox`.
+    Activate,
+    /// Closes the open `ComboBox` or `Dropdown` that focus is in.
+    Back,
+    /// Produces a `GuiEvent::NextTab`.
+    NextTab,
+    /// Produces a `GuiEvent::PreviousTab`.
+    PreviousTab,
+}
+
+impl NavAction {
+    /// Returns `true` for the directions, which repeat while they are held.
+    fn is_direction(self) -> bool {
+        matches!(
+            self,
+            NavAction::Up | NavAction::Down | NavAction::Left | NavAction::Right
+        )
+    }
+}
+
+/// Which keys and gamepad buttons perform each `NavAction`, along with how
+/// held directions repeat and how the left analog stick behaves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputMap {
+    gamepad_buttons: Vec<(GamepadButton, NavAction)>,
+    keys: Vec<(Key, NavAction)>,
+    repeat_delay: f32,
+    repeat_interval: f32,
+    slider_speed: f32,
+    stick_dead_zone: f32,
+}
+
+impl Default for InputMap {
+    /// Moves focus with the arrow keys, Tab and the D-pad, activates with
+    /// Enter, Space and the bottom face button, goes back with Escape and the
+    /// right face button and switches tabs with the shoulder buttons.
+    fn default() -> Self {
+        Self {
+            gamepad_buttons: vec![
+                (GamepadButton::DpadUp, NavAction::Up),
+                (GamepadButton::DpadDown, NavAction::Down),
+                (GamepadButton::DpadLeft, NavAction::Left),
+                (GamepadButton::DpadRight, NavAction::Right),
+                (GamepadButton::FaceDown, NavAction::Activate),
+                (GamepadButton::FaceRight, NavAction::Back),
+                (GamepadButton::LeftShoulder, NavAction::PreviousTab),
+                (GamepadButton::RightShoulder, NavAction::NextTab),
+            ],
+            keys: vec![
+                (Key::Up, NavAction::Up),
+                (Key::Down, NavAction::Down),
+                (Key::Left, NavAction::Left),
+                (Key::Right, NavAction::Right),
+                (Key::Tab, NavAction::Next),
+                (Key::Enter, NavAction::Activate),
+                (Key::Space, NavAction::Activate),
+                (Key::Escape, NavAction::Back),
+            ],
+            repeat_delay: 0.4,
+            repeat_interval: 0.1,
+            slider_speed: 0.5,
+            stick_dead_zone: 0.25,
+        }
+    }
+}
+
+impl InputMap {
+    /// Creates a new `InputMap` with the default bindings.
+    pub fn new() -> Self { Self::default() }
+
+    /// Creates a new `InputMap` with nothing bound, keeping the default
+    /// timings.
+    pub fn empty() -> Self {
+        Self {
+            gamepad_buttons: Vec::new(),
+            keys: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    /// Makes `key` perform `action`, replacing whatever it was bound to.
+    pub fn bind_key(&mut self, key: Key, action: NavAction) -> &mut Self {
+        self.unbind_key(key);
+        self.keys.push((key, action));
+
+        self
+    }
+
+    /// Stops `key` from performing any action.
+    pub fn unbind_key(&mut self, key: Key) -> &mut Self {
+        self.keys.retain(|&(k, _)| k != key);
+
+        self
+    }
+
+    /// Makes `button` on the gamepad perform `action`, replacing whatever it
+    /// was bound to.
+    pub fn bind_gamepad_button(&mut self, button: GamepadButton, action: NavAction) -> &mut Self {
+        self.unbind_gamepad_button(button);
+        self.gamepad_buttons.push((button, action));
+
+        self
+    }
+
+    /// Stops `button` on the gamepad from performing any action.
+    pub fn unbind_gamepad_button(&mut self, button: GamepadButton) -> &mut Self {
+        self.gamepad_buttons.retain(|&(b, _)| b != button);
+
+        self
+    }
+
+    /// Returns the action `key` performs, if it is bound.
+    pub fn get_key_action(&self, key: Key) -> Option<NavAction> {
+        self.keys.iter().find(|&&(k, _)| k == key).map(|&(_, a)| a)
+    }
+
+    /// Returns the action `button` on the gamepad performs, if it is bound.
+    pub fn get_gamepad_button_action(&self, button: GamepadButton) -> Option<NavAction> {
+        self.gamepad_buttons
+            .iter()
+            .find(|&&(b, _)| b == button)
+            .map(|&(_, a)| a)
+    }
+
+    /// Sets how many seconds a direction is held before it starts repeating,
+    /// and how many seconds pass between each repeat after that.
+    pub fn set_repeat(&mut self, delay: f32, interval: f32) -> &mut Self {
+        self.repeat_delay = delay.max(0.0);
+        self.repeat_interval = interval.max(f32::EPSILON);
+
+        self
+    }
+
+    /// Sets how far the left analog stick has to be pushed, from 0 to 1,
+    /// before it does anything.
+    pub fn set_stick_dead_zone(&mut self, dead_zone: f32) -> &mut Self {
+        self.stick_dead_zone = dead_zone.clamp(0.0, 0.99);
+
+        self
+    }
+
+    /// Sets how much of its track a focused `Slider` moves each second while
+    /// the left analog stick is pushed all the way.
+    pub fn set_slider_speed(&mut self, slider_speed: f32) -> &mut Self {
+        self.slider_speed = slider_speed.max(0.0);
+
+        self
+    }
+
+    /// Returns how far `axis` of the stick is pushed past the dead zone,
+    /// rescaled so that it still goes from -1 to 1.
+    fn past_dead_zone(&self, axis: f32) -> f32 {
+        match axis.abs() > self.stick_dead_zone {
+            true =>
+                axis.signum() * (axis.abs() - self.stick_dead_zone) / (1.0 - self.stick_dead_zone),
+            false => 0.0,
+        }
+    }
+
+    /// Returns how much of its track a focused `Slider` moves this frame for
+    /// the horizontal position of the left stick.
+    pub(crate) fn slider_adjustment(&self, input: &InputState) -> f32 {
+        self.past_dead_zone(input.left_stick.0).clamp(-1.0, 1.0)
+            * self.slider_speed
+            * input.frame_time
+    }
+
+    /// Returns the direction the left stick is pushed in, ignoring the
+    /// horizontal axis if `horizontal` is `false`.
+    fn stick_direction(&self, input: &InputState, horizontal: bool) -> Option<NavAction> {
+        let x = match horizontal {
+            true => self.past_dead_zone(input.left_stick.0),
+            false => 0.0,
+        };
+        let y = self.past_dead_zone(input.left_stick.1);
+
+        if x == 0.0 && y == 0.0 {
+            None
+        } else if x.abs() > y.abs() {
+            Some(if x > 0.0 {
+                NavAction::Right
+            } else {
+                NavAction::Left
+            })
+        } else {
+            Some(if y > 0.0 {
+                NavAction::Down
+            } else {
+                NavAction::Up
+            })
+        }
+    }
+
+    /// Returns the actions performed this frame. Directions are performed
+    /// when they are first pressed and then repeated while they are held,
+    /// using `repeat` to keep track of them between frames. The stick only
+    /// moves up and down if `stick_horizontal` is `false`, so that it can
+    /// adjust a focused `Slider` instead.
+    pub(crate) fn actions(
+        &self,
+        input: &InputState,
+        repeat: &mut NavRepeat,
+        stick_horizontal: bool,
+    ) -> Vec<NavAction> {
+        let shift = input.is_key_down(Key::LeftShift) || input.is_key_down(Key::RightShift);
+        let resolve = |action| match (action, shift) {
+            (NavAction::Next, true) => NavAction::Previous,
+            _ => action,
+        };
+
+        let pressed = self
+            .keys
+            .iter()
+            .filter(|&&(key, _)| input.is_key_pressed(key))
+            .map(|&(_, action)| action)
+            .chain(
+                self.gamepad_buttons
+                    .iter()
+                    .filter(|&&(button, _)| input.is_gamepad_button_pressed(button))
+                    .map(|&(_, action)| action),
+            )
+            .map(resolve)
+            .collect::<Vec<_>>();
+
+        let held = self
+            .keys
+            .iter()
+            .filter(|&&(key, _)| input.is_key_down(key))
+            .map(|&(_, action)| action)
+            .chain(
+                self.gamepad_buttons
+                    .iter()
+                    .filter(|&&(button, _)| input.is_gamepad_button_down(button))
+                    .map(|&(_, action)| action),
+            )
+            .find(|action| action.is_direction())
+            .or_else(|| self.stick_direction(input, stick_horizontal));
+
+        let mut actions = pressed
+            .iter()
+            .copied()
+            .filter(|action| !action.is_direction())
+            .collect::<Vec<_>>();
+
+        match held {
+            Some(direction) if repeat.held == Some(direction) && !pressed.contains(&direction) => {
+                repeat.timer -= input.frame_time;
+
+                if repeat.timer <= 0.0 {
+                    actions.push(direction);
+                    repeat.timer += self.repeat_interval;
+                }
+            },
+            Some(direction) => {
+                actions.push(direction);
+                repeat.held = Some(direction);
+                repeat.timer = self.repeat_delay;
+            },
+            None => repeat.held = None,
+        }
+
+        actions
+    }
+}
+
+/// Keeps track of the direction that is being held between frames, so that
+/// it can be repeated.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct NavRepeat {
+    held: Option<NavAction>,
+    timer: f32,
+}
@@ -1,4 +1,6 @@
 pub use crate::{
-    button::*, dropdown::*, gui_handler::*, gui_theme::*, label::*, slider::*, traits::*, types::*,
-    *,
+    button::*, checkbox::*, click::*, combo_box::*, dropdown::*, event::*, gui_handler::*,
+    gui_theme::*, handle::*, input::*, label::*, layout::*, navigation::*, progress_bar::*,
+    radio_group::*, range_slider::*, recording::*, renderer::*, scroll_bar::*, scroll_panel::*,
+    slider::*, text_box::*, toggle::*, traits::*, types::*, *,
 };
@@ -0,0 +1,493 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, Point, Renderer, TextMeasurer, Theme, WidgetId, WidgetState, WidgetStyle,
+    },
+};
+
+/// The direction that the filled part of a `ProgressBar` grows in as its
+/// value increases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FillDirection {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+/// A bar that fills up to show a value between a minimum and maximum, such as
+/// health or loading progress. It does not respond to input, and the mouse
+/// passes through it unless it is made interactive.
+pub struct ProgressBar {
+    custom_style: bool,
+    direction: FillDirection,
+    displayed_value: f32,
+    enabled: bool,
+    hovered: bool,
+    id: WidgetId,
+    interactive: bool,
+    max: f32,
+    min: f32,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    segments: u32,
+    show_text: bool,
+    smoothing: f32,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    value: f32,
+    visible: bool,
+}
+
+impl ProgressBar {
+    /// The space in pixels left between the segments of a segmented
+    /// `ProgressBar`.
+    pub const SEGMENT_GAP: i32 = 2;
+
+    /// Create a new `ProgressBar` that fills from left to right, showing
+    /// `value` between `min` and `max`.
+    pub fn new(min: f32, max: f32, value: f32, position: Point, dimensions: Dimensions) -> Self {
+        let mut progress_bar = Self {
+            custom_style: false,
+            dimensions,
+            direction: FillDirection::Right,
+            displayed_value: min,
+            enabled: true,
+            hovered: false,
+            id: WidgetId::unique(),
+            interactive: false,
+            max,
+            min,
+            position,
+            preferred_dimensions: dimensions,
+            segments: 0,
+            show_text: false,
+            smoothing: 0.0,
+            style: Theme::default().progress_bar,
+            text_measurer: TextMeasurer::default(),
+            value: min,
+            visible: true,
+        };
+        progress_bar.set_value_immediately(value);
+
+        progress_bar
+    }
+
+    widget_methods!(ProgressBar);
+    widget_style_methods!(ProgressBar, progress_bar);
+
+    /// Returns whether or not the `ProgressBar` takes the mouse like other
+    /// components.
+    pub fn is_interactive(&self) -> bool { self.interactive }
+
+    /// Makes the `ProgressBar` take the mouse like other components if
+    /// `interactive` is `true`, which draws it in its hovered colours while
+    /// the mouse is over it and stops clicks on it from counting as clicks on
+    /// the game. By default the mouse passes straight through it.
+    pub fn set_interactive(&mut self, interactive: bool) -> &mut Self {
+        self.interactive = interactive;
+        self.hovered &= interactive;
+
+        self
+    }
+
+    /// Returns the value the `ProgressBar` is showing, or moving towards if it
+    /// is smoothed.
+    pub fn get_value(&self) -> f32 { self.value }
+
+    /// Returns the value the filled part of the `ProgressBar` is currently
+    /// drawn at, which lags behind `get_value` while it is smoothed.
+    pub fn get_displayed_value(&self) -> f32 { self.displayed_value }
+
+    /// Sets the value of the `ProgressBar`, keeping it between the minimum and
+    /// maximum. If the `ProgressBar` is smoothed, the filled part moves
+    /// towards it over the next few updates.
+    pub fn set_value(&mut self, value: f32) -> &mut Self {
+        self.value = value.clamp(self.min.min(self.max), self.max.max(self.min));
+
+        if self.smoothing <= 0.0 {
+            self.displayed_value = self.value;
+        }
+
+        self
+    }
+
+    /// Sets the value of the `ProgressBar`, moving the filled part straight
+    /// to it even if the `ProgressBar` is smoothed.
+    pub fn set_value_immediately(&mut self, value: f32) -> &mut Self {
+        self.set_value(value);
+        self.displayed_value = self.value;
+
+        self
+    }
+
+    /// Returns the minimum and maximum values of the `ProgressBar`.
+    pub fn get_range(&self) -> (f32, f32) { (self.min, self.max) }
+
+    /// Sets the minimum and maximum values of the `ProgressBar`, keeping its
+    /// value between them.
+    pub fn set_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.min = min;
+        self.max = max;
+        self.set_value_immediately(self.value);
+
+        self
+    }
+
+    /// Returns how full the filled part is currently drawn, from 0 to 1.
+    pub fn get_fraction(&self) -> f32 {
+        if (self.max - self.min).abs() <= f32::EPSILON {
+            return 1.0;
+        }
+
+        ((self.displayed_value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Sets the direction that the filled part grows in.
+    pub fn set_direction(&mut self, direction: FillDirection) -> &mut Self {
+        self.direction = direction;
+
+        self
+    }
+
+    /// Draws the value over the `ProgressBar` with two decimal places, in the
+    /// same way as the default readout of a `Slider`.
+    pub fn set_show_text(&mut self, show_text: bool) -> &mut Self {
+        self.show_text = show_text;
+
+        self
+    }
+
+    /// Splits the `ProgressBar` into `segments` equal blocks that fill one at
+    /// a time, where 0 draws a single continuous bar. Only as many segments
+    /// are drawn as fit with at least a pixel each.
+    pub fn set_segments(&mut self, segments: u32) -> &mut Self {
+        self.segments = segments;
+
+        self
+    }
+
+    /// Sets roughly how many seconds the filled part takes to catch up with a
+    /// new value, where 0 moves it instantly.
+    pub fn set_smoothing(&mut self, smoothing: f32) -> &mut Self {
+        self.smoothing = smoothing.max(0.0);
+
+        self
+    }
+
+    /// Moves the filled part towards the value by the distance it covers in
+    /// `frame_time` seconds, slowing down as it gets closer.
+    pub fn animate(&mut self, frame_time: f32) {
+        if self.smoothing <= 0.0 {
+            self.displayed_value = self.value;
+            return;
+        }
+
+        let step = 1.0 - (-frame_time / self.smoothing * 4.0).exp();
+        self.displayed_value += (self.value - self.displayed_value) * step;
+
+        // Stops once the difference could no longer be seen.
+        if (self.value - self.displayed_value).abs() <= (self.max - self.min).abs() / 1000.0 {
+            self.displayed_value = self.value;
+        }
+    }
+
+    /// Returns `true` while the filled part is moving towards the value.
+    pub fn is_animating(&self) -> bool { (self.value - self.displayed_value).abs() > f32::EPSILON }
+
+    /// Returns the state the `ProgressBar` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match self.hovered {
+            true => WidgetState::Hovered,
+            false => WidgetState::Default,
+        }
+    }
+
+    /// Returns the part of the area at `position` with `dimensions` that is
+    /// filled when it is `fraction` full.
+    fn fill_area(
+        &self,
+        position: Point,
+        dimensions: Dimensions,
+        fraction: f32,
+    ) -> (Point, Dimensions) {
+        let width = (dimensions.0 as f32 * fraction).round() as i32;
+        let height = (dimensions.1 as f32 * fraction).round() as i32;
+
+        match self.direction {
+            FillDirection::Right => (position, (width, dimensions.1)),
+            FillDirection::Left => (
+                (position.0 + dimensions.0 - width, position.1),
+                (width, dimensions.1),
+            ),
+            FillDirection::Up => (
+                (position.0, position.1 + dimensions.1 - height),
+                (dimensions.0, height),
+            ),
+            FillDirection::Down => (position, (dimensions.0, height)),
+        }
+    }
+
+    /// Returns the area of each segment, in the order that they fill. There
+    /// are fewer segments than asked for if they would not all fit.
+    fn segment_areas(&self, position: Point, dimensions: Dimensions) -> Vec<(Point, Dimensions)> {
+        let vertical = matches!(self.direction, FillDirection::Up | FillDirection::Down);
+        let length = match vertical {
+            true => dimensions.1,
+            false => dimensions.0,
+        };
+        let fitting = ((length + Self::SEGMENT_GAP) / (1 + Self::SEGMENT_GAP)).max(0);
+        let segments = (self.segments.min(i32::MAX as u32) as i32).min(fitting);
+        // Spreads any pixels left over from dividing the length between the
+        // segments, so that the last one still reaches the end.
+        let stride = length + Self::SEGMENT_GAP;
+
+        (0..segments)
+            .map(|index| {
+                let start = stride * index / segments;
+                let segment_length = stride * (index + 1) / segments - start - Self::SEGMENT_GAP;
+
+                match self.direction {
+                    FillDirection::Right => (
+                        (position.0 + start, position.1),
+                        (segment_length, dimensions.1),
+                    ),
+                    FillDirection::Left => (
+                        (
+                            position.0 + dimensions.0 - start - segment_length,
+                            position.1,
+                        ),
+                        (segment_length, dimensions.1),
+                    ),
+                    FillDirection::Up => (
+                        (
+                            position.0,
+                            position.1 + dimensions.1 - start - segment_length,
+                        ),
+                        (dimensions.0, segment_length),
+                    ),
+                    FillDirection::Down => (
+                        (position.0, position.1 + start),
+                        (dimensions.0, segment_length),
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+impl GuiComponentBehaviour<bool> for ProgressBar {
+    /// Draw `ProgressBar` to screen, with the track in the accent colour and
+    /// the filled part in the foreground colour.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        let border = self.style.border_width.max(0);
+        let inner_position = (self.position.0 + border, self.position.1 + border);
+        let inner_dimensions = (
+            self.dimensions.0 - border * 2,
+            self.dimensions.1 - border * 2,
+        );
+        let fraction = self.get_fraction();
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        if self.segments == 0 {
+            let (fill_position, fill_dimensions) =
+                self.fill_area(inner_position, inner_dimensions, fraction);
+
+            renderer.draw_rectangle(
+                inner_position,
+                inner_dimensions,
+                self.style.accent.get(state),
+            );
+            renderer.draw_rectangle(
+                fill_position,
+                fill_dimensions,
+                self.style.foreground.get(state),
+            );
+        } else {
+            let areas = self.segment_areas(inner_position, inner_dimensions);
+            let filled = (fraction * areas.len() as f32 + f32::EPSILON).floor() as usize;
+
+            for (index, (position, dimensions)) in areas.into_iter().enumerate() {
+                let colour = match index < filled {
+                    true => self.style.foreground.get(state),
+                    false => self.style.accent.get(state),
+                };
+
+                renderer.draw_rectangle(position, dimensions, colour);
+            }
+        }
+
+        if self.show_text {
+            let text = format!("{:#.2}", self.displayed_value);
+            let text_width = self.text_measurer.measure(&text, self.style.font_size);
+
+            renderer.draw_text(
+                &text,
+                (
+                    self.position.0 + (self.dimensions.0 - text_width) / 2,
+                    self.position.1 + (self.dimensions.1 - self.style.font_size) / 2,
+                ),
+                self.style.font_size,
+                self.style.text.get(state),
+            );
+        }
+    }
+
+    /// Checks if the `ProgressBar` is being hovered over, which it never is
+    /// unless it is interactive.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered =
+            self.interactive && is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// A `ProgressBar` cannot be clicked, so this always returns `false`.
+    fn is_clicked(&mut self, _mouse_position: Point, _is_clicked: bool) -> bool { false }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{DrawCommand, RecordingRenderer};
+
+    fn progress_bar(dimensions: Dimensions) -> ProgressBar {
+        ProgressBar::new(0.0, 100.0, 50.0, (0, 0), dimensions)
+    }
+
+    /// Returns where each segment starts and how long it is along the
+    /// `ProgressBar`, for one that fills to the right.
+    fn segments(progress_bar: &ProgressBar, dimensions: Dimensions) -> Vec<(i32, i32)> {
+        progress_bar
+            .segment_areas((0, 0), dimensions)
+            .into_iter()
+            .map(|(position, dimensions)| (position.0, dimensions.0))
+            .collect()
+    }
+
+    #[test]
+    fn segments_are_spread_along_the_whole_length() {
+        let mut progress_bar = progress_bar((100, 10));
+        progress_bar.set_segments(4);
+
+        assert_eq!(
+            segments(&progress_bar, (100, 10)),
+            vec![(0, 23), (25, 24), (51, 23), (76, 24)]
+        );
+    }
+
+    #[test]
+    fn only_the_segments_that_fit_are_drawn() {
+        let mut progress_bar = progress_bar((10, 10));
+        progress_bar.set_segments(100);
+
+        assert_eq!(
+            segments(&progress_bar, (10, 10)),
+            vec![(0, 1), (3, 1), (6, 1), (9, 1)]
+        );
+        assert_eq!(segments(&progress_bar, (0, 10)), vec![]);
+        assert_eq!(segments(&progress_bar, (-5, 10)), vec![]);
+    }
+
+    #[test]
+    fn as_many_of_the_drawn_segments_are_filled_as_the_value_reaches() {
+        let mut progress_bar = progress_bar((10, 10));
+        let mut style = Theme::default().progress_bar;
+        style.border_width = 0;
+        progress_bar.set_segments(100).set_style(style.clone());
+
+        let mut renderer = RecordingRenderer::new();
+        progress_bar.draw(&mut renderer);
+        let filled = renderer
+            .display_list()
+            .commands
+            .iter()
+            .filter(|command| {
+                matches!(command, DrawCommand::Rectangle { dimensions: (1, 10), colour, .. }
+                    if *colour == style.foreground.default)
+            })
+            .count();
+
+        assert_eq!(filled, 2);
+    }
+
+    #[test]
+    fn the_filled_part_grows_in_its_direction() {
+        let mut progress_bar = progress_bar((100, 40));
+        let fill = |progress_bar: &mut ProgressBar, direction| {
+            progress_bar
+                .set_direction(direction)
+                .fill_area((10, 20), (100, 40), 0.25)
+        };
+
+        assert_eq!(
+            fill(&mut progress_bar, FillDirection::Right),
+            ((10, 20), (25, 40))
+        );
+        assert_eq!(
+            fill(&mut progress_bar, FillDirection::Left),
+            ((85, 20), (25, 40))
+        );
+        assert_eq!(
+            fill(&mut progress_bar, FillDirection::Up),
+            ((10, 50), (100, 10))
+        );
+        assert_eq!(
+            fill(&mut progress_bar, FillDirection::Down),
+            ((10, 20), (100, 10))
+        );
+    }
+
+    #[test]
+    fn segments_fill_from_the_end_the_bar_grows_from() {
+        let mut progress_bar = progress_bar((100, 10));
+        progress_bar
+            .set_segments(4)
+            .set_direction(FillDirection::Left);
+
+        assert_eq!(segments(&progress_bar, (100, 10))[0], (77, 23));
+    }
+
+    #[test]
+    fn a_smoothed_value_is_caught_up_with_over_time() {
+        let mut progress_bar = progress_bar((100, 10));
+        progress_bar.set_smoothing(0.5).set_value(100.0);
+
+        assert_eq!(progress_bar.get_value(), 100.0);
+        assert_eq!(progress_bar.get_displayed_value(), 50.0);
+        assert!(progress_bar.is_animating());
+
+        progress_bar.animate(0.1);
+        let displayed = progress_bar.get_displayed_value();
+        assert!(displayed > 50.0 && displayed < 100.0);
+
+        for _ in 0..100 {
+            progress_bar.animate(0.1);
+        }
+        assert_eq!(progress_bar.get_displayed_value(), 100.0);
+        assert!(!progress_bar.is_animating());
+
+        progress_bar.set_value_immediately(0.0);
+        assert_eq!(progress_bar.get_displayed_value(), 0.0);
+    }
+
+    #[test]
+    fn an_unsmoothed_value_is_shown_straight_away() {
+        let mut progress_bar = progress_bar((100, 10));
+        progress_bar.set_value(150.0);
+
+        assert_eq!(progress_bar.get_displayed_value(), 100.0);
+        assert_eq!(progress_bar.get_fraction(), 1.0);
+        assert!(!progress_bar.is_animating());
+    }
+}
+
+widget_from_drawables!(ProgressBar, ProgressBar);
@@ -0,0 +1,389 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, Orientation, Point, Renderer, TextMeasurer, Theme, ValueCallback, WidgetId,
+        WidgetState, WidgetStyle,
+    },
+};
+
+/// A group of labelled options where only one can be selected at a time, so
+/// that selecting an option deselects the one that was selected before it.
+pub struct RadioGroup<T> {
+    changed: bool,
+    custom_style: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: Option<usize>,
+    id: WidgetId,
+    on_change: Option<ValueCallback<T, usize>>,
+    options: Vec<String>,
+    orientation: Orientation,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    selected: Option<usize>,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    visible: bool,
+}
+
+impl<T> RadioGroup<T> {
+    /// Create a new `RadioGroup` with an option for each of `options`,
+    /// stacked in a column, automatically figuring out its size.
+    pub fn new(options: &[&str], selected: Option<usize>, position: Point) -> Self {
+        let mut radio_group = Self {
+            changed: false,
+            custom_style: false,
+            dimensions: (0, 0),
+            enabled: true,
+            focused: false,
+            hovered: None,
+            id: WidgetId::unique(),
+            on_change: None,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            orientation: Orientation::Vertical,
+            position,
+            preferred_dimensions: (0, 0),
+            selected: None,
+            style: Theme::default().radio_group,
+            text_measurer: TextMeasurer::default(),
+            visible: true,
+        };
+        radio_group.set_selected(selected);
+        radio_group.fit_text();
+
+        radio_group
+    }
+
+    widget_methods!(RadioGroup);
+    widget_style_methods!(RadioGroup, radio_group, fit_text);
+    widget_on_change!(RadioGroup, usize, |radio_group| radio_group
+        .selected
+        .unwrap_or_default());
+
+    /// Lays the options out in a column if `orientation` is vertical, or in
+    /// a row if it is horizontal, resizing the `RadioGroup` to fit.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        self.orientation = orientation;
+        self.fit_text();
+
+        self
+    }
+
+    /// Returns whether the options are laid out in a column or in a row.
+    pub fn get_orientation(&self) -> Orientation { self.orientation }
+
+    /// Adds an option labelled with `text` after the others, resizing the
+    /// `RadioGroup` to fit.
+    pub fn add_option(&mut self, text: &str) -> &mut Self {
+        self.options.push(text.to_string());
+        self.fit_text();
+
+        self
+    }
+
+    /// Returns the labels of the options of the `RadioGroup`.
+    pub fn get_options(&self) -> &[String] { &self.options }
+
+    /// Returns the index of the selected option, if there is one.
+    pub fn get_selected(&self) -> Option<usize> { self.selected }
+
+    /// Returns the label of the selected option, if there is one.
+    pub fn get_selected_text(&self) -> Option<&str> {
+        self.selected.map(|index| self.options[index].as_str())
+    }
+
+    /// Selects the option at `index`, or deselects every option if it is
+    /// `None`. An index past the last option deselects every option too.
+    pub fn set_selected(&mut self, index: Option<usize>) -> &mut Self {
+        self.selected = index.filter(|&index| index < self.options.len());
+
+        self
+    }
+
+    /// Selects the option `offset` places after the selected one, stopping at
+    /// the first and last options, without the mouse. Returns whether or not
+    /// a different option was selected.
+    pub fn select_by(&mut self, offset: i32) -> bool {
+        if self.options.is_empty() {
+            return false;
+        }
+
+        let last = self.options.len() as i32 - 1;
+        let index = match self.selected {
+            Some(selected) => (selected as i32 + offset).clamp(0, last),
+            None if offset < 0 => last,
+            None => 0,
+        } as usize;
+
+        let changed = self.selected != Some(index);
+        self.selected = Some(index);
+        self.changed |= changed;
+
+        changed
+    }
+
+    /// Returns whether or not the `RadioGroup` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `RadioGroup`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// The diameter of the circle of each option, which matches the font
+    /// size.
+    fn circle_size(&self) -> i32 { self.style.font_size }
+
+    /// Returns the dimensions that fit the circle and label of each option.
+    fn option_dimensions(&self) -> Vec<Dimensions> {
+        self.options
+            .iter()
+            .map(|option| {
+                let text_width = self.text_measurer.measure(option, self.style.font_size);
+
+                self.style
+                    .fit_text(self.circle_size() + self.style.padding.0 + text_width)
+            })
+            .collect()
+    }
+
+    fn fit_text(&mut self) {
+        let sizes = self.option_dimensions();
+
+        self.preferred_dimensions = match self.orientation {
+            Orientation::Vertical => (
+                sizes.iter().map(|s| s.0).max().unwrap_or(0),
+                sizes.iter().map(|s| s.1).sum(),
+            ),
+            Orientation::Horizontal => (
+                sizes.iter().map(|s| s.0).sum(),
+                sizes.iter().map(|s| s.1).max().unwrap_or(0),
+            ),
+        };
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns the position and dimensions of the area of each option, which
+    /// fill the width of a column or the height of a row.
+    fn option_areas(&self) -> Vec<(Point, Dimensions)> {
+        let mut offset = 0;
+
+        self.option_dimensions()
+            .into_iter()
+            .map(|size| {
+                let area = match self.orientation {
+                    Orientation::Vertical => (
+                        (self.position.0, self.position.1 + offset),
+                        (self.dimensions.0, size.1),
+                    ),
+                    Orientation::Horizontal => (
+                        (self.position.0 + offset, self.position.1),
+                        (size.0, self.dimensions.1),
+                    ),
+                };
+                offset += match self.orientation {
+                    Orientation::Vertical => size.1,
+                    Orientation::Horizontal => size.0,
+                };
+
+                area
+            })
+            .collect()
+    }
+
+    /// Returns the index of the option under `mouse_position`, if there is
+    /// one.
+    fn option_at(&self, mouse_position: Point) -> Option<usize> {
+        self.option_areas()
+            .into_iter()
+            .position(|(position, dimensions)| is_inside(position, dimensions, mouse_position))
+    }
+
+    /// Returns the state the option at `index` is currently drawn in.
+    pub fn get_option_state(&self, index: usize) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match self.hovered == Some(index) {
+            true => WidgetState::Hovered,
+            false => WidgetState::Default,
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for RadioGroup<T> {
+    /// Draw `RadioGroup` to screen, filling the circle of the selected
+    /// option with a dot.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let size = self.circle_size();
+
+        draw_background(
+            renderer,
+            self.position,
+            self.dimensions,
+            &self.style,
+            resting_state(self.enabled),
+        );
+
+        for (index, (position, dimensions)) in self.option_areas().into_iter().enumerate() {
+            let state = self.get_option_state(index);
+            let circle_position = (
+                position.0 + self.style.padding.0,
+                position.1 + (dimensions.1 - size) / 2,
+            );
+
+            renderer.draw_rounded_rectangle(
+                circle_position,
+                (size, size),
+                size / 2,
+                self.style.border.get(state),
+            );
+            renderer.draw_rounded_rectangle(
+                (circle_position.0 + 2, circle_position.1 + 2),
+                (size - 4, size - 4),
+                (size - 4) / 2,
+                self.style.accent.get(state),
+            );
+
+            if self.selected == Some(index) {
+                let inset = size / 4;
+
+                renderer.draw_rounded_rectangle(
+                    (circle_position.0 + inset, circle_position.1 + inset),
+                    (size - inset * 2, size - inset * 2),
+                    (size - inset * 2) / 2,
+                    self.style.foreground.get(state),
+                );
+            }
+
+            renderer.draw_text(
+                &self.options[index],
+                (
+                    circle_position.0 + size + self.style.padding.0,
+                    position.1 + (dimensions.1 - self.style.font_size) / 2,
+                ),
+                self.style.font_size,
+                self.style.text.get(state),
+            );
+        }
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
+    }
+
+    /// Checks which option of the `RadioGroup` is being hovered over,
+    /// returning `true` if any of them are.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = self.option_at(mouse_position);
+
+        self.hovered.is_some()
+    }
+
+    /// Checks if an option or its label was clicked, selecting it and
+    /// returning whether or not the selection changed.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let clicked = match is_clicked {
+            true => self.option_at(mouse_position),
+            false => None,
+        };
+        let changed = clicked.is_some() && clicked != self.selected;
+
+        if changed {
+            self.selected = clicked;
+        }
+        self.changed |= changed;
+
+        changed
+    }
+}
+
+widget_from_drawables!(RadioGroup<T>, RadioGroup);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::measure_text_monospace;
+
+    fn radio_group(selected: Option<usize>) -> RadioGroup<Vec<usize>> {
+        let mut radio_group = RadioGroup::new(&["Easy", "Normal", "Hard"], selected, (0, 0));
+        radio_group
+            .set_text_measurer(TextMeasurer::new(measure_text_monospace))
+            .set_on_change(Box::new(|indices: &mut Vec<usize>, index| {
+                indices.push(index)
+            }));
+
+        radio_group
+    }
+
+    /// Clicks the middle of the option at `index`, returning whether or not
+    /// the selection changed.
+    fn click_option(radio_group: &mut RadioGroup<Vec<usize>>, index: usize) -> bool {
+        let (position, dimensions) = radio_group.option_areas()[index];
+
+        radio_group.is_clicked(
+            (position.0 + dimensions.0 / 2, position.1 + dimensions.1 / 2),
+            true,
+        )
+    }
+
+    #[test]
+    fn selecting_an_option_deselects_the_one_before_it() {
+        let mut radio_group = radio_group(Some(0));
+        let mut indices = Vec::new();
+
+        assert!(click_option(&mut radio_group, 2));
+        assert_eq!(radio_group.get_selected(), Some(2));
+        assert_eq!(radio_group.get_selected_text(), Some("Hard"));
+        radio_group.execute_callbacks(&mut indices);
+
+        assert!(click_option(&mut radio_group, 1));
+        assert_eq!(radio_group.get_selected(), Some(1));
+        radio_group.execute_callbacks(&mut indices);
+
+        assert_eq!(indices, vec![2, 1]);
+    }
+
+    #[test]
+    fn clicking_the_selected_option_changes_nothing() {
+        let mut radio_group = radio_group(Some(1));
+        let mut indices = Vec::new();
+
+        assert!(!click_option(&mut radio_group, 1));
+        radio_group.execute_callbacks(&mut indices);
+
+        assert_eq!(radio_group.get_selected(), Some(1));
+        assert_eq!(indices, vec![]);
+    }
+
+    #[test]
+    fn options_are_laid_out_along_the_orientation() {
+        let mut radio_group = radio_group(None);
+        let column = radio_group.option_areas();
+        assert_eq!(column[1].0, (0, column[0].1 .1));
+
+        radio_group.set_orientation(Orientation::Horizontal);
+        let row = radio_group.option_areas();
+        assert_eq!(row[1].0, (row[0].1 .0, 0));
+    }
+
+    #[test]
+    fn selecting_without_the_mouse_stops_at_either_end() {
+        let mut radio_group = radio_group(None);
+
+        assert!(radio_group.select_by(-1));
+        assert_eq!(radio_group.get_selected(), Some(2));
+        assert!(!radio_group.select_by(1));
+        assert!(radio_group.select_by(-5));
+        assert_eq!(radio_group.get_selected(), Some(0));
+
+        radio_group.set_selected(Some(3));
+        assert_eq!(radio_group.get_selected(), None);
+    }
+}
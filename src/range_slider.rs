@@ -0,0 +1,671 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, InputState, Orientation, Point, PointerButton, Renderer, TextMeasurer, Theme,
+        ValueCallback, ValueFormatter, WidgetId, WidgetState, WidgetStyle,
+    },
+    slider::SliderTrack,
+};
+use std::iter::FromIterator;
+
+/// One of the two boxes of a `RangeSlider`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RangeThumb {
+    Low,
+    High,
+}
+
+/// A `Slider` with two boxes that select a range between its minimum and
+/// maximum. The low box can never be moved past the high box.
+pub struct RangeSlider<T> {
+    adjustment: f32,
+    changed: bool,
+    custom_style: bool,
+    dragging: Option<RangeThumb>,
+    enabled: bool,
+    focused: bool,
+    focused_thumb: RangeThumb,
+    formatter: Option<ValueFormatter>,
+    high: f32,
+    high_box_position: Point,
+    hovered: Option<RangeThumb>,
+    id: WidgetId,
+    low: f32,
+    low_box_position: Point,
+    on_change: Option<ValueCallback<T, (f32, f32)>>,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    track: SliderTrack,
+    visible: bool,
+}
+
+impl<T> RangeSlider<T> {
+    /// The space after the track that is kept for the readout of both values.
+    const TEXT_SPACE: Dimensions = (240, 50);
+
+    /// Create a new horizontal `RangeSlider` with a defined minimum and
+    /// maximum value, selecting from `low` to `high`, where `width` is the
+    /// length of its track.
+    pub fn new(min: f32, max: f32, low: f32, high: f32, position: Point, width: i32) -> Self {
+        let track = SliderTrack::new(min, max, position, width, Self::TEXT_SPACE);
+        let mut r = Self {
+            adjustment: 0.0,
+            changed: false,
+            custom_style: false,
+            dimensions: (width + Self::TEXT_SPACE.0, Self::TEXT_SPACE.1),
+            dragging: None,
+            enabled: true,
+            focused: false,
+            focused_thumb: RangeThumb::Low,
+            formatter: None,
+            high: max,
+            high_box_position: track.position,
+            hovered: None,
+            id: WidgetId::unique(),
+            low: min,
+            low_box_position: track.position,
+            on_change: None,
+            position,
+            preferred_dimensions: (width + Self::TEXT_SPACE.0, Self::TEXT_SPACE.1),
+            style: Theme::default().slider,
+            text_measurer: TextMeasurer::default(),
+            track,
+            visible: true,
+        };
+
+        r.set_values(low, high);
+
+        r
+    }
+
+    fn set_position_from_value(&mut self) {
+        self.low_box_position = self.track.box_position(self.low);
+        self.high_box_position = self.track.box_position(self.high);
+    }
+
+    /// Sets the value of `thumb` from where its box is along the track,
+    /// stopping it at the other box and snapping it to the nearest step.
+    fn update_value(&mut self, thumb: RangeThumb) {
+        match thumb {
+            RangeThumb::Low => self.low = self.track.value_at(self.low_box_position).min(self.high),
+            RangeThumb::High =>
+                self.high = self.track.value_at(self.high_box_position).max(self.low),
+        }
+
+        self.set_position_from_value();
+    }
+
+    /// Shifts the `RangeSlider` and all it's elements proportionally to the
+    /// `new_x`.
+    pub fn move_x(&mut self, new_x: i32) { self.move_to((new_x, self.position.1)); }
+
+    /// Shifts the `RangeSlider` and all it's elements so that it is placed at
+    /// `new_position`.
+    pub fn move_to(&mut self, new_position: Point) {
+        self.position = new_position;
+        self.track.layout(self.position, self.dimensions);
+        self.set_position_from_value();
+    }
+
+    /// Returns the `WidgetId` of the `RangeSlider`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `RangeSlider` is drawn and responds to
+    /// input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `RangeSlider`.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the `RangeSlider` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `RangeSlider`. A disabled `RangeSlider` is drawn
+    /// in the disabled colours of its style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Sets the function used to measure the width of the readout, so that it
+    /// can be centred below the track of a vertical `RangeSlider`.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+
+        self
+    }
+
+    /// Returns the low and high values of the `RangeSlider`.
+    pub fn get_values(&self) -> (f32, f32) { (self.low, self.high) }
+
+    /// Returns the low value of the `RangeSlider`.
+    pub fn get_low(&self) -> f32 { self.low }
+
+    /// Returns the high value of the `RangeSlider`.
+    pub fn get_high(&self) -> f32 { self.high }
+
+    /// Sets the low and high values of the `RangeSlider`, swapping them if
+    /// `low` is above `high`, keeping them between the minimum and maximum
+    /// and rounding them to the nearest step.
+    pub fn set_values(&mut self, low: f32, high: f32) -> &mut Self {
+        self.low = self.track.snap(low.min(high));
+        self.high = self.track.snap(high.max(low));
+        self.set_position_from_value();
+
+        self
+    }
+
+    /// Moves the value of `thumb` to `value` without the mouse, stopping it at
+    /// the other box, and returns whether or not it changed.
+    fn move_value(&mut self, thumb: RangeThumb, value: f32) -> bool {
+        let previous_values = (self.low, self.high);
+
+        match thumb {
+            RangeThumb::Low => self.low = value.min(self.high),
+            RangeThumb::High => self.high = value.max(self.low),
+        }
+        self.set_position_from_value();
+
+        let changed = (self.low, self.high) != previous_values;
+        self.changed |= changed;
+
+        changed
+    }
+
+    /// Returns the value of the box of `thumb`.
+    fn thumb_value(&self, thumb: RangeThumb) -> f32 {
+        match thumb {
+            RangeThumb::Low => self.low,
+            RangeThumb::High => self.high,
+        }
+    }
+
+    /// Moves the focused box `steps` steps, or `steps` twentieths of the
+    /// track if the `RangeSlider` has no step, such as when the arrow keys are
+    /// pressed while it is focused. Returns whether or not its value changed.
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        let thumb = self.focused_thumb;
+        self.adjustment = 0.0;
+
+        self.move_value(thumb, self.track.step_from(self.thumb_value(thumb), steps))
+    }
+
+    /// Moves the focused box `fraction` of the length of the track, such as
+    /// while an analog stick is pushed while it is focused. Movements that
+    /// are too small to reach the next step are added up until they do.
+    /// Returns whether or not its value changed.
+    pub fn adjust(&mut self, fraction: f32) -> bool {
+        let thumb = self.focused_thumb;
+        let value = self.thumb_value(thumb);
+        self.adjustment += fraction;
+
+        let past_end = self.track.is_past_end(value, self.adjustment);
+        let changed = self.move_value(thumb, self.track.offset_from(value, self.adjustment));
+
+        if changed || past_end {
+            self.adjustment = 0.0;
+        }
+
+        changed
+    }
+
+    /// Returns whether or not the `RangeSlider` has keyboard or gamepad
+    /// focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `RangeSlider` and its focused box.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Returns the box that is moved by the keyboard or gamepad while the
+    /// `RangeSlider` is focused.
+    pub fn get_focused_thumb(&self) -> RangeThumb { self.focused_thumb }
+
+    /// Sets the box that is moved by the keyboard or gamepad while the
+    /// `RangeSlider` is focused.
+    pub fn set_focused_thumb(&mut self, thumb: RangeThumb) -> &mut Self {
+        self.focused_thumb = thumb;
+        self.adjustment = 0.0;
+
+        self
+    }
+
+    /// Returns the minimum and maximum values of the `RangeSlider`.
+    pub fn get_range(&self) -> (f32, f32) { self.track.get_range() }
+
+    /// Sets the minimum and maximum values of the `RangeSlider`, keeping both
+    /// of its values between them.
+    pub fn set_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.track.set_range(min, max);
+        self.set_values(self.low, self.high);
+
+        self
+    }
+
+    /// Returns the step that the values of the `RangeSlider` are rounded to,
+    /// if it has one.
+    pub fn get_step(&self) -> Option<f32> { self.track.get_step() }
+
+    /// Rounds the values of the `RangeSlider` to the nearest multiple of
+    /// `step` above the minimum, or lets them take any value if `step` is
+    /// `None`. Steps that are not above zero are ignored.
+    pub fn set_step(&mut self, step: Option<f32>) -> &mut Self {
+        self.track.set_step(step);
+        self.set_values(self.low, self.high);
+
+        self
+    }
+
+    /// Returns `true` if the values of the `RangeSlider` change
+    /// logarithmically along its track.
+    pub fn is_logarithmic(&self) -> bool { self.track.is_logarithmic() }
+
+    /// Makes the values change logarithmically along the track, in the same
+    /// way as `Slider::set_logarithmic`.
+    pub fn set_logarithmic(&mut self, logarithmic: bool) -> &mut Self {
+        self.track.set_logarithmic(logarithmic);
+        self.set_position_from_value();
+
+        self
+    }
+
+    /// Returns the direction that the track of the `RangeSlider` runs in.
+    pub fn get_orientation(&self) -> Orientation { self.track.get_orientation() }
+
+    /// Runs the track of the `RangeSlider` across if `orientation` is
+    /// horizontal, or up and down with the maximum at the top if it is
+    /// vertical, keeping the length of the track.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        if self.track.get_orientation() != orientation {
+            self.preferred_dimensions = self.track.set_orientation(orientation);
+            self.resize(self.preferred_dimensions);
+        }
+
+        self
+    }
+
+    /// Sets the function that turns each value into text for the readout,
+    /// instead of showing them with two decimal places.
+    pub fn set_formatter(&mut self, formatter: ValueFormatter) -> &mut Self {
+        self.formatter = Some(formatter);
+
+        self
+    }
+
+    /// Returns the text shown next to the track for the current values.
+    pub fn get_value_text(&self) -> String {
+        match &self.formatter {
+            Some(formatter) => format!("{} - {}", formatter(self.low), formatter(self.high)),
+            None => format!("{:#.2} - {:#.2}", self.low, self.high),
+        }
+    }
+
+    /// Returns the position of the box of `thumb`.
+    fn box_position(&self, thumb: RangeThumb) -> Point {
+        match thumb {
+            RangeThumb::Low => self.low_box_position,
+            RangeThumb::High => self.high_box_position,
+        }
+    }
+
+    /// Returns the box under `mouse_position`, choosing the one that is
+    /// closest to the value there when they overlap.
+    fn thumb_at(&self, mouse_position: Point) -> Option<RangeThumb> {
+        let inside = |thumb| {
+            is_inside(
+                self.box_position(thumb),
+                self.track.box_dimensions,
+                mouse_position,
+            )
+        };
+
+        match (inside(RangeThumb::Low), inside(RangeThumb::High)) {
+            (true, true) => Some(self.thumb_nearest(mouse_position)),
+            (true, false) => Some(RangeThumb::Low),
+            (false, true) => Some(RangeThumb::High),
+            (false, false) => None,
+        }
+    }
+
+    /// Returns the box that is moved by pressing at `mouse_position`, which is
+    /// the box that is closest to the value there.
+    fn thumb_nearest(&self, mouse_position: Point) -> RangeThumb {
+        let value = self
+            .track
+            .value_at(self.track.box_position_at(mouse_position));
+        let (min, max) = self.track.get_range();
+
+        if value > self.high {
+            RangeThumb::High
+        } else if value < self.low {
+            RangeThumb::Low
+        } else if (self.high - self.low).abs() <= f32::EPSILON {
+            // Boxes on top of each other are pulled apart by the one that
+            // still has room to move.
+            match self.high >= max.max(min) {
+                true => RangeThumb::Low,
+                false => RangeThumb::High,
+            }
+        } else if self.high - value < value - self.low {
+            RangeThumb::High
+        } else {
+            RangeThumb::Low
+        }
+    }
+
+    /// Moves the box that is pressed, or the closest box to where the
+    /// `RangeSlider` is pressed, and drags it until the button is released.
+    pub fn handle_input(&mut self, input: &InputState) {
+        let previous_values = (self.low, self.high);
+        let mouse_position = input.mouse_position;
+
+        if !input.is_button_down(PointerButton::Left) {
+            self.dragging = None;
+        }
+
+        if input.is_button_pressed(PointerButton::Left)
+            && is_inside(self.position, self.dimensions, mouse_position)
+        {
+            let thumb = self
+                .thumb_at(mouse_position)
+                .unwrap_or_else(|| self.thumb_nearest(mouse_position));
+            self.dragging = Some(thumb);
+            self.focused_thumb = thumb;
+        }
+
+        if let Some(thumb) = self.dragging {
+            match thumb {
+                RangeThumb::Low =>
+                    self.low_box_position = self.track.box_position_at(mouse_position),
+                RangeThumb::High =>
+                    self.high_box_position = self.track.box_position_at(mouse_position),
+            }
+
+            self.update_value(thumb);
+        }
+
+        self.changed = (self.low, self.high) != previous_values;
+    }
+
+    /// Returns `true` if either value changed during the last update.
+    pub fn was_changed(&self) -> bool { self.changed }
+
+    /// Gives the `RangeSlider` its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`, resizing it to fit the readout.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
+
+        self
+    }
+
+    /// Styles the `RangeSlider` using the `Slider` style of `theme`, unless it
+    /// has been given its own style with `set_style`, resizing it to fit the
+    /// readout.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.slider.clone();
+            self.fit_text();
+        }
+    }
+
+    fn fit_text(&mut self) {
+        self.preferred_dimensions = self
+            .track
+            .fit_text_space(Self::TEXT_SPACE, self.style.font_size);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns the state the box of `thumb` is currently drawn in.
+    pub fn get_thumb_state(&self, thumb: RangeThumb) -> WidgetState {
+        if !self.enabled {
+            WidgetState::Disabled
+        } else if self.dragging == Some(thumb) {
+            WidgetState::Active
+        } else if self.hovered == Some(thumb) {
+            WidgetState::Hovered
+        } else {
+            WidgetState::Default
+        }
+    }
+
+    /// Returns the dimensions the `RangeSlider` was created with, which
+    /// layouts use as the size the `RangeSlider` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the `RangeSlider` to `new_dimensions` and resizes it's
+    /// components proportionally.
+    pub fn resize(&mut self, new_dimensions: Dimensions) {
+        self.dimensions = new_dimensions;
+        self.track.layout(self.position, self.dimensions);
+        self.set_position_from_value();
+    }
+
+    /// Sets the callback that is called with the new low and high values of
+    /// the `RangeSlider` whenever either of them changes.
+    pub fn set_on_change(&mut self, on_change: ValueCallback<T, (f32, f32)>) -> &mut Self {
+        self.on_change = Some(on_change);
+
+        self
+    }
+
+    /// Calls the `RangeSlider`'s callback if its values changed during the
+    /// last update.
+    pub fn execute_callbacks(&mut self, state: &mut T) {
+        if std::mem::take(&mut self.changed) {
+            if let Some(on_change) = self.on_change.as_mut() {
+                on_change(state, (self.low, self.high));
+            }
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for RangeSlider<T> {
+    /// Draw `RangeSlider` to screen.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let text = self.get_value_text();
+
+        draw_background(
+            renderer,
+            self.position,
+            self.dimensions,
+            &self.style,
+            resting_state(self.enabled),
+        );
+
+        renderer.draw_text(
+            &text,
+            self.track.text_position(
+                self.position,
+                self.dimensions,
+                &text,
+                &self.style,
+                &self.text_measurer,
+            ),
+            self.style.font_size,
+            self.style.text.get(resting_state(self.enabled)),
+        );
+
+        self.track
+            .draw(renderer, &self.style, resting_state(self.enabled));
+
+        for &thumb in [RangeThumb::Low, RangeThumb::High].iter() {
+            renderer.draw_rectangle(
+                self.box_position(thumb),
+                self.track.box_dimensions,
+                self.style.foreground.get(self.get_thumb_state(thumb)),
+            );
+        }
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+            draw_focus_outline(
+                renderer,
+                self.box_position(self.focused_thumb),
+                self.track.box_dimensions,
+                &self.style,
+            );
+        }
+    }
+
+    /// Checks which box of the `RangeSlider` is being hovered over, returning
+    /// `true` if either of them are.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = self.thumb_at(mouse_position);
+
+        self.hovered.is_some()
+    }
+
+    /// Checks if the `RangeSlider` is clicked, moving the closest box to the
+    /// cursor and returning whether or not its values changed.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
+        if is_clicked {
+            input = input.with_button_pressed(PointerButton::Left);
+        }
+
+        self.handle_input(&input);
+
+        self.changed
+    }
+}
+
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<RangeSlider<T>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
+        let mut c = Vec::new();
+
+        for i in iter {
+            if let DrawableType::RangeSlider(r) = i {
+                c.push(r)
+            }
+        }
+
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `RangeSlider` from 0 to 100 with a track 100 pixels long starting at
+    /// x = 10, along which its 30 pixel wide boxes travel 70 pixels, so that
+    /// the mouse at x = 25 + 0.7 * value is over the middle of a box.
+    fn range_slider(low: f32, high: f32) -> RangeSlider<()> {
+        RangeSlider::new(0.0, 100.0, low, high, (0, 0), 100)
+    }
+
+    fn press(x: i32) -> InputState {
+        InputState::new()
+            .with_mouse_position((x, 20))
+            .with_button_pressed(PointerButton::Left)
+    }
+
+    fn hold(x: i32) -> InputState {
+        InputState::new()
+            .with_mouse_position((x, 20))
+            .with_button_down(PointerButton::Left)
+    }
+
+    /// Returns the box that pressing at `x` picks up.
+    fn pressed_thumb(mut range_slider: RangeSlider<()>, x: i32) -> RangeThumb {
+        range_slider.handle_input(&press(x));
+
+        range_slider.get_focused_thumb()
+    }
+
+    #[test]
+    fn the_low_box_stops_at_the_high_box() {
+        let mut range_slider = range_slider(20.0, 60.0);
+
+        range_slider.handle_input(&press(29));
+        assert_eq!(range_slider.get_focused_thumb(), RangeThumb::Low);
+
+        range_slider.handle_input(&hold(500));
+        assert_eq!(range_slider.get_values(), (60.0, 60.0));
+        assert!(range_slider.was_changed());
+    }
+
+    #[test]
+    fn the_high_box_stops_at_the_low_box() {
+        let mut range_slider = range_slider(20.0, 60.0);
+
+        range_slider.handle_input(&press(57));
+        assert_eq!(range_slider.get_focused_thumb(), RangeThumb::High);
+
+        range_slider.handle_input(&hold(-500));
+        assert_eq!(range_slider.get_values(), (20.0, 20.0));
+    }
+
+    #[test]
+    fn values_that_cross_are_swapped() {
+        let mut range_slider = range_slider(20.0, 60.0);
+
+        range_slider.set_values(80.0, 40.0);
+        assert_eq!(range_slider.get_values(), (40.0, 80.0));
+
+        range_slider.set_focused_thumb(RangeThumb::High);
+        for _ in 0..20 {
+            range_slider.step_by(-1);
+        }
+        assert_eq!(range_slider.get_values(), (40.0, 40.0));
+    }
+
+    #[test]
+    fn pressing_the_track_picks_up_the_nearest_box() {
+        assert_eq!(pressed_thumb(range_slider(20.0, 60.0), 20), RangeThumb::Low);
+        assert_eq!(
+            pressed_thumb(range_slider(20.0, 60.0), 85),
+            RangeThumb::High
+        );
+        // Between the boxes, closer to 10 than to 90 and then the other way.
+        assert_eq!(pressed_thumb(range_slider(10.0, 90.0), 55), RangeThumb::Low);
+        assert_eq!(
+            pressed_thumb(range_slider(10.0, 90.0), 65),
+            RangeThumb::High
+        );
+    }
+
+    #[test]
+    fn overlapping_boxes_are_pulled_apart_towards_the_mouse() {
+        // Just to the left of the value of both boxes.
+        assert_eq!(pressed_thumb(range_slider(50.0, 50.0), 55), RangeThumb::Low);
+        // Just to the right of it.
+        assert_eq!(
+            pressed_thumb(range_slider(50.0, 50.0), 65),
+            RangeThumb::High
+        );
+    }
+
+    #[test]
+    fn overlapping_boxes_at_either_end_are_pulled_apart_by_the_one_with_room() {
+        assert_eq!(
+            pressed_thumb(range_slider(50.0, 50.0), 60),
+            RangeThumb::High
+        );
+        assert_eq!(pressed_thumb(range_slider(0.0, 0.0), 25), RangeThumb::High);
+        assert_eq!(
+            pressed_thumb(range_slider(100.0, 100.0), 95),
+            RangeThumb::Low
+        );
+
+        let mut range_slider = range_slider(100.0, 100.0);
+        range_slider.handle_input(&press(95));
+        range_slider.handle_input(&hold(60));
+        assert_eq!(range_slider.get_values(), (50.0, 100.0));
+    }
+}
@@ -0,0 +1,649 @@
+use crate::prelude::{Colour, Dimensions, Point, Renderer, TextMeasurer};
+use std::{fmt, str::FromStr};
+
+/// A single draw call captured by a `RecordingRenderer`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    Rectangle {
+        position: Point,
+        dimensions: Dimensions,
+        colour: Colour,
+    },
+    RoundedRectangle {
+        position: Point,
+        dimensions: Dimensions,
+        corner_radius: i32,
+        colour: Colour,
+    },
+    RectangleLines {
+        position: Point,
+        dimensions: Dimensions,
+        thickness: i32,
+        colour: Colour,
+    },
+    Line {
+        start: Point,
+        end: Point,
+        thickness: f32,
+        colour: Colour,
+    },
+    Text {
+        text: String,
+        position: Point,
+        font_size: i32,
+        colour: Colour,
+    },
+    PushClip {
+        position: Point,
+        dimensions: Dimensions,
+    },
+    PopClip,
+}
+
+fn format_colour(colour: Colour) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        colour.r, colour.g, colour.b, colour.a
+    )
+}
+
+fn parse_colour(value: &str) -> Result<Colour, String> {
+    let hex = value
+        .strip_prefix('#')
+        .filter(|hex| hex.len() == 8)
+        .ok_or_else(|| format!("Expected a colour in the form #rrggbbaa, found \"{}\"", value))?;
+
+    let channel = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16)
+            .map_err(|_| format!("Invalid colour \"{}\"", value))
+    };
+
+    Ok(Colour::new(channel(0)?, channel(2)?, channel(4)?, channel(6)?))
+}
+
+fn parse_number<N: FromStr>(value: Option<&str>, name: &str) -> Result<N, String> {
+    let value = value.ok_or_else(|| format!("Missing value for `{}`", name))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value \"{}\" for `{}`", value, name))
+}
+
+fn unescape_text(quoted: &str) -> Result<String, String> {
+    let inner = quoted
+        .strip_prefix('"')
+        .and_then(|q| q.strip_suffix('"'))
+        .ok_or_else(|| format!("Expected quoted text, found {}", quoted))?;
+
+    let mut text = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('r') => text.push('\r'),
+            Some('"') => text.push('"'),
+            Some('\\') => text.push('\\'),
+            Some('\'') => text.push('\''),
+            Some('0') => text.push('\0'),
+            Some('u') => text.push(unescape_unicode(&mut chars)?),
+            other => return Err(format!("Unsupported escape sequence \\{:?}", other)),
+        }
+    }
+
+    Ok(text)
+}
+
+/// Reads the `{..}` part of a `\u{..}` escape sequence, as written by the
+/// `Debug` formatting of strings for characters that are not printable.
+fn unescape_unicode(chars: &mut std::str::Chars) -> Result<char, String> {
+    if chars.next() != Some('{') {
+        return Err("Expected `{` after \\u".to_string());
+    }
+
+    let mut hex = String::new();
+    loop {
+        match chars.next() {
+            Some('}') => break,
+            Some(c) => hex.push(c),
+            None => return Err(format!("Unterminated unicode escape sequence \\u{{{}", hex)),
+        }
+    }
+
+    u32::from_str_radix(&hex, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+        .ok_or_else(|| format!("Invalid unicode escape sequence \\u{{{}}}", hex))
+}
+
+impl fmt::Display for DrawCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawCommand::Rectangle {
+                position,
+                dimensions,
+                colour,
+            } => write!(
+                f,
+                "rect {} {} {} {} {}",
+                position.0,
+                position.1,
+                dimensions.0,
+                dimensions.1,
+                format_colour(*colour)
+            ),
+            DrawCommand::RoundedRectangle {
+                position,
+                dimensions,
+                corner_radius,
+                colour,
+            } => write!(
+                f,
+                "rounded_rect {} {} {} {} {} {}",
+                position.0,
+                position.1,
+                dimensions.0,
+                dimensions.1,
+                corner_radius,
+                format_colour(*colour)
+            ),
+            DrawCommand::RectangleLines {
+                position,
+                dimensions,
+                thickness,
+                colour,
+            } => write!(
+                f,
+                "rect_lines {} {} {} {} {} {}",
+                position.0,
+                position.1,
+                dimensions.0,
+                dimensions.1,
+                thickness,
+                format_colour(*colour)
+            ),
+            DrawCommand::Line {
+                start,
+                end,
+                thickness,
+                colour,
+            } => write!(
+                f,
+                "line {} {} {} {} {} {}",
+                start.0,
+                start.1,
+                end.0,
+                end.1,
+                thickness,
+                format_colour(*colour)
+            ),
+            DrawCommand::Text {
+                text,
+                position,
+                font_size,
+                colour,
+            } => write!(
+                f,
+                "text {} {} {} {} {:?}",
+                position.0,
+                position.1,
+                font_size,
+                format_colour(*colour),
+                text
+            ),
+            DrawCommand::PushClip {
+                position,
+                dimensions,
+            } => write!(
+                f,
+                "clip {} {} {} {}",
+                position.0, position.1, dimensions.0, dimensions.1
+            ),
+            DrawCommand::PopClip => write!(f, "unclip"),
+        }
+    }
+}
+
+impl FromStr for DrawCommand {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (kind, rest) = match line.find(' ') {
+            Some(index) => (&line[..index], line[index + 1..].trim_start()),
+            None => (line, ""),
+        };
+
+        // Text commands keep everything after the colour as the quoted text so
+        // that text containing spaces survives the round trip.
+        if kind == "text" {
+            let mut values = rest.splitn(5, ' ');
+            let x = parse_number(values.next(), "x")?;
+            let y = parse_number(values.next(), "y")?;
+            let font_size = parse_number(values.next(), "font_size")?;
+            let colour = parse_colour(values.next().unwrap_or(""))?;
+            let text = unescape_text(values.next().unwrap_or(""))?;
+
+            return Ok(DrawCommand::Text {
+                text,
+                position: (x, y),
+                font_size,
+                colour,
+            });
+        }
+
+        let mut values = rest.split_whitespace();
+        let command = match kind {
+            "rect" => DrawCommand::Rectangle {
+                position: (
+                    parse_number(values.next(), "x")?,
+                    parse_number(values.next(), "y")?,
+                ),
+                dimensions: (
+                    parse_number(values.next(), "width")?,
+                    parse_number(values.next(), "height")?,
+                ),
+                colour: parse_colour(values.next().unwrap_or(""))?,
+            },
+            "rounded_rect" => DrawCommand::RoundedRectangle {
+                position: (
+                    parse_number(values.next(), "x")?,
+                    parse_number(values.next(), "y")?,
+                ),
+                dimensions: (
+                    parse_number(values.next(), "width")?,
+                    parse_number(values.next(), "height")?,
+                ),
+                corner_radius: parse_number(values.next(), "corner_radius")?,
+                colour: parse_colour(values.next().unwrap_or(""))?,
+            },
+            "rect_lines" => DrawCommand::RectangleLines {
+                position: (
+                    parse_number(values.next(), "x")?,
+                    parse_number(values.next(), "y")?,
+                ),
+                dimensions: (
+                    parse_number(values.next(), "width")?,
+                    parse_number(values.next(), "height")?,
+                ),
+                thickness: parse_number(values.next(), "thickness")?,
+                colour: parse_colour(values.next().unwrap_or(""))?,
+            },
+            "line" => DrawCommand::Line {
+                start: (
+                    parse_number(values.next(), "start_x")?,
+                    parse_number(values.next(), "start_y")?,
+                ),
+                end: (
+                    parse_number(values.next(), "end_x")?,
+                    parse_number(values.next(), "end_y")?,
+                ),
+                thickness: parse_number(values.next(), "thickness")?,
+                colour: parse_colour(values.next().unwrap_or(""))?,
+            },
+            "clip" => DrawCommand::PushClip {
+                position: (
+                    parse_number(values.next(), "x")?,
+                    parse_number(values.next(), "y")?,
+                ),
+                dimensions: (
+                    parse_number(values.next(), "width")?,
+                    parse_number(values.next(), "height")?,
+                ),
+            },
+            "unclip" => DrawCommand::PopClip,
+            _ => return Err(format!("Unknown draw command \"{}\"", kind)),
+        };
+
+        match values.next() {
+            Some(extra) => Err(format!("Unexpected trailing value \"{}\"", extra)),
+            None => Ok(command),
+        }
+    }
+}
+
+/// Every draw command emitted during a single frame, in the order they were
+/// issued. A `DisplayList` can be written out with `to_string` and read back
+/// with `parse`, which makes it suitable for golden-file tests.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DisplayList {
+    pub commands: Vec<DrawCommand>,
+}
+
+impl DisplayList {
+    /// Creates a new, empty `DisplayList`.
+    pub fn new() -> Self { Self::default() }
+
+    /// Compares this `DisplayList` against an `expected` one, returning `None`
+    /// if they are identical or a human-readable report of the commands that
+    /// were removed (`-`) and added (`+`) otherwise.
+    pub fn diff(&self, expected: &DisplayList) -> Option<String> {
+        if self == expected {
+            return None;
+        }
+
+        let expected_lines: Vec<String> = expected.commands.iter().map(|c| c.to_string()).collect();
+        let actual_lines: Vec<String> = self.commands.iter().map(|c| c.to_string()).collect();
+
+        // Longest common subsequence table, used to line up unchanged commands.
+        let (n, m) = (expected_lines.len(), actual_lines.len());
+        let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lengths[i][j] = if expected_lines[i] == actual_lines[j] {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+
+        let mut report = format!(
+            "Display lists differ (expected {} commands, found {}):\n",
+            n, m
+        );
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && expected_lines[i] == actual_lines[j] {
+                report.push_str(&format!("  {:>4}   {}\n", i, expected_lines[i]));
+                i += 1;
+                j += 1;
+            } else if j < m && (i == n || lengths[i][j + 1] >= lengths[i + 1][j]) {
+                report.push_str(&format!("+ {:>4}   {}\n", j, actual_lines[j]));
+                j += 1;
+            } else {
+                report.push_str(&format!("- {:>4}   {}\n", i, expected_lines[i]));
+                i += 1;
+            }
+        }
+
+        Some(report)
+    }
+}
+
+impl fmt::Display for DisplayList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for command in self.commands.iter() {
+            writeln!(f, "{}", command)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DisplayList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut commands = Vec::new();
+
+        for (number, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with("//") {
+                continue;
+            }
+
+            commands.push(
+                line.parse()
+                    .map_err(|e| format!("Line {}: {}", number + 1, e))?,
+            );
+        }
+
+        Ok(Self { commands })
+    }
+}
+
+/// Measures text as if every character were half of `font_size` wide. This
+/// is what the `RecordingRenderer` uses, and can be given to
+/// `GuiHandler::set_text_measurer` so that components are sized the same way
+/// before anything has been recorded.
+pub fn measure_text_monospace(text: &str, font_size: i32) -> i32 {
+    text.chars().count() as i32 * (font_size / 2)
+}
+
+/// A headless `Renderer` that records every draw command instead of drawing
+/// it, so that the output of a `GuiHandler` can be inspected without a GPU.
+#[derive(Default)]
+pub struct RecordingRenderer {
+    display_list: DisplayList,
+}
+
+impl RecordingRenderer {
+    /// Creates a new `RecordingRenderer` with an empty `DisplayList`.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns the commands recorded so far in the current frame.
+    pub fn display_list(&self) -> &DisplayList { &self.display_list }
+
+    /// Ends the current frame, returning its `DisplayList` and starting a new,
+    /// empty one.
+    pub fn take_display_list(&mut self) -> DisplayList { std::mem::take(&mut self.display_list) }
+}
+
+impl Renderer for RecordingRenderer {
+    fn draw_rectangle(&mut self, position: Point, dimensions: Dimensions, colour: Colour) {
+        self.display_list.commands.push(DrawCommand::Rectangle {
+            position,
+            dimensions,
+            colour,
+        });
+    }
+
+    fn draw_rounded_rectangle(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        corner_radius: i32,
+        colour: Colour,
+    ) {
+        self.display_list.commands.push(DrawCommand::RoundedRectangle {
+            position,
+            dimensions,
+            corner_radius,
+            colour,
+        });
+    }
+
+    fn draw_rectangle_lines(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        thickness: i32,
+        colour: Colour,
+    ) {
+        self.display_list.commands.push(DrawCommand::RectangleLines {
+            position,
+            dimensions,
+            thickness,
+            colour,
+        });
+    }
+
+    fn draw_line(&mut self, start: Point, end: Point, thickness: f32, colour: Colour) {
+        self.display_list.commands.push(DrawCommand::Line {
+            start,
+            end,
+            thickness,
+            colour,
+        });
+    }
+
+    fn draw_text(&mut self, text: &str, position: Point, font_size: i32, colour: Colour) {
+        self.display_list.commands.push(DrawCommand::Text {
+            text: text.to_string(),
+            position,
+            font_size,
+            colour,
+        });
+    }
+
+    fn measure_text(&self, text: &str, font_size: i32) -> i32 {
+        measure_text_monospace(text, font_size)
+    }
+
+    fn text_measurer(&self) -> Option<TextMeasurer> {
+        Some(TextMeasurer::new(measure_text_monospace))
+    }
+
+    fn push_clip(&mut self, position: Point, dimensions: Dimensions) {
+        self.display_list.commands.push(DrawCommand::PushClip {
+            position,
+            dimensions,
+        });
+    }
+
+    fn pop_clip(&mut self) { self.display_list.commands.push(DrawCommand::PopClip); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{GuiHandler, InputState};
+
+    fn text(text: &str) -> DrawCommand {
+        DrawCommand::Text {
+            text: text.to_string(),
+            position: (10, 20),
+            font_size: 20,
+            colour: Colour::new(1, 2, 3, 255),
+        }
+    }
+
+    #[test]
+    fn text_escapes_round_trip() {
+        for original in [
+            "plain text",
+            "quotes \" and ' and \\ backslashes",
+            "new\nlines\tand\rreturns",
+            "a null \0 byte",
+            "control \u{7} \u{1b}[0m characters",
+            "combining \u{301} marks and zero\u{200b}width spaces",
+            "unicode: caf\u{e9} \u{1f600}",
+        ]
+        .iter()
+        {
+            let command = text(original);
+            let line = command.to_string();
+
+            assert_eq!(line.parse::<DrawCommand>(), Ok(command), "{}", line);
+        }
+    }
+
+    #[test]
+    fn display_list_round_trips() {
+        let list = DisplayList {
+            commands: vec![
+                DrawCommand::PushClip {
+                    position: (0, 0),
+                    dimensions: (100, 50),
+                },
+                DrawCommand::Rectangle {
+                    position: (0, 0),
+                    dimensions: (100, 50),
+                    colour: Colour::new(10, 20, 30, 40),
+                },
+                DrawCommand::RoundedRectangle {
+                    position: (1, 2),
+                    dimensions: (3, 4),
+                    corner_radius: 5,
+                    colour: Colour::new(255, 255, 255, 255),
+                },
+                DrawCommand::RectangleLines {
+                    position: (-1, -2),
+                    dimensions: (3, 4),
+                    thickness: 2,
+                    colour: Colour::new(0, 0, 0, 0),
+                },
+                DrawCommand::Line {
+                    start: (0, 0),
+                    end: (10, 10),
+                    thickness: 1.5,
+                    colour: Colour::new(0, 0, 0, 255),
+                },
+                text("tab\there \u{0}"),
+                DrawCommand::PopClip,
+            ],
+        };
+
+        assert_eq!(list.to_string().parse::<DisplayList>(), Ok(list));
+    }
+
+    #[test]
+    fn text_matches_golden_line() {
+        let golden = r#"text 10 20 20 #010203ff "café\n\0\u{7}""#;
+        let command = text("café\n\0\u{7}");
+
+        assert_eq!(command.to_string(), golden);
+        assert_eq!(golden.parse::<DrawCommand>(), Ok(command));
+    }
+
+    #[test]
+    fn rejects_bad_escapes() {
+        for line in [
+            "text 0 0 20 #000000ff \"\\q\"",
+            "text 0 0 20 #000000ff \"\\u{110000}\"",
+            "text 0 0 20 #000000ff \"\\u{41\"",
+            "text 0 0 20 #000000ff \"\\u41\"",
+        ]
+        .iter()
+        {
+            assert!(line.parse::<DrawCommand>().is_err(), "{}", line);
+        }
+    }
+
+    /// Renders a menu with a `Button`, a `Slider` and an open `Dropdown` with
+    /// a `Button` inside of it.
+    fn menu() -> DisplayList {
+        let mut g_handler = GuiHandler::<(), &str>::new(Colour::WHITE);
+        g_handler.set_text_measurer(TextMeasurer::new(measure_text_monospace));
+        g_handler.add_button("Play", "play");
+        g_handler.add_slider(0.0, 100.0, 25.0);
+        let options = g_handler.add_dropdown("Options");
+        let dropdown = g_handler.get_dropdown_mut(options).unwrap();
+        dropdown.add_button("Quit", "quit");
+        dropdown.set_open(true);
+
+        let mut renderer = RecordingRenderer::new();
+        g_handler
+            .update(&InputState::new().with_screen_size((800, 600)))
+            .render(&mut renderer);
+
+        renderer.display_list().clone()
+    }
+
+    #[test]
+    fn menu_matches_golden_display_list() {
+        let expected = include_str!("../tests/golden/menu.txt")
+            .parse::<DisplayList>()
+            .unwrap();
+
+        if let Some(report) = menu().diff(&expected) {
+            panic!("{}", report);
+        }
+    }
+
+    #[test]
+    fn diff_reports_removed_and_added_commands() {
+        let expected = "rect 0 0 10 10 #000000ff\ntext 0 0 20 #ffffffff \"Old\"\nunclip\n"
+            .parse::<DisplayList>()
+            .unwrap();
+        let actual = "rect 0 0 10 10 #000000ff\ntext 0 0 20 #ffffffff \"New\"\nunclip\n"
+            .parse::<DisplayList>()
+            .unwrap();
+
+        assert_eq!(expected.diff(&expected), None);
+        assert_eq!(
+            actual.diff(&expected).unwrap(),
+            "Display lists differ (expected 3 commands, found 3):\n\
+             \x20    0   rect 0 0 10 10 #000000ff\n\
+             +    1   text 0 0 20 #ffffffff \"New\"\n\
+             -    1   text 0 0 20 #ffffffff \"Old\"\n\
+             \x20    2   unclip\n"
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use crate::prelude::{Colour, Dimensions, Point, TextMeasurer};
+use raylib::prelude::{
+    text::{measure_text, measure_text_ex},
+    *,
+};
+use std::rc::Rc;
+
+/// The drawing backend that every component in `ptgui` draws itself through.
+/// Implementing this trait allows the GUI to be drawn somewhere other than a
+/// live raylib window.
+pub trait Renderer {
+    /// Draws a filled rectangle at `position` with the given `dimensions`.
+    fn draw_rectangle(&mut self, position: Point, dimensions: Dimensions, colour: Colour);
+
+    /// Draws a filled rectangle at `position` with the given `dimensions`,
+    /// with its corners rounded off by `corner_radius` pixels.
+    fn draw_rounded_rectangle(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        corner_radius: i32,
+        colour: Colour,
+    );
+
+    /// Draws the outline of a rectangle at `position` with the given
+    /// `dimensions`.
+    fn draw_rectangle_lines(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        thickness: i32,
+        colour: Colour,
+    );
+
+    /// Draws a line from `start` to `end`.
+    fn draw_line(&mut self, start: Point, end: Point, thickness: f32, colour: Colour);
+
+    /// Draws `text` with its top-left corner at `position`.
+    fn draw_text(&mut self, text: &str, position: Point, font_size: i32, colour: Colour);
+
+    /// Returns the width in pixels that `text` would take up when drawn at
+    /// `font_size`.
+    fn measure_text(&self, text: &str, font_size: i32) -> i32;
+
+    /// Returns a function that measures text the same way as `measure_text`,
+    /// if there is one. `GuiHandler::render` gives this to its components so
+    /// that they are sized to fit the text as this renderer will draw it.
+    fn text_measurer(&self) -> Option<TextMeasurer>;
+
+    /// Restricts all following draws to the given area until the matching
+    /// `pop_clip` is called. Nested clips are intersected with each other.
+    fn push_clip(&mut self, position: Point, dimensions: Dimensions);
+
+    /// Removes the most recently pushed clipping area.
+    fn pop_clip(&mut self);
+}
+
+/// Returns the area shared by both of the given rectangles, with an empty
+/// area if they do not overlap.
+pub fn intersect_clip(
+    first: (Point, Dimensions),
+    second: (Point, Dimensions),
+) -> (Point, Dimensions) {
+    let ((first_position, first_dimensions), (second_position, second_dimensions)) =
+        (first, second);
+
+    let start = (
+        first_position.0.max(second_position.0),
+        first_position.1.max(second_position.1),
+    );
+    let end = (
+        (first_position.0 + first_dimensions.0).min(second_position.0 + second_dimensions.0),
+        (first_position.1 + first_dimensions.1).min(second_position.1 + second_dimensions.1),
+    );
+
+    (start, ((end.0 - start.0).max(0), (end.1 - start.1).max(0)))
+}
+
+/// Measures text in raylib's default font, the same way as a
+/// `RaylibRenderer` without a font. Components measure their text with this
+/// until they are given another `TextMeasurer`.
+pub fn measure_text_default_font(text: &str, font_size: i32) -> i32 {
+    measure_text(text, font_size)
+}
+
+/// The spacing between characters that raylib uses for its default font.
+fn font_spacing(font_size: i32) -> f32 { (font_size / 10).max(1) as f32 }
+
+/// Measures text in `font`, the same way as a `RaylibRenderer` with that font.
+pub fn measure_text_in_font(font: &Font, text: &str, font_size: i32) -> i32 {
+    measure_text_ex(font, text, font_size as f32, font_spacing(font_size)).x as i32
+}
+
+/// The default `Renderer`, drawing to a raylib window through a
+/// `RaylibDrawHandle`.
+pub struct RaylibRenderer<'a, 'b> {
+    clip_stack: Vec<(Point, Dimensions)>,
+    draw_handler: &'a mut RaylibDrawHandle<'b>,
+    font: Option<Rc<Font>>,
+}
+
+impl<'a, 'b> RaylibRenderer<'a, 'b> {
+    /// Creates a new `RaylibRenderer` that draws using `draw_handler`.
+    pub fn new(draw_handler: &'a mut RaylibDrawHandle<'b>) -> Self {
+        Self {
+            clip_stack: Vec::new(),
+            draw_handler,
+            font: None,
+        }
+    }
+
+    /// Draws and measures text using `font`, or raylib's default font if it
+    /// is `None`.
+    pub fn with_font(mut self, font: Option<Rc<Font>>) -> Self {
+        self.font = font;
+
+        self
+    }
+
+    fn apply_clip(&mut self) {
+        // SAFETY: only called while the wrapped draw handle is drawing.
+        unsafe {
+            match self.clip_stack.last() {
+                Some((position, dimensions)) => ffi::BeginScissorMode(
+                    position.0,
+                    position.1,
+                    dimensions.0,
+                    dimensions.1,
+                ),
+                None => ffi::EndScissorMode(),
+            }
+        }
+    }
+}
+
+impl Renderer for RaylibRenderer<'_, '_> {
+    fn draw_rectangle(&mut self, position: Point, dimensions: Dimensions, colour: Colour) {
+        self.draw_handler.draw_rectangle(
+            position.0,
+            position.1,
+            dimensions.0,
+            dimensions.1,
+            colour,
+        );
+    }
+
+    fn draw_rounded_rectangle(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        corner_radius: i32,
+        colour: Colour,
+    ) {
+        // raylib describes the rounding relative to the shortest side.
+        let shortest_side = dimensions.0.min(dimensions.1).max(1) as f32;
+
+        self.draw_handler.draw_rectangle_rounded(
+            Rectangle::new(
+                position.0 as f32,
+                position.1 as f32,
+                dimensions.0 as f32,
+                dimensions.1 as f32,
+            ),
+            (corner_radius as f32 * 2.0 / shortest_side).min(1.0),
+            8,
+            colour,
+        );
+    }
+
+    fn draw_rectangle_lines(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        thickness: i32,
+        colour: Colour,
+    ) {
+        self.draw_handler.draw_rectangle_lines_ex(
+            Rectangle::new(
+                position.0 as f32,
+                position.1 as f32,
+                dimensions.0 as f32,
+                dimensions.1 as f32,
+            ),
+            thickness,
+            colour,
+        );
+    }
+
+    fn draw_line(&mut self, start: Point, end: Point, thickness: f32, colour: Colour) {
+        self.draw_handler.draw_line_ex(
+            Vector2::new(start.0 as f32, start.1 as f32),
+            Vector2::new(end.0 as f32, end.1 as f32),
+            thickness,
+            colour,
+        );
+    }
+
+    fn draw_text(&mut self, text: &str, position: Point, font_size: i32, colour: Colour) {
+        match self.font.as_deref() {
+            Some(font) => self.draw_handler.draw_text_ex(
+                font,
+                text,
+                Vector2::new(position.0 as f32, position.1 as f32),
+                font_size as f32,
+                font_spacing(font_size),
+                colour,
+            ),
+            None => self
+                .draw_handler
+                .draw_text(text, position.0, position.1, font_size, colour),
+        }
+    }
+
+    fn measure_text(&self, text: &str, font_size: i32) -> i32 {
+        match self.font.as_deref() {
+            Some(font) => measure_text_in_font(font, text, font_size),
+            None => measure_text_default_font(text, font_size),
+        }
+    }
+
+    fn text_measurer(&self) -> Option<TextMeasurer> {
+        match self.font.as_ref() {
+            Some(font) => Some(TextMeasurer::with_source(font.clone(), measure_text_in_font)),
+            None => Some(TextMeasurer::new(measure_text_default_font)),
+        }
+    }
+
+    fn push_clip(&mut self, position: Point, dimensions: Dimensions) {
+        let clip = match self.clip_stack.last() {
+            Some(current) => intersect_clip(*current, (position, dimensions)),
+            None => (position, dimensions),
+        };
+
+        self.clip_stack.push(clip);
+        self.apply_clip();
+    }
+
+    fn pop_clip(&mut self) {
+        self.clip_stack.pop();
+        self.apply_clip();
+    }
+}
+
+/// A `Renderer` that scales everything drawn through it by `scale` before
+/// passing it on to another `Renderer`, which is how the `GuiHandler` applies
+/// its UI scale.
+pub struct ScaledRenderer<'a> {
+    renderer: &'a mut dyn Renderer,
+    scale: f32,
+}
+
+impl<'a> ScaledRenderer<'a> {
+    /// Creates a new `ScaledRenderer` that draws to `renderer` at `scale`.
+    pub fn new(renderer: &'a mut dyn Renderer, scale: f32) -> Self { Self { renderer, scale } }
+
+    fn scale_length(&self, length: i32) -> i32 { (length as f32 * self.scale).round() as i32 }
+
+    fn scale_point(&self, point: Point) -> Point {
+        (self.scale_length(point.0), self.scale_length(point.1))
+    }
+
+    /// Scales the far corner rather than the dimensions themselves, so that
+    /// rectangles that touch before scaling still touch afterwards.
+    fn scale_area(&self, position: Point, dimensions: Dimensions) -> (Point, Dimensions) {
+        let start = self.scale_point(position);
+        let end = self.scale_point((position.0 + dimensions.0, position.1 + dimensions.1));
+
+        (start, (end.0 - start.0, end.1 - start.1))
+    }
+}
+
+impl Renderer for ScaledRenderer<'_> {
+    fn draw_rectangle(&mut self, position: Point, dimensions: Dimensions, colour: Colour) {
+        let (position, dimensions) = self.scale_area(position, dimensions);
+
+        self.renderer.draw_rectangle(position, dimensions, colour);
+    }
+
+    fn draw_rounded_rectangle(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        corner_radius: i32,
+        colour: Colour,
+    ) {
+        let (position, dimensions) = self.scale_area(position, dimensions);
+        let corner_radius = self.scale_length(corner_radius);
+
+        self.renderer
+            .draw_rounded_rectangle(position, dimensions, corner_radius, colour);
+    }
+
+    fn draw_rectangle_lines(
+        &mut self,
+        position: Point,
+        dimensions: Dimensions,
+        thickness: i32,
+        colour: Colour,
+    ) {
+        let (position, dimensions) = self.scale_area(position, dimensions);
+        let thickness = self.scale_length(thickness).max(1);
+
+        self.renderer
+            .draw_rectangle_lines(position, dimensions, thickness, colour);
+    }
+
+    fn draw_line(&mut self, start: Point, end: Point, thickness: f32, colour: Colour) {
+        let (start, end) = (self.scale_point(start), self.scale_point(end));
+
+        self.renderer
+            .draw_line(start, end, thickness * self.scale, colour);
+    }
+
+    fn draw_text(&mut self, text: &str, position: Point, font_size: i32, colour: Colour) {
+        let (position, font_size) = (self.scale_point(position), self.scale_length(font_size));
+
+        self.renderer.draw_text(text, position, font_size, colour);
+    }
+
+    fn measure_text(&self, text: &str, font_size: i32) -> i32 {
+        let width = self
+            .renderer
+            .measure_text(text, self.scale_length(font_size));
+
+        (width as f32 / self.scale).round() as i32
+    }
+
+    fn text_measurer(&self) -> Option<TextMeasurer> { self.renderer.text_measurer() }
+
+    fn push_clip(&mut self, position: Point, dimensions: Dimensions) {
+        let (position, dimensions) = self.scale_area(position, dimensions);
+
+        self.renderer.push_clip(position, dimensions);
+    }
+
+    fn pop_clip(&mut self) { self.renderer.pop_clip(); }
+}
@@ -0,0 +1,405 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, InputState, Point, PointerButton, Renderer, Theme, WidgetId, WidgetState,
+        WidgetStyle,
+    },
+};
+use std::iter::FromIterator;
+
+/// The direction a component runs in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+/// A bar with a thumb that can be dragged to scroll through content that is
+/// longer than the space it is shown in.
+pub struct ScrollBar {
+    changed: bool,
+    content_length: i32,
+    custom_style: bool,
+    drag_offset: Option<i32>,
+    enabled: bool,
+    hovered: bool,
+    id: WidgetId,
+    offset: i32,
+    orientation: Orientation,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
+    viewport_length: i32,
+    visible: bool,
+}
+
+impl ScrollBar {
+    /// The width of a vertical `ScrollBar`, or the height of a horizontal
+    /// one.
+    pub const THICKNESS: i32 = 16;
+    /// The shortest that the thumb is drawn, so that it can still be grabbed
+    /// when the content is very long.
+    pub const MIN_THUMB_LENGTH: i32 = 20;
+    /// How far the mouse wheel and the up and down directions scroll at a time.
+    pub const LINE_LENGTH: i32 = 40;
+
+    /// Create a new `ScrollBar` that is `length` pixels long, with content
+    /// that fits inside of it.
+    pub fn new(position: Point, length: i32, orientation: Orientation) -> Self {
+        let dimensions = match orientation {
+            Orientation::Horizontal => (length, Self::THICKNESS),
+            Orientation::Vertical => (Self::THICKNESS, length),
+        };
+
+        Self {
+            changed: false,
+            content_length: length,
+            custom_style: false,
+            dimensions,
+            drag_offset: None,
+            enabled: true,
+            hovered: false,
+            id: WidgetId::unique(),
+            offset: 0,
+            orientation,
+            position,
+            preferred_dimensions: dimensions,
+            style: Theme::default().scroll_bar,
+            viewport_length: length,
+            visible: true,
+        }
+    }
+
+    /// Returns the `WidgetId` of the `ScrollBar`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `ScrollBar` is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `ScrollBar`.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the `ScrollBar` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `ScrollBar`. A disabled `ScrollBar` is drawn in
+    /// the disabled colours of its style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Sets the length of the content being scrolled through and of the
+    /// space it is shown in, keeping the offset inside of the content.
+    pub fn set_content(&mut self, content_length: i32, viewport_length: i32) -> &mut Self {
+        self.content_length = content_length.max(0);
+        self.viewport_length = viewport_length.max(0);
+        self.set_offset(self.offset);
+
+        self
+    }
+
+    /// Returns `true` if the content is longer than the space it is shown in,
+    /// so that there is something to scroll.
+    pub fn is_scrollable(&self) -> bool { self.content_length > self.viewport_length }
+
+    /// Returns how far the content is scrolled, in pixels.
+    pub fn get_offset(&self) -> i32 { self.offset }
+
+    /// Returns the furthest that the content can be scrolled.
+    pub fn get_max_offset(&self) -> i32 { (self.content_length - self.viewport_length).max(0) }
+
+    /// Scrolls the content to `offset`, keeping it inside of the content.
+    pub fn set_offset(&mut self, offset: i32) -> &mut Self {
+        self.offset = offset.max(0).min(self.get_max_offset());
+
+        self
+    }
+
+    /// Scrolls the content by `distance` pixels, where a positive distance
+    /// scrolls down or right.
+    pub fn scroll_by(&mut self, distance: i32) -> &mut Self {
+        self.set_offset(self.offset + distance)
+    }
+
+    /// Returns the orientation of the `ScrollBar`.
+    pub fn get_orientation(&self) -> Orientation { self.orientation }
+
+    /// Returns `true` if the offset changed during the last update.
+    pub fn was_changed(&self) -> bool { self.changed }
+
+    /// Gives the `ScrollBar` its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+
+        self
+    }
+
+    /// Styles the `ScrollBar` using `theme`, unless it has been given its own
+    /// style with `set_style`.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.scroll_bar.clone();
+        }
+    }
+
+    /// Returns the state the thumb of the `ScrollBar` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match (self.drag_offset, self.hovered) {
+            (Some(_), _) => WidgetState::Active,
+            (None, true) => WidgetState::Hovered,
+            (None, false) => WidgetState::Default,
+        }
+    }
+
+    /// Returns the dimensions the `ScrollBar` was created with, which layouts
+    /// use as the size the `ScrollBar` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the `ScrollBar` to the given `new_dimensions`.
+    pub fn resize(&mut self, new_dimensions: Dimensions) { self.dimensions = new_dimensions; }
+
+    /// Returns the start of the track and its length along the orientation of
+    /// the `ScrollBar`.
+    fn track(&self) -> (i32, i32) {
+        match self.orientation {
+            Orientation::Horizontal => (self.position.0, self.dimensions.0),
+            Orientation::Vertical => (self.position.1, self.dimensions.1),
+        }
+    }
+
+    /// Returns the part of `point` that runs along the `ScrollBar`.
+    fn along(&self, point: Point) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => point.0,
+            Orientation::Vertical => point.1,
+        }
+    }
+
+    /// Returns the start and length of the thumb along the track.
+    fn thumb(&self) -> (i32, i32) {
+        let (track_start, track_length) = self.track();
+
+        if !self.is_scrollable() {
+            return (track_start, track_length);
+        }
+
+        let length = ((track_length as f32 * self.viewport_length as f32
+            / self.content_length as f32) as i32)
+            .max(Self::MIN_THUMB_LENGTH)
+            .min(track_length);
+        let start = track_start
+            + ((track_length - length) as f32 * self.offset as f32 / self.get_max_offset() as f32)
+                as i32;
+
+        (start, length)
+    }
+
+    /// Returns the position and dimensions of the thumb on the screen.
+    fn thumb_area(&self) -> (Point, Dimensions) {
+        let (start, length) = self.thumb();
+
+        match self.orientation {
+            Orientation::Horizontal => ((start, self.position.1), (length, self.dimensions.1)),
+            Orientation::Vertical => ((self.position.0, start), (self.dimensions.0, length)),
+        }
+    }
+
+    /// Drags the thumb, jumps a page at a time when the track is clicked and
+    /// scrolls with the mouse wheel while the mouse is over the `ScrollBar`.
+    pub fn handle_input(&mut self, input: &InputState) {
+        let previous_offset = self.offset;
+        let mouse_position = input.mouse_position;
+        let (thumb_position, thumb_dimensions) = self.thumb_area();
+        let inside = is_inside(self.position, self.dimensions, mouse_position);
+
+        if !input.is_button_down(PointerButton::Left) {
+            self.drag_offset = None;
+        }
+
+        if input.is_button_pressed(PointerButton::Left) && inside {
+            if is_inside(thumb_position, thumb_dimensions, mouse_position) {
+                self.drag_offset = Some(self.along(mouse_position) - self.thumb().0);
+            } else if self.along(mouse_position) < self.thumb().0 {
+                self.scroll_by(-self.viewport_length);
+            } else {
+                self.scroll_by(self.viewport_length);
+            }
+        } else if let Some(drag_offset) = self.drag_offset {
+            let (track_start, track_length) = self.track();
+            let travel = (track_length - self.thumb().1).max(1);
+            let thumb_start = self
+                .along(mouse_position)
+                .saturating_sub(drag_offset + track_start);
+
+            self.set_offset(
+                (thumb_start as f32 / travel as f32 * self.get_max_offset() as f32).round() as i32,
+            );
+        }
+
+        if inside && input.mouse_wheel != 0.0 {
+            self.scroll_by(-(input.mouse_wheel * Self::LINE_LENGTH as f32) as i32);
+        }
+
+        self.changed = self.offset != previous_offset;
+    }
+}
+
+impl GuiComponentBehaviour<bool> for ScrollBar {
+    /// Draw `ScrollBar` to screen.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let (thumb_position, thumb_dimensions) = self.thumb_area();
+
+        renderer.draw_rectangle(
+            self.position,
+            self.dimensions,
+            self.style.accent.get(resting_state(self.enabled)),
+        );
+        renderer.draw_rectangle(
+            thumb_position,
+            thumb_dimensions,
+            self.style.foreground.get(self.get_state()),
+        );
+    }
+
+    /// Checks if the thumb of the `ScrollBar` is being hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        let (thumb_position, thumb_dimensions) = self.thumb_area();
+        self.hovered = is_inside(thumb_position, thumb_dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// Checks if the track of the `ScrollBar` was clicked, jumping a page
+    /// towards the mouse and returning whether or not the offset changed.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
+        if is_clicked {
+            input = input.with_button_pressed(PointerButton::Left);
+        }
+
+        self.handle_input(&input);
+
+        self.changed
+    }
+}
+
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<ScrollBar> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
+        let mut c = Vec::new();
+
+        for i in iter {
+            if let DrawableType::ScrollBar(s) = i {
+                c.push(s)
+            }
+        }
+
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `ScrollBar` 100 pixels long showing a quarter of its content.
+    fn scroll_bar() -> ScrollBar {
+        let mut scroll_bar = ScrollBar::new((0, 0), 100, Orientation::Vertical);
+        scroll_bar.set_content(400, 100);
+
+        scroll_bar
+    }
+
+    fn press(position: Point) -> InputState {
+        InputState::new()
+            .with_mouse_position(position)
+            .with_button_pressed(PointerButton::Left)
+    }
+
+    fn hold(position: Point) -> InputState {
+        InputState::new()
+            .with_mouse_position(position)
+            .with_button_down(PointerButton::Left)
+    }
+
+    #[test]
+    fn the_thumb_shows_how_much_of_the_content_fits() {
+        let mut scroll_bar = scroll_bar();
+        assert_eq!(scroll_bar.thumb(), (0, 25));
+
+        scroll_bar.set_offset(300);
+        assert_eq!(scroll_bar.thumb(), (75, 25));
+
+        scroll_bar.set_offset(1000);
+        assert_eq!(scroll_bar.get_offset(), 300);
+
+        scroll_bar.scroll_by(-1000);
+        assert_eq!(scroll_bar.get_offset(), 0);
+    }
+
+    #[test]
+    fn content_that_fits_cannot_be_scrolled() {
+        let mut scroll_bar = ScrollBar::new((0, 0), 100, Orientation::Horizontal);
+        scroll_bar.set_content(50, 100).set_offset(10);
+
+        assert!(!scroll_bar.is_scrollable());
+        assert_eq!(scroll_bar.get_offset(), 0);
+        assert_eq!(scroll_bar.thumb(), (0, 100));
+    }
+
+    #[test]
+    fn clicking_the_track_jumps_a_page_towards_the_mouse() {
+        let mut scroll_bar = scroll_bar();
+
+        scroll_bar.handle_input(&press((5, 90)));
+        assert_eq!(scroll_bar.get_offset(), 100);
+        assert!(scroll_bar.was_changed());
+
+        scroll_bar.handle_input(&press((5, 10)));
+        assert_eq!(scroll_bar.get_offset(), 0);
+    }
+
+    #[test]
+    fn dragging_the_thumb_scrolls_in_proportion() {
+        let mut scroll_bar = scroll_bar();
+
+        scroll_bar.handle_input(&press((5, 10)));
+        assert_eq!(scroll_bar.get_offset(), 0);
+
+        scroll_bar.handle_input(&hold((5, 35)));
+        assert_eq!(scroll_bar.get_offset(), 100);
+
+        scroll_bar.handle_input(&hold((5, 200)));
+        assert_eq!(scroll_bar.get_offset(), 300);
+
+        scroll_bar.handle_input(&InputState::new().with_mouse_position((5, 10)));
+        scroll_bar.handle_input(&hold((5, 35)));
+        assert_eq!(scroll_bar.get_offset(), 300);
+        assert!(!scroll_bar.was_changed());
+    }
+
+    #[test]
+    fn the_mouse_wheel_scrolls_a_line_while_the_mouse_is_over_it() {
+        let mut scroll_bar = scroll_bar();
+        let wheel = InputState::new().with_mouse_wheel(-1.0);
+
+        scroll_bar.handle_input(&wheel.clone().with_mouse_position((50, 50)));
+        assert_eq!(scroll_bar.get_offset(), 0);
+
+        scroll_bar.handle_input(&wheel.with_mouse_position((5, 50)));
+        assert_eq!(scroll_bar.get_offset(), ScrollBar::LINE_LENGTH);
+    }
+}
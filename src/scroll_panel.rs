@@ -0,0 +1,690 @@
+use crate::{common::*, gui_component::*, prelude::*};
+
+/// A container that stacks its components in a column and clips them to its
+/// own area, scrolling through them with the mouse wheel, by dragging the
+/// thumb of its `ScrollBar`, or with the Page Up, Page Down, Home and End keys
+/// while it or one of its components has focus. When there is something to
+/// scroll, the `ScrollPanel` can be focused itself and scrolled a line at a
+/// time with the up and down directions.
+pub struct ScrollPanel<T, E> {
+    components: Vec<DrawableType<T, E>>,
+    content_dimensions: Dimensions,
+    custom_style: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    layout: Layout,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    scroll_bar: Box<ScrollBar>,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    theme: Box<Theme>,
+    visible: bool,
+}
+
+impl<T, E> ScrollPanel<T, E> {
+    /// Create a new, empty `ScrollPanel` that shows an area of the given
+    /// `dimensions`.
+    pub fn new(position: Point, dimensions: Dimensions) -> Self {
+        Self {
+            components: Vec::new(),
+            content_dimensions: (0, 0),
+            custom_style: false,
+            dimensions,
+            enabled: true,
+            focused: false,
+            hovered: false,
+            id: WidgetId::unique(),
+            layout: Layout::vbox(),
+            position,
+            preferred_dimensions: dimensions,
+            scroll_bar: Box::new(ScrollBar::new(
+                position,
+                dimensions.1,
+                Orientation::Vertical,
+            )),
+            style: Theme::default().scroll_panel,
+            text_measurer: TextMeasurer::default(),
+            theme: Box::default(),
+            visible: true,
+        }
+    }
+
+    /// Returns the `WidgetId` of the `ScrollPanel`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `ScrollPanel` is drawn and responds to
+    /// input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `ScrollPanel`, along with all of its components.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the `ScrollPanel` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `ScrollPanel`. A disabled `ScrollPanel` is drawn
+    /// in the disabled colours of its style, and neither it nor its components
+    /// respond to input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Returns whether or not the `ScrollPanel` has keyboard or gamepad
+    /// focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `ScrollPanel` and lets it be scrolled with the keys.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Returns `true` if the components do not fit inside of the
+    /// `ScrollPanel`, so that it can be scrolled.
+    pub fn is_scrollable(&self) -> bool { self.scroll_bar.is_scrollable() }
+
+    /// Scrolls `lines` lines down, or up if it is negative, returning whether
+    /// or not the offset changed.
+    pub fn scroll_lines(&mut self, lines: i32) -> bool {
+        let previous_offset = self.get_scroll_offset();
+        self.set_scroll_offset(previous_offset + lines * ScrollBar::LINE_LENGTH);
+
+        self.get_scroll_offset() != previous_offset
+    }
+
+    /// Returns the components of the `ScrollPanel`.
+    pub fn get_components(&self) -> &[DrawableType<T, E>] { &self.components }
+
+    /// Returns the components of the `ScrollPanel` mutably.
+    pub fn get_components_mut(&mut self) -> &mut [DrawableType<T, E>] { &mut self.components }
+
+    /// Returns the `Layout` that stacks the components of the `ScrollPanel`,
+    /// so that its padding, spacing and alignment can be changed.
+    pub fn get_layout_mut(&mut self) -> &mut Layout { &mut self.layout }
+
+    /// Removes the component with the given `id` from the `ScrollPanel`, or
+    /// from any container inside of it, returning it if it was found.
+    pub fn remove(&mut self, id: impl Into<WidgetId>) -> Option<DrawableType<T, E>> {
+        let id = id.into();
+        self.layout.remove(id);
+
+        remove_component(&mut self.components, id)
+    }
+
+    /// Sets the function used to measure the width of text when sizing the
+    /// components of this `ScrollPanel`, resizing the ones it already has.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+
+        for component in self.components.iter_mut() {
+            component.set_text_measurer(self.text_measurer.clone());
+        }
+
+        self
+    }
+
+    /// Add a new `DrawableType` component to the bottom of the
+    /// `ScrollPanel`, styling it with the `ScrollPanel`'s theme and returning
+    /// its `WidgetId`.
+    pub fn add_component(&mut self, mut component: DrawableType<T, E>) -> WidgetId {
+        component.apply_theme(&self.theme);
+        let id = component.get_id();
+        self.components.push(component);
+        self.layout.add_widget(id);
+        self.layout_components();
+
+        id
+    }
+
+    /// Makes it so that all components are drawn at the same width, filling
+    /// the `ScrollPanel`.
+    pub fn set_components_fix_widths(&mut self, value: bool) -> &mut Self {
+        self.layout.set_alignment(match value {
+            true => Alignment::Fill,
+            false => Alignment::Start,
+        });
+
+        self
+    }
+
+    /// Adds a `Button` to the bottom of the `ScrollPanel`.
+    pub fn add_button(&mut self, text: &str, event: E) -> ButtonHandle {
+        let mut button = Button::new_with_dimensions(text, event, 20, self.position, (0, 0));
+        button.set_text_measurer(self.text_measurer.clone());
+
+        ButtonHandle(self.add_component(DrawableType::Button(button)))
+    }
+
+    /// Adds a `Checkbox` labelled with `text` to the bottom of the
+    /// `ScrollPanel`.
+    pub fn add_checkbox(&mut self, text: &str, checked: bool) -> CheckboxHandle {
+        let mut checkbox = Checkbox::new(text, checked, self.position);
+        checkbox.set_text_measurer(self.text_measurer.clone());
+
+        CheckboxHandle(self.add_component(DrawableType::Checkbox(checkbox)))
+    }
+
+    /// Adds a `Label` to the bottom of the `ScrollPanel`.
+    pub fn add_label(&mut self, text: &str) -> LabelHandle {
+        let mut label = Label::new_with_dimensions(text, 20, self.position, (0, 0));
+        label.set_text_measurer(self.text_measurer.clone());
+
+        LabelHandle(self.add_component(DrawableType::Label(label)))
+    }
+
+    /// Adds a `RadioGroup` with an option for each of `options` to the
+    /// bottom of the `ScrollPanel`.
+    pub fn add_radio_group(
+        &mut self,
+        options: &[&str],
+        selected: Option<usize>,
+    ) -> RadioGroupHandle {
+        let mut radio_group = RadioGroup::new(options, selected, self.position);
+        radio_group.set_text_measurer(self.text_measurer.clone());
+
+        RadioGroupHandle(self.add_component(DrawableType::RadioGroup(radio_group)))
+    }
+
+    /// Adds a `Slider` to the bottom of the `ScrollPanel`.
+    pub fn add_slider(&mut self, min: f32, max: f32, initial_value: f32) -> SliderHandle {
+        let mut slider = Slider::new(min, max, initial_value, self.position, 250);
+        slider.set_text_measurer(self.text_measurer.clone());
+
+        SliderHandle(self.add_component(DrawableType::Slider(slider)))
+    }
+
+    /// Adds an empty `TextBox` that is `width` pixels wide to the bottom of
+    /// the `ScrollPanel`.
+    pub fn add_text_box(&mut self, width: i32) -> TextBoxHandle {
+        let mut text_box = TextBox::new(self.position, width);
+        text_box.set_text_measurer(self.text_measurer.clone());
+
+        TextBoxHandle(self.add_component(DrawableType::TextBox(text_box)))
+    }
+
+    /// Adds a `Toggle` labelled with `text` to the bottom of the
+    /// `ScrollPanel`.
+    pub fn add_toggle(&mut self, text: &str, value: bool) -> ToggleHandle {
+        let mut toggle = Toggle::new(text, value, self.position);
+        toggle.set_text_measurer(self.text_measurer.clone());
+
+        ToggleHandle(self.add_component(DrawableType::Toggle(toggle)))
+    }
+
+    /// Returns how far the components are scrolled, in pixels.
+    pub fn get_scroll_offset(&self) -> i32 { self.scroll_bar.get_offset() }
+
+    /// Scrolls the components to `offset` pixels from the top, keeping them
+    /// inside of the `ScrollPanel`.
+    pub fn set_scroll_offset(&mut self, offset: i32) -> &mut Self {
+        self.layout_components();
+        self.scroll_bar.set_offset(offset);
+        self.layout_components();
+
+        self
+    }
+
+    /// Scrolls the least distance needed for the component with the given
+    /// `id` to be fully inside of the `ScrollPanel`, doing nothing if it is
+    /// not one of its components.
+    pub fn scroll_into_view(&mut self, id: impl Into<WidgetId>) -> &mut Self {
+        let id = id.into();
+        let component = match self.components.iter().find(|c| c.get_id() == id) {
+            Some(component) => component,
+            None => return self,
+        };
+
+        let top = component.get_position().1 - self.position.1 + self.get_scroll_offset();
+        let bottom = top + component.get_dimensions().1;
+        let offset = self.get_scroll_offset();
+
+        if top < offset {
+            self.set_scroll_offset(top);
+        } else if bottom > offset + self.dimensions.1 {
+            self.set_scroll_offset(bottom - self.dimensions.1);
+        }
+
+        self
+    }
+
+    /// Returns the dimensions of all of the components stacked together,
+    /// which may be larger than the `ScrollPanel` itself.
+    pub fn get_content_dimensions(&self) -> Dimensions { self.content_dimensions }
+
+    /// Resizes the `ScrollPanel` to fit its components, but no taller than
+    /// `max_height` if it is given, leaving room for the `ScrollBar` if they
+    /// do not fit.
+    pub fn fit_to_content(&mut self, max_height: Option<i32>) {
+        let content = self.layout.measure(&self.components);
+        let height = max_height.map_or(content.1, |max_height| content.1.min(max_height));
+        let bar_width = match content.1 > height {
+            true => ScrollBar::THICKNESS,
+            false => 0,
+        };
+
+        self.preferred_dimensions = (content.0 + bar_width, height);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Gives the `ScrollPanel` its own `style`, which is used instead of the
+    /// theme of the `GuiHandler`. This does not change the style of its
+    /// components.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+
+        self
+    }
+
+    /// Styles the `ScrollPanel`, its `ScrollBar` and its components using
+    /// `theme`, unless they have been given their own style.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        *self.theme = theme.clone();
+
+        if !self.custom_style {
+            self.style = theme.scroll_panel.clone();
+        }
+
+        self.scroll_bar.apply_theme(theme);
+
+        for component in self.components.iter_mut() {
+            component.apply_theme(theme);
+        }
+    }
+
+    /// Returns the state the `ScrollPanel` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match (self.focused, self.hovered) {
+            (true, _) => WidgetState::Focused,
+            (false, true) => WidgetState::Hovered,
+            (false, false) => WidgetState::Default,
+        }
+    }
+
+    /// Returns the dimensions the `ScrollPanel` was created with, which
+    /// layouts use as the size the `ScrollPanel` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the area the `ScrollPanel` shows to `new_dimensions`.
+    pub fn resize(&mut self, new_dimensions: Dimensions) {
+        self.dimensions = new_dimensions;
+        self.layout_components();
+    }
+
+    /// Moves the `ScrollPanel` to `new_position`, moving its components along
+    /// with it.
+    pub fn move_to(&mut self, new_position: Point) {
+        self.position = new_position;
+        self.layout_components();
+    }
+
+    /// Returns the width of the area the components are drawn in, which
+    /// leaves room for the `ScrollBar` when there is something to scroll.
+    fn viewport_width(&self) -> i32 {
+        match self.scroll_bar.is_scrollable() {
+            true => self.dimensions.0 - ScrollBar::THICKNESS,
+            false => self.dimensions.0,
+        }
+    }
+
+    /// Stacks the components of the `ScrollPanel` in a column, moved up by
+    /// how far it is scrolled.
+    pub fn layout_components(&mut self) {
+        self.content_dimensions = self.layout.measure(&self.components);
+        self.scroll_bar
+            .set_content(self.content_dimensions.1, self.dimensions.1);
+
+        let viewport_width = self.viewport_width();
+        self.scroll_bar
+            .resize((ScrollBar::THICKNESS, self.dimensions.1));
+        self.scroll_bar.position = (self.position.0 + viewport_width, self.position.1);
+
+        self.layout.arrange(
+            (self.position.0, self.position.1 - self.get_scroll_offset()),
+            (viewport_width, self.content_dimensions.1),
+            &mut self.components,
+        );
+    }
+
+    /// Returns `true` if any `TextBox` in the `ScrollPanel` is being typed
+    /// into, in which case it receives the keys instead of the `ScrollPanel`.
+    fn has_editing_text_box(&self) -> bool {
+        self.components
+            .iter()
+            .any(|c| matches!(c, DrawableType::TextBox(t) if t.is_editing()))
+    }
+
+    /// Returns `true` if the component with the `focused` id is this
+    /// `ScrollPanel` or one of its components, but not one inside of a nested
+    /// `ScrollPanel`, which scrolls with the keys instead.
+    fn has_focus(&self, focused: Option<WidgetId>) -> bool {
+        let focused = match focused {
+            Some(focused) => focused,
+            None => return false,
+        };
+        if focused == self.id {
+            return true;
+        }
+
+        let in_nested_panel = self.components.iter().any(|c| {
+            let panel = match c {
+                DrawableType::ScrollPanel(p) => p,
+                DrawableType::Dropdown(d) => d.get_panel(),
+                _ => return false,
+            };
+
+            find_component(panel.get_components(), focused).is_some()
+        });
+
+        !in_nested_panel && find_component(&self.components, focused).is_some()
+    }
+
+    /// Scrolls with the mouse wheel, keys and `ScrollBar`, returning whether
+    /// or not the offset changed.
+    fn scroll(&mut self, input: &InputState) -> bool {
+        let previous_offset = self.get_scroll_offset();
+        let viewport = (self.viewport_width(), self.dimensions.1);
+
+        if self.scroll_bar.is_scrollable() {
+            self.scroll_bar.is_hovered(input.mouse_position);
+            self.scroll_bar.handle_input(input);
+        }
+
+        if is_inside(self.position, viewport, input.mouse_position) && input.mouse_wheel != 0.0 {
+            self.scroll_bar
+                .scroll_by(-(input.mouse_wheel * ScrollBar::LINE_LENGTH as f32) as i32);
+        }
+
+        if self.has_focus(input.focused) && !self.has_editing_text_box() {
+            for key in input.keys_pressed.iter() {
+                let distance = match key {
+                    Key::PageUp => -self.dimensions.1,
+                    Key::PageDown => self.dimensions.1,
+                    Key::Home => -self.scroll_bar.get_offset(),
+                    Key::End => self.scroll_bar.get_max_offset(),
+                    _ => 0,
+                };
+
+                self.scroll_bar.scroll_by(distance);
+            }
+        }
+
+        self.get_scroll_offset() != previous_offset
+    }
+
+    /// Calls the callbacks of the components of the `ScrollPanel` for
+    /// anything that happened during the last update.
+    pub fn execute_callbacks(&mut self, state: &mut T, button_action: Option<Action<T, E>>) {
+        for component in self.components.iter_mut() {
+            component.execute_callbacks(state, button_action);
+        }
+    }
+}
+
+impl<T, E: Clone> ScrollPanel<T, E> {
+    /// Scrolls the `ScrollPanel` and updates its components with the given
+    /// `input`, returning whether or not it was scrolled. The mouse is only
+    /// passed on to the components while it is inside of the `ScrollPanel`,
+    /// so that parts of them that are scrolled out of view cannot be clicked,
+    /// unless one of them holds the pointer capture.
+    pub fn update(&mut self, input: &InputState, events: &mut Vec<GuiEvent<E>>) -> bool {
+        self.layout_components();
+        self.is_hovered(input.mouse_position);
+        let scrolled = self.scroll(input);
+        self.layout_components();
+
+        let viewport = (self.viewport_width(), self.dimensions.1);
+        let popup_hovered = self
+            .components
+            .iter()
+            .any(|c| c.is_visible() && c.is_popup_hovered(input.mouse_position));
+
+        let captured = input
+            .pointer_capture
+            .is_some_and(|id| find_component(&self.components, id).is_some());
+
+        if popup_hovered || captured || is_inside(self.position, viewport, input.mouse_position) {
+            update_components(&mut self.components, input, events);
+        } else {
+            let outside = input.clone().with_mouse_position((i32::MIN, i32::MIN));
+            update_components(&mut self.components, &outside, events);
+        }
+
+        scrolled
+    }
+}
+
+impl<T, E: Clone> GuiComponentBehaviour<bool> for ScrollPanel<T, E> {
+    /// Draw `ScrollPanel` and the parts of its components that are inside of
+    /// it to screen.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        self.layout_components();
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        renderer.push_clip(self.position, (self.viewport_width(), self.dimensions.1));
+        for component in self.components.iter_mut() {
+            component.draw(renderer);
+        }
+        renderer.pop_clip();
+
+        if self.scroll_bar.is_scrollable() {
+            self.scroll_bar.draw(renderer);
+        }
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
+
+        // Popups are not clipped, so that they can cover more than the
+        // `ScrollPanel`.
+        for component in self.components.iter_mut() {
+            component.draw_popup(renderer);
+        }
+    }
+
+    /// Checks if the `ScrollPanel` is being hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// Checks if the `ScrollBar` of the `ScrollPanel` was clicked, returning
+    /// whether or not it scrolled. Use `update` to pass input on to its
+    /// components.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let previous_offset = self.get_scroll_offset();
+        self.scroll_bar.is_clicked(mouse_position, is_clicked);
+        self.layout_components();
+
+        self.get_scroll_offset() != previous_offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `ScrollPanel` 100 pixels tall holding five buttons, which do not fit.
+    fn panel() -> ScrollPanel<(), &'static str> {
+        let mut panel = ScrollPanel::new((0, 0), (200, 100));
+        panel.set_text_measurer(TextMeasurer::new(measure_text_monospace));
+
+        for name in ["One", "Two", "Three", "Four", "Five"] {
+            panel.add_button(name, name);
+        }
+
+        panel
+    }
+
+    /// Updates `panel` with `input`, returning how far it is then scrolled.
+    fn scroll(panel: &mut ScrollPanel<(), &'static str>, input: InputState) -> i32 {
+        panel.update(&input, &mut Vec::new());
+
+        panel.get_scroll_offset()
+    }
+
+    #[test]
+    fn the_mouse_wheel_scrolls_while_the_mouse_is_inside() {
+        let mut panel = panel();
+        let wheel = InputState::new().with_mouse_wheel(-1.0);
+
+        assert_eq!(
+            scroll(&mut panel, wheel.clone().with_mouse_position((300, 50))),
+            0
+        );
+        assert_eq!(
+            scroll(&mut panel, wheel.with_mouse_position((50, 50))),
+            ScrollBar::LINE_LENGTH
+        );
+    }
+
+    #[test]
+    fn keys_scroll_the_panel_only_while_it_has_focus() {
+        let mut panel = panel();
+        let max_offset = panel.get_content_dimensions().1 - 100;
+        let hovered = InputState::new().with_mouse_position((50, 50));
+        let focused = InputState::new().with_focused(panel.get_id());
+
+        assert_eq!(
+            scroll(&mut panel, hovered.with_key_pressed(Key::PageDown)),
+            0
+        );
+        assert_eq!(
+            scroll(&mut panel, focused.clone().with_key_pressed(Key::PageDown)),
+            100
+        );
+        assert_eq!(
+            scroll(&mut panel, focused.clone().with_key_pressed(Key::End)),
+            max_offset
+        );
+        assert_eq!(
+            scroll(&mut panel, focused.clone().with_key_pressed(Key::Home)),
+            0
+        );
+        // The arrow keys are left to move focus.
+        assert_eq!(scroll(&mut panel, focused.with_key_pressed(Key::Down)), 0);
+    }
+
+    #[test]
+    fn keys_scroll_the_panel_while_one_of_its_components_has_focus() {
+        let mut panel = panel();
+        let button = panel.get_components()[0].get_id();
+        let focused = InputState::new().with_focused(button);
+
+        assert_eq!(
+            scroll(&mut panel, focused.with_key_pressed(Key::PageDown)),
+            100
+        );
+    }
+
+    #[test]
+    fn only_the_innermost_panel_scrolls_with_the_keys() {
+        let inner = panel();
+        let button = inner.get_components()[0].get_id();
+        let mut outer = ScrollPanel::new((0, 0), (200, 100));
+        outer.set_text_measurer(TextMeasurer::new(measure_text_monospace));
+        let inner = outer.add_component(DrawableType::ScrollPanel(inner));
+        outer.add_button("After", "after");
+
+        let focused = InputState::new().with_focused(button);
+        assert_eq!(
+            scroll(&mut outer, focused.with_key_pressed(Key::PageDown)),
+            0
+        );
+
+        match find_component(outer.get_components(), inner) {
+            Some(DrawableType::ScrollPanel(inner)) => assert_eq!(inner.get_scroll_offset(), 100),
+            _ => panic!("the inner panel is missing"),
+        }
+    }
+
+    #[test]
+    fn scrolling_lines_stops_at_either_end() {
+        let mut panel = panel();
+        let max_offset = panel.get_content_dimensions().1 - 100;
+
+        assert!(!panel.scroll_lines(-1));
+        assert!(panel.scroll_lines(1));
+        assert_eq!(panel.get_scroll_offset(), ScrollBar::LINE_LENGTH);
+
+        assert!(panel.scroll_lines(100));
+        assert_eq!(panel.get_scroll_offset(), max_offset);
+        assert!(!panel.scroll_lines(1));
+    }
+
+    #[test]
+    fn components_scrolled_out_of_view_cannot_be_clicked() {
+        let mut panel = panel();
+        panel.set_scroll_offset(100);
+        let first = panel.get_components()[0].get_id();
+        let above = InputState::new().with_mouse_position((10, -90));
+        let mut events = Vec::new();
+
+        panel.update(
+            &above.clone().with_button_pressed(PointerButton::Left),
+            &mut events,
+        );
+        panel.update(
+            &above.with_button_released(PointerButton::Left),
+            &mut events,
+        );
+
+        assert!(panel.get_components()[0].get_position().1 < 0);
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, GuiEvent::ButtonClicked { button, .. } if button.id() == first)));
+    }
+
+    #[test]
+    fn a_focused_panel_scrolls_with_the_up_and_down_directions() {
+        let mut g_handler = GuiHandler::<(), &str>::new(Colour::WHITE);
+        g_handler.set_text_measurer(TextMeasurer::new(measure_text_monospace));
+        let panel = g_handler.add_scroll_panel_with_position((200, 100), (0, 0));
+        let after = g_handler.add_button_with_position("After", "after", (0, 200));
+        for text in ["One", "Two", "Three", "Four", "Five"] {
+            g_handler
+                .get_scroll_panel_mut(panel)
+                .unwrap()
+                .add_label(text);
+        }
+
+        let input = InputState::new().with_screen_size((800, 600));
+        let down = input.clone().with_key_pressed(Key::Down);
+        g_handler.update(&input);
+        g_handler.set_focus(panel).unwrap();
+
+        g_handler.update(&down);
+        assert_eq!(g_handler.get_focused(), Some(panel.id()));
+        assert_eq!(
+            g_handler.events(),
+            &[GuiEvent::ScrollPanelScrolled {
+                scroll_panel: panel,
+                offset: ScrollBar::LINE_LENGTH,
+            }]
+        );
+
+        while !g_handler.events().is_empty() {
+            g_handler.update(&input).update(&down);
+        }
+        assert_eq!(g_handler.get_focused(), Some(after.id()));
+    }
+}
@@ -1,181 +1,681 @@
 use crate::{
     common::*,
     gui_component::GuiComponentBehaviour,
-    prelude::{state_get_colour, Colour, Dimensions, Point, StateColour},
+    prelude::{
+        Dimensions, InputState, Orientation, Point, PointerButton, Renderer, TextMeasurer, Theme,
+        ValueCallback, ValueFormatter, WidgetId, WidgetState, WidgetStyle,
+    },
 };
-use raylib::prelude::*;
 use std::iter::FromIterator;
 
-#[derive(Copy, Clone, PartialEq)]
-pub struct Slider {
-    background_colour: Colour,
-    max: i32,
-    min: i32,
+/// The track of a `Slider` or `RangeSlider`, which maps between values and
+/// positions of the boxes that are dragged along it.
+pub(crate) struct SliderTrack {
+    logarithmic: bool,
+    max: f32,
+    min: f32,
+    orientation: Orientation,
+    step: Option<f32>,
+    text_space: Dimensions,
+    pub box_dimensions: Dimensions,
     pub dimensions: Dimensions,
     pub position: Point,
-    slider_background_colour: Colour,
-    slider_box_colour: Colour,
-    slider_box_dimensions: Dimensions,
-    slider_box_position: Point,
-    slider_dimensions: Dimensions,
-    slider_position: Point,
-    slider_text_colour: Colour,
-    value: f32,
 }
 
-impl Slider {
-    /// Create a new `Slider` with a defined minimum and maximum value.
-    pub fn new(min: i32, max: i32, initial_value: f32, position: Point, width: i32) -> Self {
-        let mut initial_value = initial_value;
+impl SliderTrack {
+    /// The thickness of the track of a vertical slider.
+    const VERTICAL_THICKNESS: i32 = 35;
 
-        if min > initial_value as i32 {
-            initial_value = min as f32;
-        } else if max < initial_value as i32 {
-            initial_value = max as f32;
-        }
+    /// How much of the track a box is moved by each step when there is no
+    /// step to move by.
+    const DEFAULT_STEP_FRACTION: f32 = 0.05;
 
-        let mut s = Self {
-            background_colour: state_get_colour(StateColour::Default),
-            dimensions: (width + 120, 50),
+    /// Create a new horizontal track that is `width` long inside a slider at
+    /// `position`, leaving `text_space` for the readout after it.
+    pub fn new(min: f32, max: f32, position: Point, width: i32, text_space: Dimensions) -> Self {
+        Self {
+            box_dimensions: (30, 35),
+            dimensions: (width, 35),
+            logarithmic: false,
             max,
             min,
+            orientation: Orientation::Horizontal,
+            position: (position.0 + 10, position.1 + 7),
+            step: None,
+            text_space,
+        }
+    }
+
+    pub fn get_range(&self) -> (f32, f32) { (self.min, self.max) }
+
+    pub fn set_range(&mut self, min: f32, max: f32) {
+        self.min = min;
+        self.max = max;
+    }
+
+    pub fn get_step(&self) -> Option<f32> { self.step }
+
+    pub fn set_step(&mut self, step: Option<f32>) { self.step = step.filter(|&step| step > 0.0); }
+
+    pub fn is_logarithmic(&self) -> bool { self.logarithmic && self.min > 0.0 && self.max > 0.0 }
+
+    pub fn set_logarithmic(&mut self, logarithmic: bool) { self.logarithmic = logarithmic; }
+
+    pub fn get_orientation(&self) -> Orientation { self.orientation }
+
+    /// Changes the orientation of the track, returning the dimensions that
+    /// fit a slider with a track of the same length.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> Dimensions {
+        let length = self.length();
+        self.orientation = orientation;
+
+        self.fit_length(length)
+    }
+
+    /// Keeps the space for the readout of a slider with the given
+    /// `text_space` at the font size of the default theme, grown or shrunk
+    /// to fit text of `font_size`. This returns the dimensions that fit a
+    /// slider with a track of the same length.
+    pub fn fit_text_space(&mut self, text_space: Dimensions, font_size: i32) -> Dimensions {
+        let default_font_size = Theme::default().slider.font_size;
+        let length = self.length();
+        self.text_space = (
+            text_space.0 * font_size / default_font_size,
+            text_space.1 + font_size - default_font_size,
+        );
+
+        self.fit_length(length)
+    }
+
+    fn length(&self) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => self.dimensions.0,
+            Orientation::Vertical => self.dimensions.1,
+        }
+    }
+
+    /// Returns the dimensions of a slider with a track `length` long.
+    fn fit_length(&self, length: i32) -> Dimensions {
+        match self.orientation {
+            Orientation::Horizontal => (length + self.text_space.0, self.text_space.1),
+            Orientation::Vertical => (self.text_space.0, length + self.text_space.1 + 10),
+        }
+    }
+
+    /// Returns how far along the track `value` is, from 0 at the minimum to 1
+    /// at the maximum.
+    fn value_to_fraction(&self, value: f32) -> f32 {
+        let fraction = match self.is_logarithmic() {
+            true => (value / self.min).ln() / (self.max / self.min).ln(),
+            false => (value - self.min) / (self.max - self.min),
+        };
+
+        match fraction.is_finite() {
+            true => fraction.clamp(0.0, 1.0),
+            false => 0.0,
+        }
+    }
+
+    /// Returns the value that is `fraction` of the way along the track.
+    fn fraction_to_value(&self, fraction: f32) -> f32 {
+        match self.is_logarithmic() {
+            true => self.min * (self.max / self.min).powf(fraction),
+            false => self.min + (self.max - self.min) * fraction,
+        }
+    }
+
+    /// Returns `value` kept between the minimum and maximum and rounded to the
+    /// nearest step, if there is one.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = match self.step {
+            Some(step) => self.min + ((value - self.min) / step).round() * step,
+            None => value,
+        };
+
+        value.clamp(self.min.min(self.max), self.max.max(self.min))
+    }
+
+    /// Returns `value` moved `steps` steps along the track, or `steps`
+    /// twentieths of the track if there is no step, kept between the minimum
+    /// and maximum.
+    pub fn step_from(&self, value: f32, steps: i32) -> f32 {
+        match self.step {
+            Some(step) => self.snap(value + step * steps as f32),
+            None => self.offset_from(value, steps as f32 * Self::DEFAULT_STEP_FRACTION),
+        }
+    }
+
+    /// Returns `value` moved `fraction` of the length of the track, rounded to
+    /// the nearest step.
+    pub fn offset_from(&self, value: f32, fraction: f32) -> f32 {
+        let fraction = (self.value_to_fraction(value) + fraction).clamp(0.0, 1.0);
+
+        self.snap(self.fraction_to_value(fraction))
+    }
+
+    /// Returns `true` if moving `value` by `fraction` of the length of the
+    /// track would take it past either end.
+    pub fn is_past_end(&self, value: f32, fraction: f32) -> bool {
+        !(0.0..=1.0).contains(&(self.value_to_fraction(value) + fraction))
+    }
+
+    /// Returns the distance a box can move along the track.
+    fn travel(&self) -> i32 {
+        match self.orientation {
+            Orientation::Horizontal => self.dimensions.0 - self.box_dimensions.0,
+            Orientation::Vertical => self.dimensions.1 - self.box_dimensions.1,
+        }
+        .max(0)
+    }
+
+    /// Returns the position of the box for `value`. The minimum is at the left
+    /// of a horizontal track and the bottom of a vertical one.
+    pub fn box_position(&self, value: f32) -> Point {
+        let distance = (self.value_to_fraction(value) * self.travel() as f32).round() as i32;
+
+        match self.orientation {
+            Orientation::Horizontal => (self.position.0 + distance, self.position.1),
+            Orientation::Vertical => (self.position.0, self.position.1 + self.travel() - distance),
+        }
+    }
+
+    /// Returns the value for a box at `box_position`, rounded to the nearest
+    /// step.
+    pub fn value_at(&self, box_position: Point) -> f32 {
+        let distance = match self.orientation {
+            Orientation::Horizontal => box_position.0 - self.position.0,
+            Orientation::Vertical => self.position.1 + self.travel() - box_position.1,
+        };
+        let fraction = match self.travel() {
+            0 => 0.0,
+            travel => distance as f32 / travel as f32,
+        };
+
+        self.snap(self.fraction_to_value(fraction))
+    }
+
+    /// Returns the position of a box that is centred on `mouse_position`,
+    /// kept on the track.
+    pub fn box_position_at(&self, mouse_position: Point) -> Point {
+        match self.orientation {
+            Orientation::Horizontal => (
+                mouse_position
+                    .0
+                    .saturating_sub(self.box_dimensions.0 / 2)
+                    .clamp(self.position.0, self.position.0 + self.travel()),
+                self.position.1,
+            ),
+            Orientation::Vertical => (
+                self.position.0,
+                mouse_position
+                    .1
+                    .saturating_sub(self.box_dimensions.1 / 2)
+                    .clamp(self.position.1, self.position.1 + self.travel()),
+            ),
+        }
+    }
+
+    /// Places the track inside a slider at `position` with `dimensions`,
+    /// leaving space for the readout after it.
+    pub fn layout(&mut self, position: Point, dimensions: Dimensions) {
+        match self.orientation {
+            Orientation::Horizontal => {
+                self.position = (position.0 + 10, position.1 + 7);
+                self.dimensions = (dimensions.0 - self.text_space.0, dimensions.1 - 15);
+                self.box_dimensions = (30, dimensions.1 - 15);
+            },
+            Orientation::Vertical => {
+                self.position = (
+                    position.0 + (dimensions.0 - Self::VERTICAL_THICKNESS) / 2,
+                    position.1 + 10,
+                );
+                self.dimensions = (
+                    Self::VERTICAL_THICKNESS,
+                    dimensions.1 - self.text_space.1 - 10,
+                );
+                self.box_dimensions = (Self::VERTICAL_THICKNESS, 30);
+            },
+        }
+    }
+
+    /// Returns where `text` is drawn in a slider at `position` with
+    /// `dimensions`: after a horizontal track, or centred below a vertical
+    /// one.
+    pub fn text_position(
+        &self,
+        position: Point,
+        dimensions: Dimensions,
+        text: &str,
+        style: &WidgetStyle,
+        text_measurer: &TextMeasurer,
+    ) -> Point {
+        match self.orientation {
+            Orientation::Horizontal => (
+                (position.0 + dimensions.0) - (self.text_space.0 - 15),
+                position.1 + (dimensions.1 - style.font_size) / 2,
+            ),
+            Orientation::Vertical => (
+                position.0 + (dimensions.0 - text_measurer.measure(text, style.font_size)) / 2,
+                (position.1 + dimensions.1) - (self.text_space.1 + style.font_size) / 2,
+            ),
+        }
+    }
+
+    /// Draws the track in the accent colour of `style` for `state`.
+    pub fn draw(&self, renderer: &mut dyn Renderer, style: &WidgetStyle, state: WidgetState) {
+        renderer.draw_rectangle(self.position, self.dimensions, style.accent.get(state));
+    }
+}
+
+pub struct Slider<T> {
+    adjustment: f32,
+    changed: bool,
+    custom_style: bool,
+    dragging: bool,
+    enabled: bool,
+    focused: bool,
+    formatter: Option<ValueFormatter>,
+    hovered: bool,
+    id: WidgetId,
+    on_change: Option<ValueCallback<T, f32>>,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    slider_box_position: Point,
+    style: WidgetStyle,
+    text_measurer: TextMeasurer,
+    track: SliderTrack,
+    value: f32,
+    visible: bool,
+}
+
+impl<T> Slider<T> {
+    /// The space after the track that is kept for the readout, at the font
+    /// size of the default theme.
+    const TEXT_SPACE: Dimensions = (120, 50);
+
+    /// Create a new horizontal `Slider` with a defined minimum and maximum
+    /// value, where `width` is the length of its track.
+    pub fn new(min: f32, max: f32, initial_value: f32, position: Point, width: i32) -> Self {
+        let mut s = Self {
+            adjustment: 0.0,
+            changed: false,
+            custom_style: false,
+            dragging: false,
+            dimensions: (width + Self::TEXT_SPACE.0, Self::TEXT_SPACE.1),
+            enabled: true,
+            focused: false,
+            formatter: None,
+            hovered: false,
+            id: WidgetId::unique(),
+            on_change: None,
             position,
-            slider_background_colour: Colour::LIGHTGRAY,
-            slider_box_colour: state_get_colour(StateColour::Text),
-            slider_box_dimensions: (30, 35),
+            preferred_dimensions: (width + Self::TEXT_SPACE.0, Self::TEXT_SPACE.1),
             slider_box_position: (position.0 + 10, position.1 + 7),
-            slider_dimensions: (width, 35),
-            slider_position: (position.0 + 10, position.1 + 7),
-            slider_text_colour: state_get_colour(StateColour::Text),
-            value: initial_value,
+            style: Theme::default().slider,
+            text_measurer: TextMeasurer::default(),
+            track: SliderTrack::new(min, max, position, width, Self::TEXT_SPACE),
+            value: min,
+            visible: true,
         };
 
-        s.set_position_from_value();
+        s.set_value(initial_value);
 
         s
     }
 
     fn set_position_from_value(&mut self) {
-        self.slider_box_position.0 = (((self.value - self.min as f32)
-            / (self.max - self.min) as f32)
-            * ((self.slider_position.0 + self.slider_dimensions.0) - self.slider_position.0) as f32
-            + self.slider_position.0 as f32) as i32;
+        self.slider_box_position = self.track.box_position(self.value);
     }
 
-    fn update_value(&mut self) {
-        let difference = (self.slider_box_position.0 + self.slider_box_dimensions.0)
-            - (self.slider_position.0 + self.slider_box_dimensions.0);
+    /// Sets the value from where the box is along the track, snapping the box
+    /// to the nearest step.
+    fn update_value(&mut self) { self.move_value(self.track.value_at(self.slider_box_position)); }
 
-        self.value = (difference as f32
-            / (self.slider_dimensions.0 - self.slider_box_dimensions.0) as f32)
-            * (self.max - self.min) as f32
-            + self.min as f32;
+    /// Shifts the `Slider` and all it's elements proportionally to the `new_x`.
+    pub fn move_x(&mut self, new_x: i32) { self.move_to((new_x, self.position.1)); }
+
+    /// Shifts the `Slider` and all it's elements so that it is placed at
+    /// `new_position`.
+    pub fn move_to(&mut self, new_position: Point) {
+        self.position = new_position;
+        self.track.layout(self.position, self.dimensions);
+        self.set_position_from_value();
     }
 
-    /// Shifts the `Slider` and all it's elements proportionally to the `new_x`.
-    pub fn move_x(&mut self, new_x: i32) {
-        self.position.0 = new_x;
-        let difference = self.slider_box_position.0 - self.slider_position.0;
-        self.slider_box_position.0 = difference + new_x + 10;
-        self.slider_position.0 = new_x + 10;
+    /// Returns the `WidgetId` of the `Slider`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `Slider` is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `Slider`.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        self
+    }
+
+    /// Returns whether or not the `Slider` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `Slider`. A disabled `Slider` is drawn in the
+    /// disabled colours of its style and ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    /// Sets the function used to measure the width of the readout, so that it
+    /// can be centred below the track of a vertical `Slider`.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+
+        self
     }
 
     /// Returns the value of the current `Slider`.
     pub fn get_value(&self) -> f32 { self.value }
 
+    /// Sets the value of the `Slider`, keeping it between the minimum and
+    /// maximum and rounding it to the nearest step.
+    pub fn set_value(&mut self, value: f32) -> &mut Self {
+        self.value = self.track.snap(value);
+        self.set_position_from_value();
+
+        self
+    }
+
+    /// Moves the value to `value` without the mouse, returning whether or not
+    /// it changed.
+    fn move_value(&mut self, value: f32) -> bool {
+        let changed = (value - self.value).abs() > f32::EPSILON;
+        self.value = value;
+        self.set_position_from_value();
+        self.changed |= changed;
+
+        changed
+    }
+
+    /// Moves the value `steps` steps, or `steps` twentieths of the track if
+    /// the `Slider` has no step, such as when the arrow keys are pressed
+    /// while it is focused. Returns whether or not the value changed.
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        self.adjustment = 0.0;
+
+        self.move_value(self.track.step_from(self.value, steps))
+    }
+
+    /// Moves the value `fraction` of the length of the track, such as while
+    /// an analog stick is pushed while it is focused. Movements that are too
+    /// small to reach the next step are added up until they do. Returns
+    /// whether or not the value changed.
+    pub fn adjust(&mut self, fraction: f32) -> bool {
+        self.adjustment += fraction;
+
+        let value = self.track.offset_from(self.value, self.adjustment);
+        let past_end = self.track.is_past_end(self.value, self.adjustment);
+        let changed = self.move_value(value);
+
+        // Anything pushed past either end is dropped, so that it does not
+        // have to be undone before the value moves back the other way.
+        if changed || past_end {
+            self.adjustment = 0.0;
+        }
+
+        changed
+    }
+
+    /// Returns whether or not the `Slider` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `Slider`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Returns the minimum and maximum values of the `Slider`.
+    pub fn get_range(&self) -> (f32, f32) { self.track.get_range() }
+
+    /// Sets the minimum and maximum values of the `Slider`, keeping its value
+    /// between them.
+    pub fn set_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.track.set_range(min, max);
+        self.set_value(self.value);
+
+        self
+    }
+
+    /// Returns the step that the value of the `Slider` is rounded to, if it
+    /// has one.
+    pub fn get_step(&self) -> Option<f32> { self.track.get_step() }
+
+    /// Rounds the value of the `Slider` to the nearest multiple of `step`
+    /// above the minimum, or lets it take any value if `step` is `None`.
+    /// Steps that are not above zero are ignored.
+    pub fn set_step(&mut self, step: Option<f32>) -> &mut Self {
+        self.track.set_step(step);
+        self.set_value(self.value);
+
+        self
+    }
+
+    /// Returns `true` if the value of the `Slider` changes logarithmically
+    /// along its track.
+    pub fn is_logarithmic(&self) -> bool { self.track.is_logarithmic() }
+
+    /// Makes the value change logarithmically along the track, so that each
+    /// part of the track multiplies the value by the same amount. This only
+    /// takes effect while the minimum and maximum are both above zero.
+    pub fn set_logarithmic(&mut self, logarithmic: bool) -> &mut Self {
+        self.track.set_logarithmic(logarithmic);
+        self.set_position_from_value();
+
+        self
+    }
+
+    /// Returns the direction that the track of the `Slider` runs in.
+    pub fn get_orientation(&self) -> Orientation { self.track.get_orientation() }
+
+    /// Runs the track of the `Slider` across if `orientation` is horizontal,
+    /// or up and down with the maximum at the top if it is vertical, keeping
+    /// the length of the track.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        if self.track.get_orientation() != orientation {
+            self.preferred_dimensions = self.track.set_orientation(orientation);
+            self.resize(self.preferred_dimensions);
+        }
+
+        self
+    }
+
+    /// Sets the function that turns the value into the text shown next to the
+    /// track, instead of showing it with two decimal places.
+    pub fn set_formatter(&mut self, formatter: ValueFormatter) -> &mut Self {
+        self.formatter = Some(formatter);
+
+        self
+    }
+
+    /// Returns the text shown next to the track for the current value.
+    pub fn get_value_text(&self) -> String {
+        match &self.formatter {
+            Some(formatter) => formatter(self.value),
+            None => format!("{:#.2}", self.value),
+        }
+    }
+
+    /// Gives the `Slider` its own `style`, which is used instead of the theme
+    /// of the `GuiHandler`, resizing it to fit the readout.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_text();
+
+        self
+    }
+
+    /// Styles the `Slider` using `theme`, unless it has been given its own
+    /// style with `set_style`, resizing it to fit the readout.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.slider.clone();
+            self.fit_text();
+        }
+    }
+
+    fn fit_text(&mut self) {
+        self.preferred_dimensions = self
+            .track
+            .fit_text_space(Self::TEXT_SPACE, self.style.font_size);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns the state the box of the `Slider` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            WidgetState::Disabled
+        } else if self.dragging {
+            WidgetState::Active
+        } else if self.hovered {
+            WidgetState::Hovered
+        } else {
+            WidgetState::Default
+        }
+    }
+
+    /// Returns the dimensions the `Slider` was created with, which layouts use
+    /// as the size the `Slider` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
     /// Resizes the slider to `new_dimensions` and resizes it's components
     /// proportionally.
     pub fn resize(&mut self, new_dimensions: Dimensions) {
         self.dimensions = new_dimensions;
-        self.slider_dimensions = (new_dimensions.0 - 120, new_dimensions.1 - 15);
-        self.slider_box_dimensions = (self.slider_box_dimensions.0, new_dimensions.1 - 15);
+        self.track.layout(self.position, self.dimensions);
+        self.set_position_from_value();
+    }
+
+    /// Sets the callback that is called with the new value of the `Slider`
+    /// whenever it changes.
+    pub fn set_on_change(&mut self, on_change: ValueCallback<T, f32>) -> &mut Self {
+        self.on_change = Some(on_change);
+
+        self
+    }
+
+    /// Moves the box to the cursor when the `Slider` is pressed, and keeps it
+    /// following the cursor until the button is released, even if the cursor
+    /// leaves the `Slider`. Returns whether or not the value changed.
+    pub fn handle_input(&mut self, input: &InputState) -> bool {
+        let previous_value = self.value;
+        let mouse_position = input.mouse_position;
+
+        if !input.is_button_down(PointerButton::Left) {
+            self.dragging = false;
+        }
+
+        if input.is_button_pressed(PointerButton::Left)
+            && is_inside(self.position, self.dimensions, mouse_position)
+        {
+            self.dragging = true;
+        }
+
+        if self.dragging {
+            self.slider_box_position = self.track.box_position_at(mouse_position);
+            self.update_value();
+        }
+
+        (self.value - previous_value).abs() > f32::EPSILON
+    }
+
+    /// Calls the `Slider`'s callback if its value changed during the last
+    /// update.
+    pub fn execute_callbacks(&mut self, state: &mut T) {
+        if std::mem::take(&mut self.changed) {
+            if let Some(on_change) = self.on_change.as_mut() {
+                on_change(state, self.value);
+            }
+        }
     }
 }
 
-impl GuiComponentBehaviour<()> for Slider {
+impl<T> GuiComponentBehaviour<bool> for Slider<T> {
     /// Draw `Slider` to screen.
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle) {
-        let mouse_position = (draw_handler.get_mouse_x(), draw_handler.get_mouse_y());
-        self.is_hovered(mouse_position);
-
-        draw_handler.draw_rectangle(
-            self.position.0,
-            self.position.1,
-            self.dimensions.0,
-            self.dimensions.1,
-            self.background_colour,
-        );
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        let text = self.get_value_text();
 
-        draw_handler.draw_text(
-            format!("{:#.2}", self.value).as_str(),
-            (self.position.0 + self.dimensions.0) - 105,
-            self.position.1 + 10,
-            32,
-            self.slider_text_colour,
+        draw_background(
+            renderer,
+            self.position,
+            self.dimensions,
+            &self.style,
+            resting_state(self.enabled),
         );
 
-        draw_handler.draw_rectangle(
-            self.slider_position.0,
-            self.slider_position.1,
-            self.slider_dimensions.0,
-            self.slider_dimensions.1,
-            self.slider_background_colour,
+        renderer.draw_text(
+            &text,
+            self.track.text_position(
+                self.position,
+                self.dimensions,
+                &text,
+                &self.style,
+                &self.text_measurer,
+            ),
+            self.style.font_size,
+            self.style.text.get(resting_state(self.enabled)),
         );
 
-        draw_handler.draw_rectangle(
-            self.slider_box_position.0,
-            self.slider_box_position.1,
-            self.slider_box_dimensions.0,
-            self.slider_box_dimensions.1,
-            self.slider_box_colour,
+        self.track
+            .draw(renderer, &self.style, resting_state(self.enabled));
+
+        renderer.draw_rectangle(
+            self.slider_box_position,
+            self.track.box_dimensions,
+            self.style.foreground.get(state),
         );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
     }
 
     /// Checks if the `Slider` is being hovered over.
     fn is_hovered(&mut self, mouse_position: Point) -> bool {
-        if is_inside(
+        self.hovered = is_inside(
             self.slider_box_position,
-            self.slider_box_dimensions,
+            self.track.box_dimensions,
             mouse_position,
-        ) {
-            self.slider_box_colour = state_get_colour(StateColour::Hovered);
-            true
-        } else {
-            self.slider_box_colour = state_get_colour(StateColour::Text);
-            false
-        }
+        );
+
+        self.hovered
     }
 
-    /// Checks if the `Slider` is clicked.
-    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) {
-        if (is_inside(
-            self.slider_box_position,
-            self.slider_box_dimensions,
-            mouse_position,
-        ) || (is_inside(self.position, self.dimensions, mouse_position)))
-            && is_clicked
-        {
-            self.slider_box_position = (
-                mouse_position.0 - self.slider_box_dimensions.0 / 2,
-                self.slider_box_position.1,
-            );
-
-            if (self.slider_box_position.0 + self.slider_box_dimensions.0)
-                > self.slider_position.0 + self.slider_dimensions.0
-            {
-                self.slider_box_position.0 = (self.slider_position.0 + self.slider_dimensions.0)
-                    - self.slider_box_dimensions.0;
-            } else if self.slider_box_position.0 < self.slider_position.0 {
-                self.slider_box_position.0 = self.slider_position.0;
-            }
+    /// Checks if the `Slider` is clicked, where `is_clicked` is whether or not
+    /// the left mouse button is being held down, returning whether or not its
+    /// value changed. As this cannot tell when the button was pressed, it
+    /// counts as pressed whenever the `Slider` is not already being dragged;
+    /// `handle_input` only starts dragging on the press itself.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
 
-            self.update_value();
+        match (is_clicked, self.dragging) {
+            (true, false) => input = input.with_button_pressed(PointerButton::Left),
+            (true, true) => input = input.with_button_down(PointerButton::Left),
+            (false, _) => (),
         }
+
+        self.handle_input(&input)
     }
 }
 
-impl FromIterator<DrawableType> for Vec<Slider> {
-    fn from_iter<T: IntoIterator<Item = DrawableType>>(iter: T) -> Self {
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<Slider<T>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
         let mut c = Vec::new();
 
         for i in iter {
@@ -187,3 +687,101 @@ impl FromIterator<DrawableType> for Vec<Slider> {
         c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `Slider` from 0 to 100 with a track 100 pixels long starting at
+    /// x = 10, along which its 30 pixel wide box travels 70 pixels.
+    fn slider() -> Slider<()> { Slider::new(0.0, 100.0, 0.0, (0, 0), 100) }
+
+    fn press(position: Point) -> InputState {
+        InputState::new()
+            .with_mouse_position(position)
+            .with_button_pressed(PointerButton::Left)
+    }
+
+    fn hold(position: Point) -> InputState {
+        InputState::new()
+            .with_mouse_position(position)
+            .with_button_down(PointerButton::Left)
+    }
+
+    #[test]
+    fn pressing_drags_the_box_until_the_button_is_released() {
+        let mut slider = slider();
+
+        assert!(slider.handle_input(&press((60, 25))));
+        assert_eq!(slider.get_value(), 50.0);
+        assert_eq!(slider.get_state(), WidgetState::Active);
+
+        slider.handle_input(&hold((500, 25)));
+        assert_eq!(slider.get_value(), 100.0);
+
+        slider.handle_input(&InputState::new().with_mouse_position((10, 25)));
+        assert_eq!(slider.get_value(), 100.0);
+        assert!(slider.changed);
+    }
+
+    #[test]
+    fn sweeping_onto_the_slider_while_held_does_not_grab_it() {
+        let mut slider = slider();
+
+        assert!(!slider.handle_input(&press((300, 25))));
+        assert!(!slider.handle_input(&hold((60, 25))));
+        assert_eq!(slider.get_value(), 0.0);
+    }
+
+    #[test]
+    fn values_snap_to_the_step() {
+        let mut slider = slider();
+        slider.set_step(Some(25.0));
+
+        slider.handle_input(&press((45, 25)));
+        assert_eq!(slider.get_value(), 25.0);
+
+        slider.set_value(60.0);
+        assert_eq!(slider.get_value(), 50.0);
+
+        assert!(slider.step_by(1));
+        assert_eq!(slider.get_value(), 75.0);
+        assert!(slider.step_by(4));
+        assert_eq!(slider.get_value(), 100.0);
+        assert!(!slider.step_by(1));
+    }
+
+    #[test]
+    fn a_logarithmic_slider_multiplies_the_value_along_the_track() {
+        let mut slider = Slider::<()>::new(1.0, 100.0, 1.0, (0, 0), 100);
+        slider.set_logarithmic(true);
+        assert!(slider.is_logarithmic());
+
+        slider.handle_input(&press((60, 25)));
+        assert!((slider.get_value() - 10.0).abs() < 0.01);
+
+        slider.set_value(100.0);
+        assert_eq!(slider.slider_box_position, (80, 7));
+
+        // A range that reaches zero has no logarithm, so it stays linear.
+        slider.set_range(0.0, 100.0);
+        assert!(!slider.is_logarithmic());
+    }
+
+    #[test]
+    fn a_vertical_slider_has_its_maximum_at_the_top() {
+        let mut slider = slider();
+        slider.set_orientation(Orientation::Vertical);
+        assert_eq!(slider.get_orientation(), Orientation::Vertical);
+        assert_eq!(slider.dimensions, (120, 160));
+
+        slider.handle_input(&press((60, 10)));
+        assert_eq!(slider.get_value(), 100.0);
+
+        slider.handle_input(&hold((60, 120)));
+        assert_eq!(slider.get_value(), 0.0);
+
+        assert!(slider.step_by(1));
+        assert_eq!(slider.get_value(), 5.0);
+    }
+}
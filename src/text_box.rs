@@ -0,0 +1,738 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, InputState, Key, Point, PointerButton, Renderer, TextMeasurer, Theme,
+        ValueCallback, WidgetId, WidgetState, WidgetStyle,
+    },
+};
+use std::iter::FromIterator;
+
+/// A single line of editable text, such as a player name or a server address.
+pub struct TextBox<T> {
+    caret: usize,
+    changed: bool,
+    custom_style: bool,
+    dragging: bool,
+    editing: bool,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    max_length: Option<usize>,
+    on_submit: Option<ValueCallback<T, String>>,
+    password: bool,
+    placeholder: String,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    scroll: i32,
+    selection_anchor: Option<usize>,
+    style: WidgetStyle,
+    submitted: bool,
+    text: String,
+    text_measurer: TextMeasurer,
+    visible: bool,
+}
+
+impl<T> TextBox<T> {
+    /// The character drawn in place of each character of a password.
+    pub const PASSWORD_MASK: char = '*';
+
+    /// Create a new, empty `TextBox` that is `width` pixels wide, with a
+    /// height that fits its font.
+    pub fn new(position: Point, width: i32) -> Self {
+        let style = Theme::default().text_box;
+        let dimensions = (width, style.fit_text(0).1);
+
+        Self {
+            caret: 0,
+            changed: false,
+            custom_style: false,
+            dimensions,
+            dragging: false,
+            editing: false,
+            enabled: true,
+            focused: false,
+            hovered: false,
+            id: WidgetId::unique(),
+            max_length: None,
+            on_submit: None,
+            password: false,
+            placeholder: String::new(),
+            position,
+            preferred_dimensions: dimensions,
+            scroll: 0,
+            selection_anchor: None,
+            style,
+            submitted: false,
+            text: String::new(),
+            text_measurer: TextMeasurer::default(),
+            visible: true,
+        }
+    }
+
+    /// Returns the `WidgetId` of the `TextBox`.
+    pub fn get_id(&self) -> WidgetId { self.id }
+
+    /// Returns whether or not the `TextBox` is drawn and responds to input.
+    pub fn is_visible(&self) -> bool { self.visible }
+
+    /// Shows or hides the `TextBox`.
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+
+        if !visible {
+            self.set_focused(false);
+        }
+
+        self
+    }
+
+    /// Returns whether or not the `TextBox` responds to input.
+    pub fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Enables or disables the `TextBox`, which stops it from being typed into.
+    /// A disabled `TextBox` is drawn in the disabled colours of its style and
+    /// ignores input.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+
+        if !enabled {
+            self.set_focused(false);
+        }
+
+        self
+    }
+
+    /// Sets the function used to measure the width of the text, which places
+    /// the caret and decides when the text scrolls.
+    pub fn set_text_measurer(&mut self, text_measurer: TextMeasurer) -> &mut Self {
+        self.text_measurer = text_measurer;
+
+        self
+    }
+
+    /// Returns the text in the `TextBox`.
+    pub fn get_text(&self) -> &str { &self.text }
+
+    /// Replaces the text in the `TextBox`, cutting it down to the maximum
+    /// length and moving the caret to the end of it.
+    pub fn set_text(&mut self, text: &str) -> &mut Self {
+        self.text = match self.max_length {
+            Some(max_length) => text.chars().take(max_length).collect(),
+            None => text.to_string(),
+        };
+        self.caret = self.len();
+        self.selection_anchor = None;
+        self.scroll_to_caret();
+
+        self
+    }
+
+    /// Sets the text that is shown while the `TextBox` is empty.
+    pub fn set_placeholder(&mut self, placeholder: &str) -> &mut Self {
+        self.placeholder = placeholder.to_string();
+
+        self
+    }
+
+    /// Limits the number of characters that can be entered, or removes the
+    /// limit if `max_length` is `None`. Text that is already longer than the
+    /// limit is cut down to fit.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) -> &mut Self {
+        self.max_length = max_length;
+        let text = self.text.clone();
+        self.set_text(&text);
+
+        self
+    }
+
+    /// Draws every character as `TextBox::PASSWORD_MASK` if `password` is
+    /// `true`.
+    pub fn set_password(&mut self, password: bool) -> &mut Self {
+        self.password = password;
+        self.scroll_to_caret();
+
+        self
+    }
+
+    /// Returns whether or not the `TextBox` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus. Focusing the `TextBox`
+    /// does not let it be typed into, and taking focus away stops it being
+    /// typed into.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        if !focused {
+            self.set_editing(false);
+        }
+
+        self
+    }
+
+    /// Returns whether or not the `TextBox` is being typed into.
+    pub fn is_editing(&self) -> bool { self.editing }
+
+    /// Starts or stops the `TextBox` being typed into, focusing it when
+    /// editing starts. Stopping clears the selection.
+    pub fn set_editing(&mut self, editing: bool) -> &mut Self {
+        self.editing = editing;
+
+        if editing {
+            self.focused = true;
+        } else {
+            self.selection_anchor = None;
+            self.dragging = false;
+        }
+
+        self
+    }
+
+    /// Returns the position of the caret, counted in characters.
+    pub fn get_caret(&self) -> usize { self.caret }
+
+    /// Returns the selected range of characters, if any are selected.
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        match self.selection_anchor {
+            Some(anchor) if anchor != self.caret =>
+                Some((anchor.min(self.caret), anchor.max(self.caret))),
+            _ => None,
+        }
+    }
+
+    /// Returns the selected text, if any is selected.
+    pub fn get_selected_text(&self) -> Option<&str> {
+        self.get_selection()
+            .map(|(start, end)| &self.text[self.byte_index(start)..self.byte_index(end)])
+    }
+
+    /// Returns `true` if the text changed during the last update.
+    pub fn was_changed(&self) -> bool { self.changed }
+
+    /// Returns `true` if Enter was pressed during the last update.
+    pub fn was_submitted(&self) -> bool { self.submitted }
+
+    /// Gives the `TextBox` its own `style`, which is used instead of the theme
+    /// of the `GuiHandler`, resizing it to fit.
+    pub fn set_style(&mut self, style: WidgetStyle) -> &mut Self {
+        self.style = style;
+        self.custom_style = true;
+        self.fit_font();
+
+        self
+    }
+
+    /// Styles the `TextBox` using `theme`, unless it has been given its own
+    /// style with `set_style`, resizing it to fit.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        if !self.custom_style {
+            self.style = theme.text_box.clone();
+            self.fit_font();
+        }
+    }
+
+    fn fit_font(&mut self) {
+        self.preferred_dimensions.1 = self.style.fit_text(0).1;
+        self.resize((self.dimensions.0, self.preferred_dimensions.1));
+    }
+
+    /// Returns the state the `TextBox` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match (self.focused, self.hovered) {
+            (true, _) => WidgetState::Focused,
+            (false, true) => WidgetState::Hovered,
+            (false, false) => WidgetState::Default,
+        }
+    }
+
+    /// Returns the width the `TextBox` was created with and the height of its
+    /// font, which layouts use as the size the `TextBox` would like to be.
+    pub fn get_preferred_dimensions(&self) -> Dimensions { self.preferred_dimensions }
+
+    /// Resizes the `TextBox` to the given `new_dimensions`.
+    pub fn resize(&mut self, new_dimensions: Dimensions) {
+        self.dimensions = new_dimensions;
+        self.scroll_to_caret();
+    }
+
+    /// Sets the callback that is called with the text of the `TextBox` when
+    /// Enter is pressed while it is being typed into.
+    pub fn set_on_submit(&mut self, on_submit: ValueCallback<T, String>) -> &mut Self {
+        self.on_submit = Some(on_submit);
+
+        self
+    }
+
+    /// Calls the `TextBox`'s callback if it was submitted during the last
+    /// update.
+    pub fn execute_callbacks(&mut self, state: &mut T) {
+        if std::mem::take(&mut self.submitted) {
+            if let Some(on_submit) = self.on_submit.as_mut() {
+                on_submit(state, self.text.clone());
+            }
+        }
+    }
+
+    /// Moves the caret, selects text and edits the text of the `TextBox`
+    /// using the keys and characters in `input`. Nothing happens unless the
+    /// `TextBox` is being typed into.
+    pub fn handle_keys(&mut self, input: &InputState) {
+        self.changed = false;
+        self.submitted = false;
+
+        if !self.editing {
+            return;
+        }
+
+        let shift = input.is_key_down(Key::LeftShift) || input.is_key_down(Key::RightShift);
+
+        for key in input.keys_pressed.iter() {
+            match key {
+                Key::Left => {
+                    let caret = match (self.get_selection(), shift) {
+                        (Some((start, _)), false) => start,
+                        _ => self.caret.saturating_sub(1),
+                    };
+                    self.move_caret(caret, shift);
+                },
+                Key::Right => {
+                    let caret = match (self.get_selection(), shift) {
+                        (Some((_, end)), false) => end,
+                        _ => (self.caret + 1).min(self.len()),
+                    };
+                    self.move_caret(caret, shift);
+                },
+                Key::Home => self.move_caret(0, shift),
+                Key::End => self.move_caret(self.len(), shift),
+                Key::Backspace => self.delete_backward(),
+                Key::Delete => self.delete_forward(),
+                Key::Enter => self.submitted = true,
+                Key::Escape => {
+                    self.set_editing(false);
+                    return;
+                },
+                _ => (),
+            }
+        }
+
+        for c in input.characters.iter().filter(|c| !c.is_control()) {
+            self.insert_char(*c);
+        }
+
+        self.scroll_to_caret();
+    }
+
+    fn len(&self) -> usize { self.text.chars().count() }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    fn move_caret(&mut self, caret: usize, select: bool) {
+        match (select, self.selection_anchor) {
+            (true, None) => self.selection_anchor = Some(self.caret),
+            (false, _) => self.selection_anchor = None,
+            (true, Some(_)) => (),
+        }
+
+        self.caret = caret;
+    }
+
+    fn remove_char(&mut self, char_index: usize) {
+        self.text.remove(self.byte_index(char_index));
+        self.selection_anchor = None;
+        self.changed = true;
+    }
+
+    /// Removes the selected text, returning `false` if nothing was selected.
+    fn delete_selection(&mut self) -> bool {
+        let (start, end) = match self.get_selection() {
+            Some(selection) => selection,
+            None => return false,
+        };
+
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.text.replace_range(range, "");
+        self.caret = start;
+        self.selection_anchor = None;
+        self.changed = true;
+
+        true
+    }
+
+    fn delete_backward(&mut self) {
+        if !self.delete_selection() && self.caret > 0 {
+            self.caret -= 1;
+            self.remove_char(self.caret);
+        }
+    }
+
+    fn delete_forward(&mut self) {
+        if !self.delete_selection() && self.caret < self.len() {
+            self.remove_char(self.caret);
+        }
+    }
+
+    fn insert_char(&mut self, c: char) {
+        self.delete_selection();
+
+        if self.max_length.is_some_and(|max| self.len() >= max) {
+            return;
+        }
+
+        let index = self.byte_index(self.caret);
+        self.text.insert(index, c);
+        self.caret += 1;
+        self.selection_anchor = None;
+        self.changed = true;
+    }
+
+    /// Returns the text as it is drawn, which is masked for passwords.
+    fn display_text(&self) -> String {
+        match self.password {
+            true => Self::PASSWORD_MASK.to_string().repeat(self.len()),
+            false => self.text.clone(),
+        }
+    }
+
+    /// Returns the width of the first `char_index` characters of the text as
+    /// it is drawn.
+    fn measure_to(&self, display_text: &str, char_index: usize) -> i32 {
+        let end = display_text
+            .char_indices()
+            .nth(char_index)
+            .map_or(display_text.len(), |(i, _)| i);
+
+        self.text_measurer
+            .measure(&display_text[..end], self.style.font_size)
+    }
+
+    fn inner_width(&self) -> i32 { (self.dimensions.0 - self.style.padding.0 * 2).max(0) }
+
+    /// Scrolls the text horizontally so that the caret is inside the
+    /// `TextBox`, without leaving empty space after the end of the text.
+    fn scroll_to_caret(&mut self) {
+        let display_text = self.display_text();
+        let caret_x = self.measure_to(&display_text, self.caret);
+        let text_width = self
+            .text_measurer
+            .measure(&display_text, self.style.font_size);
+        let inner_width = self.inner_width();
+
+        if caret_x - self.scroll > inner_width {
+            self.scroll = caret_x - inner_width;
+        }
+        if caret_x < self.scroll {
+            self.scroll = caret_x;
+        }
+
+        self.scroll = self.scroll.min((text_width - inner_width).max(0));
+    }
+
+    /// Returns the character index closest to the horizontal position `x` on
+    /// the screen.
+    fn char_index_at(&self, x: i32) -> usize {
+        let display_text = self.display_text();
+        let x = x - (self.position.0 + self.style.padding.0) + self.scroll;
+
+        (0..=self.len())
+            .min_by_key(|&i| (self.measure_to(&display_text, i) - x).abs())
+            .unwrap_or(0)
+    }
+
+    /// Starts typing into the `TextBox` when it is clicked and unfocuses it
+    /// when anything else is clicked, placing the caret under the mouse and
+    /// selecting text while the mouse is dragged.
+    pub fn handle_mouse(&mut self, input: &InputState) {
+        let mouse_position = input.mouse_position;
+
+        if input.is_button_pressed(PointerButton::Left) {
+            let inside = is_inside(self.position, self.dimensions, mouse_position);
+            self.set_focused(inside);
+            self.set_editing(inside);
+
+            if inside {
+                self.caret = self.char_index_at(mouse_position.0);
+                self.selection_anchor = Some(self.caret);
+                self.dragging = true;
+            }
+        } else if self.dragging && input.is_button_down(PointerButton::Left) {
+            self.caret = self.char_index_at(mouse_position.0);
+            self.scroll_to_caret();
+        } else {
+            self.dragging = false;
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for TextBox<T> {
+    /// Draw `TextBox` to screen.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        let display_text = self.display_text();
+        let text_position = (
+            self.position.0 + self.style.padding.0,
+            self.position.1 + (self.dimensions.1 - self.style.font_size) / 2,
+        );
+        let scrolled_x = text_position.0 - self.scroll;
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        renderer.push_clip(
+            (text_position.0, self.position.1),
+            (self.inner_width(), self.dimensions.1),
+        );
+
+        if let Some((start, end)) = self.get_selection() {
+            let start_x = self.measure_to(&display_text, start);
+            let end_x = self.measure_to(&display_text, end);
+
+            renderer.draw_rectangle(
+                (scrolled_x + start_x, text_position.1),
+                (end_x - start_x, self.style.font_size),
+                self.style.accent.get(state),
+            );
+        }
+
+        if display_text.is_empty() {
+            renderer.draw_text(
+                &self.placeholder,
+                text_position,
+                self.style.font_size,
+                self.style.text.get(WidgetState::Disabled),
+            );
+        } else {
+            renderer.draw_text(
+                &display_text,
+                (scrolled_x, text_position.1),
+                self.style.font_size,
+                self.style.text.get(state),
+            );
+        }
+
+        if self.editing {
+            let caret_x = scrolled_x + self.measure_to(&display_text, self.caret);
+
+            renderer.draw_line(
+                (caret_x, text_position.1),
+                (caret_x, text_position.1 + self.style.font_size),
+                2.0,
+                self.style.foreground.get(state),
+            );
+        }
+
+        renderer.pop_clip();
+    }
+
+    /// Checks if the `TextBox` is being hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// Checks if the `TextBox` was clicked, returning whether or not it is
+    /// being typed into afterwards.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let mut input = InputState::new().with_mouse_position(mouse_position);
+        if is_clicked {
+            input = input.with_button_pressed(PointerButton::Left);
+        }
+
+        self.handle_mouse(&input);
+
+        self.editing
+    }
+}
+
+impl<T, E> FromIterator<DrawableType<T, E>> for Vec<TextBox<T>> {
+    fn from_iter<I: IntoIterator<Item = DrawableType<T, E>>>(iter: I) -> Self {
+        let mut c = Vec::new();
+
+        for i in iter {
+            if let DrawableType::TextBox(t) = i {
+                c.push(t)
+            }
+        }
+
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{measure_text_monospace, DrawCommand, RecordingRenderer};
+
+    /// A `TextBox` 100 pixels wide, with 80 pixels inside of its padding, or
+    /// 8 characters of its monospaced font, that has been clicked into.
+    fn text_box(text: &str) -> TextBox<()> {
+        let mut text_box = TextBox::new((0, 0), 100);
+        text_box.set_text_measurer(TextMeasurer::new(measure_text_monospace));
+        text_box.set_text(text);
+        text_box.handle_mouse(
+            &InputState::new()
+                .with_mouse_position((95, 5))
+                .with_button_pressed(PointerButton::Left),
+        );
+
+        text_box
+    }
+
+    fn press(text_box: &mut TextBox<()>, key: Key) {
+        text_box.handle_keys(&InputState::new().with_key_pressed(key));
+    }
+
+    fn shift_press(text_box: &mut TextBox<()>, key: Key) {
+        text_box.handle_keys(
+            &InputState::new()
+                .with_key_down(Key::LeftShift)
+                .with_key_pressed(key),
+        );
+    }
+
+    fn type_text(text_box: &mut TextBox<()>, text: &str) {
+        text_box.handle_keys(&InputState::new().with_characters(text));
+    }
+
+    #[test]
+    fn caret_moves_with_the_arrow_home_and_end_keys() {
+        let mut text_box = text_box("hello");
+        assert_eq!(text_box.get_caret(), 5);
+
+        press(&mut text_box, Key::Left);
+        press(&mut text_box, Key::Left);
+        assert_eq!(text_box.get_caret(), 3);
+
+        press(&mut text_box, Key::Home);
+        press(&mut text_box, Key::Left);
+        assert_eq!(text_box.get_caret(), 0);
+
+        press(&mut text_box, Key::End);
+        press(&mut text_box, Key::Right);
+        assert_eq!(text_box.get_caret(), 5);
+        assert_eq!(text_box.get_selection(), None);
+    }
+
+    #[test]
+    fn shift_selects_and_typing_replaces_the_selection() {
+        let mut text_box = text_box("hello world");
+
+        shift_press(&mut text_box, Key::Left);
+        shift_press(&mut text_box, Key::Left);
+        shift_press(&mut text_box, Key::Left);
+        shift_press(&mut text_box, Key::Left);
+        shift_press(&mut text_box, Key::Left);
+        assert_eq!(text_box.get_selection(), Some((6, 11)));
+        assert_eq!(text_box.get_selected_text(), Some("world"));
+
+        type_text(&mut text_box, "there");
+        assert_eq!(text_box.get_text(), "hello there");
+        assert_eq!(text_box.get_selection(), None);
+        assert!(text_box.was_changed());
+
+        shift_press(&mut text_box, Key::Home);
+        press(&mut text_box, Key::Right);
+        assert_eq!(text_box.get_caret(), 11);
+        assert_eq!(text_box.get_selection(), None);
+
+        shift_press(&mut text_box, Key::Home);
+        press(&mut text_box, Key::Backspace);
+        assert_eq!(text_box.get_text(), "");
+    }
+
+    #[test]
+    fn edits_multi_byte_text_by_character() {
+        let mut text_box = text_box("héllo wörld");
+        assert_eq!(text_box.byte_index(2), 3);
+        assert_eq!(text_box.byte_index(11), text_box.get_text().len());
+        assert_eq!(text_box.byte_index(20), text_box.get_text().len());
+
+        press(&mut text_box, Key::Home);
+        press(&mut text_box, Key::Right);
+        shift_press(&mut text_box, Key::Right);
+        assert_eq!(text_box.get_selected_text(), Some("é"));
+
+        type_text(&mut text_box, "ë");
+        press(&mut text_box, Key::Delete);
+        assert_eq!(text_box.get_text(), "hëlo wörld");
+
+        press(&mut text_box, Key::End);
+        press(&mut text_box, Key::Left);
+        press(&mut text_box, Key::Left);
+        press(&mut text_box, Key::Left);
+        press(&mut text_box, Key::Backspace);
+        assert_eq!(text_box.get_text(), "hëlo wrld");
+        assert_eq!(text_box.get_caret(), 6);
+    }
+
+    #[test]
+    fn max_length_limits_the_number_of_characters() {
+        let mut text_box = text_box("héllo");
+        text_box.set_max_length(Some(3));
+        assert_eq!(text_box.get_text(), "hél");
+
+        type_text(&mut text_box, "abc");
+        assert_eq!(text_box.get_text(), "hél");
+        assert!(!text_box.was_changed());
+
+        shift_press(&mut text_box, Key::Left);
+        type_text(&mut text_box, "ö");
+        assert_eq!(text_box.get_text(), "héö");
+
+        text_box.set_max_length(None);
+        type_text(&mut text_box, "!");
+        assert_eq!(text_box.get_text(), "héö!");
+    }
+
+    #[test]
+    fn passwords_are_drawn_masked() {
+        let mut text_box = text_box("sécret");
+        text_box.set_password(true);
+        assert_eq!(text_box.display_text(), "******");
+
+        let mut renderer = RecordingRenderer::new();
+        text_box.draw(&mut renderer);
+        let drawn: Vec<&str> = renderer
+            .display_list()
+            .commands
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(drawn, vec!["******"]);
+        assert_eq!(text_box.get_text(), "sécret");
+    }
+
+    #[test]
+    fn scrolls_to_keep_the_caret_inside() {
+        let mut text_box = text_box("abcdefghijkl");
+        assert_eq!(text_box.scroll, 40);
+
+        press(&mut text_box, Key::Home);
+        assert_eq!(text_box.scroll, 0);
+
+        press(&mut text_box, Key::End);
+        assert_eq!(text_box.scroll, 40);
+
+        press(&mut text_box, Key::Backspace);
+        press(&mut text_box, Key::Backspace);
+        assert_eq!(text_box.scroll, 20);
+
+        text_box.resize((200, text_box.dimensions.1));
+        assert_eq!(text_box.scroll, 0);
+    }
+}
@@ -0,0 +1,384 @@
+use crate::prelude::{Colour, StateColours, Theme, WidgetStyle};
+use std::{fs, path::Path};
+use toml::{value::Table, Value};
+
+/// The sections of a theme file that style a single kind of component.
+const WIDGET_SECTIONS: [&str; 12] = [
+    "button",
+    "checkbox",
+    "combo_box",
+    "dropdown",
+    "label",
+    "progress_bar",
+    "radio_group",
+    "scroll_bar",
+    "scroll_panel",
+    "slider",
+    "text_box",
+    "toggle",
+];
+
+/// The keys that may be used in the `[style]` section and in each widget
+/// section.
+const STYLE_KEYS: [&str; 9] = [
+    "accent",
+    "background",
+    "border",
+    "border_width",
+    "corner_radius",
+    "font_size",
+    "foreground",
+    "padding",
+    "text",
+];
+
+/// The keys that may be used to give a colour to a single `WidgetState`.
+const STATE_KEYS: [&str; 5] = ["default", "hovered", "active", "disabled", "focused"];
+
+impl Theme {
+    /// Reads a `Theme` from a TOML file at `path`. See `Theme::from_toml` for
+    /// the format of the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read theme file {}: {}", path.display(), e))?;
+
+        Self::from_toml(&source)
+            .map_err(|e| format!("Invalid theme file {}: {}", path.display(), e))
+    }
+
+    /// Reads a `Theme` from TOML `source`, such as:
+    ///
+    /// ```toml
+    /// base = "light"            # the theme to start from, "dark" or "light"
+    /// font = "assets/font.ttf"
+    ///
+    /// [style]                   # changes to every kind of component
+    /// padding = [12, 10]
+    /// corner_radius = 4
+    ///
+    /// [button]                  # changes to buttons only
+    /// background = { default = "#3060c0", hovered = "#4070d0" }
+    /// text = "#ffffffff"
+    /// ```
+    ///
+    /// Colours are written as `"#rrggbb"`, `"#rrggbbaa"`, `[r, g, b]` or
+    /// `[r, g, b, a]`, where colours without an alpha are opaque, either once
+    /// for every state or as a table of the states to change.
+    /// The sections for components are `button`, `checkbox`, `combo_box`,
+    /// `dropdown`, `label`, `progress_bar`, `radio_group`, `scroll_bar`,
+    /// `scroll_panel`, `slider`, `text_box` and `toggle`. Anything that is
+    /// left out keeps the value of the base theme.
+    pub fn from_toml(source: &str) -> Result<Self, String> {
+        let table: Table = toml::from_str(source).map_err(|e| e.to_string())?;
+
+        let mut theme = match table.get("base") {
+            None => Theme::default(),
+            Some(Value::String(base)) if base == "dark" => Theme::dark(),
+            Some(Value::String(base)) if base == "light" => Theme::light(),
+            Some(other) =>
+                return Err(format!(
+                    "`base` must be \"dark\" or \"light\", found {}",
+                    other
+                )),
+        };
+
+        for (key, value) in table.iter() {
+            match key.as_str() {
+                "base" => {},
+                "font" => match value {
+                    Value::String(font) => theme.font = Some(font.clone()),
+                    _ => return Err(format!("`font` must be a path, found {}", value)),
+                },
+                "style" => {
+                    let section = get_section(key, value)?;
+
+                    for style in theme.styles_mut() {
+                        apply_style(style, key, section)?;
+                    }
+                },
+                key if WIDGET_SECTIONS.contains(&key) => {},
+                _ =>
+                    return Err(format!(
+                        "Unknown key `{}`, expected one of `base`, `font`, `style`, {}",
+                        key,
+                        list_keys(&WIDGET_SECTIONS)
+                    )),
+            }
+        }
+
+        // Sections for single kinds of components are applied after `[style]`
+        // so that they always override it.
+        for &name in WIDGET_SECTIONS.iter() {
+            if let Some(value) = table.get(name) {
+                let section = get_section(name, value)?;
+                let style = match name {
+                    "button" => &mut theme.button,
+                    "checkbox" => &mut theme.checkbox,
+                    "combo_box" => &mut theme.combo_box,
+                    "dropdown" => &mut theme.dropdown,
+                    "label" => &mut theme.label,
+                    "progress_bar" => &mut theme.progress_bar,
+                    "radio_group" => &mut theme.radio_group,
+                    "scroll_bar" => &mut theme.scroll_bar,
+                    "scroll_panel" => &mut theme.scroll_panel,
+                    "slider" => &mut theme.slider,
+                    "text_box" => &mut theme.text_box,
+                    _ => &mut theme.toggle,
+                };
+
+                apply_style(style, name, section)?;
+            }
+        }
+
+        Ok(theme)
+    }
+
+    fn styles_mut(&mut self) -> Vec<&mut WidgetStyle> {
+        vec![
+            &mut self.button,
+            &mut self.checkbox,
+            &mut self.combo_box,
+            &mut self.dropdown,
+            &mut self.label,
+            &mut self.progress_bar,
+            &mut self.radio_group,
+            &mut self.scroll_bar,
+            &mut self.scroll_panel,
+            &mut self.slider,
+            &mut self.text_box,
+            &mut self.toggle,
+        ]
+    }
+}
+
+fn list_keys(keys: &[&str]) -> String {
+    keys.iter()
+        .map(|key| format!("`{}`", key))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn get_section<'a>(name: &str, value: &'a Value) -> Result<&'a Table, String> {
+    value
+        .as_table()
+        .ok_or_else(|| format!("`{}` must be a section, found {}", name, value))
+}
+
+/// Changes `style` to match the keys in `section`, where `name` is the name of
+/// the section used in errors.
+fn apply_style(style: &mut WidgetStyle, name: &str, section: &Table) -> Result<(), String> {
+    for (key, value) in section.iter() {
+        let path = format!("{}.{}", name, key);
+
+        match key.as_str() {
+            "accent" => parse_state_colours(&mut style.accent, &path, value)?,
+            "background" => parse_state_colours(&mut style.background, &path, value)?,
+            "border" => parse_state_colours(&mut style.border, &path, value)?,
+            "foreground" => parse_state_colours(&mut style.foreground, &path, value)?,
+            "text" => parse_state_colours(&mut style.text, &path, value)?,
+            "border_width" => style.border_width = parse_length(&path, value)?,
+            "corner_radius" => style.corner_radius = parse_length(&path, value)?,
+            "font_size" => style.font_size = parse_length(&path, value)?,
+            "padding" =>
+                style.padding = match value.as_array().map(|a| a.as_slice()) {
+                    Some([x, y]) => (parse_length(&path, x)?, parse_length(&path, y)?),
+                    _ =>
+                        return Err(format!(
+                            "`{}` must be an array of two lengths, found {}",
+                            path, value
+                        )),
+                },
+            _ =>
+                return Err(format!(
+                    "Unknown key `{}`, expected one of {}",
+                    path,
+                    list_keys(&STYLE_KEYS)
+                )),
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_length(path: &str, value: &Value) -> Result<i32, String> {
+    match value.as_integer() {
+        Some(length) if (0..=i32::MAX as i64).contains(&length) => Ok(length as i32),
+        _ => Err(format!(
+            "`{}` must be a whole number of at least 0, found {}",
+            path, value
+        )),
+    }
+}
+
+/// Changes `colours` to match `value`, which is either a single colour for
+/// every state or a table of colours for some of the states.
+fn parse_state_colours(
+    colours: &mut StateColours,
+    path: &str,
+    value: &Value,
+) -> Result<(), String> {
+    let states = match value.as_table() {
+        Some(states) => states,
+        None => {
+            *colours = StateColours::uniform(parse_colour(path, value)?);
+            return Ok(());
+        },
+    };
+
+    for (state, value) in states.iter() {
+        let path = format!("{}.{}", path, state);
+        let colour = match state.as_str() {
+            "default" => &mut colours.default,
+            "hovered" => &mut colours.hovered,
+            "active" => &mut colours.active,
+            "disabled" => &mut colours.disabled,
+            "focused" => &mut colours.focused,
+            _ =>
+                return Err(format!(
+                    "Unknown state `{}`, expected one of {}",
+                    path,
+                    list_keys(&STATE_KEYS)
+                )),
+        };
+
+        *colour = parse_colour(&path, value)?;
+    }
+
+    Ok(())
+}
+
+fn parse_colour(path: &str, value: &Value) -> Result<Colour, String> {
+    let error = || {
+        format!(
+            "`{}` must be a colour such as \"#rrggbb\", \"#rrggbbaa\", [r, g, b] or [r, g, b, a], \
+             found {}",
+            path, value
+        )
+    };
+
+    let channels = match value {
+        Value::String(hex) => {
+            let digits = hex.strip_prefix('#').ok_or_else(error)?;
+
+            if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+                return Err(error());
+            }
+
+            (0..digits.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| error()))
+                .collect::<Result<Vec<_>, _>>()?
+        },
+        Value::Array(values) if values.len() == 3 || values.len() == 4 => values
+            .iter()
+            .map(|v| match v.as_integer() {
+                Some(channel) if (0..=255).contains(&channel) => Ok(channel as u8),
+                _ => Err(error()),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(error()),
+    };
+
+    Ok(Colour::new(
+        channels[0],
+        channels[1],
+        channels[2],
+        channels.get(3).copied().unwrap_or(255),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_empty_file_is_the_default_theme() {
+        assert_eq!(Theme::from_toml(""), Ok(Theme::default()));
+        assert_eq!(Theme::from_toml("base = \"light\""), Ok(Theme::light()));
+    }
+
+    #[test]
+    fn colours_are_read_in_every_format() {
+        let theme = Theme::from_toml(
+            r##"
+            [button]
+            background = "#102030"
+            foreground = "#10203040"
+            accent = [16, 32, 48]
+            text = [16, 32, 48, 64]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            theme.button.background.default,
+            Colour::new(16, 32, 48, 255)
+        );
+        assert_eq!(theme.button.foreground.default, Colour::new(16, 32, 48, 64));
+        assert_eq!(theme.button.accent.default, Colour::new(16, 32, 48, 255));
+        assert_eq!(theme.button.text.disabled, Colour::new(16, 32, 48, 64));
+    }
+
+    #[test]
+    fn a_table_of_states_only_changes_those_states() {
+        let theme = Theme::from_toml("[button]\nbackground = { hovered = \"#ff0000\" }").unwrap();
+        let dark = Theme::dark().button.background;
+
+        assert_eq!(theme.button.background.hovered, Colour::new(255, 0, 0, 255));
+        assert_eq!(theme.button.background.default, dark.default);
+        assert_eq!(theme.button.background.active, dark.active);
+    }
+
+    #[test]
+    fn widget_sections_override_the_style_section() {
+        let theme = Theme::from_toml(
+            r#"
+            [toggle]
+            padding = [1, 2]
+
+            [style]
+            padding = [12, 10]
+            corner_radius = 4
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(theme.toggle.padding, (1, 2));
+        assert_eq!(theme.toggle.corner_radius, 4);
+        assert_eq!(theme.button.padding, (12, 10));
+        assert_eq!(theme.slider.corner_radius, 4);
+    }
+
+    #[test]
+    fn errors_name_the_key_that_is_wrong() {
+        let error = |source| Theme::from_toml(source).unwrap_err();
+
+        assert_eq!(
+            error("base = \"blue\""),
+            "`base` must be \"dark\" or \"light\", found \"blue\""
+        );
+        assert!(error("colour = 1").starts_with("Unknown key `colour`, expected one of `base`"));
+        assert!(error("[button]\nmargin = 1").starts_with("Unknown key `button.margin`"));
+        assert_eq!(
+            error("[label]\nfont_size = -1"),
+            "`label.font_size` must be a whole number of at least 0, found -1"
+        );
+        assert_eq!(
+            error("[label]\npadding = [1]"),
+            "`label.padding` must be an array of two lengths, found [1]"
+        );
+        assert!(error("[label]\ntext = { pressed = \"#ffffff\" }")
+            .starts_with("Unknown state `label.text.pressed`"));
+        assert!(error("[label]\ntext = \"#fff\"").starts_with("`label.text` must be a colour"));
+        assert!(error("[label]\ntext = [1, 2]").starts_with("`label.text` must be a colour"));
+        assert!(error("[label]\ntext = [256, 0, 0]").starts_with("`label.text` must be a colour"));
+    }
+
+    #[test]
+    fn loading_a_missing_file_names_the_file() {
+        let error = Theme::load("missing-theme.toml").unwrap_err();
+
+        assert!(error.starts_with("Could not read theme file missing-theme.toml"));
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    common::*,
+    gui_component::GuiComponentBehaviour,
+    prelude::{
+        Dimensions, Point, Renderer, TextMeasurer, Theme, ValueCallback, WidgetId, WidgetState,
+        WidgetStyle,
+    },
+};
+
+/// An on/off switch with a label, whose knob slides across its track when it
+/// is clicked.
+pub struct Toggle<T> {
+    changed: bool,
+    custom_style: bool,
+    duration: f32,
+    enabled: bool,
+    focused: bool,
+    hovered: bool,
+    id: WidgetId,
+    knob: f32,
+    on_change: Option<ValueCallback<T, bool>>,
+    pub dimensions: Dimensions,
+    pub position: Point,
+    preferred_dimensions: Dimensions,
+    style: WidgetStyle,
+    text: String,
+    text_measurer: TextMeasurer,
+    value: bool,
+    visible: bool,
+}
+
+impl<T> Toggle<T> {
+    /// The default time in seconds that the knob takes to slide across the
+    /// track.
+    pub const DEFAULT_DURATION: f32 = 0.15;
+
+    /// Create a new `Toggle` labelled with `text`, automatically figuring out
+    /// its width.
+    pub fn new(text: &str, value: bool, position: Point) -> Self {
+        let mut toggle = Self {
+            changed: false,
+            custom_style: false,
+            dimensions: (0, 0),
+            duration: Self::DEFAULT_DURATION,
+            enabled: true,
+            focused: false,
+            hovered: false,
+            id: WidgetId::unique(),
+            knob: if value { 1.0 } else { 0.0 },
+            on_change: None,
+            position,
+            preferred_dimensions: (0, 0),
+            style: Theme::default().toggle,
+            text: text.to_string(),
+            text_measurer: TextMeasurer::default(),
+            value,
+            visible: true,
+        };
+        toggle.fit_text();
+
+        toggle
+    }
+
+    widget_methods!(Toggle);
+    widget_style_methods!(Toggle, toggle, fit_text);
+    widget_on_change!(Toggle, bool, |toggle| toggle.value);
+
+    /// Sets the text of the label of the `Toggle`, resizing it to fit.
+    pub fn set_text(&mut self, text: &str) -> &mut Self {
+        self.text = text.to_string();
+        self.fit_text();
+
+        self
+    }
+
+    /// The dimensions of the track, which is twice as wide as the font is
+    /// tall.
+    fn track_dimensions(&self) -> Dimensions { (self.style.font_size * 2, self.style.font_size) }
+
+    fn fit_text(&mut self) {
+        let text_width = self.text_measurer.measure(&self.text, self.style.font_size);
+        self.preferred_dimensions = self
+            .style
+            .fit_text(self.track_dimensions().0 + self.style.padding.0 + text_width);
+        self.resize(self.preferred_dimensions);
+    }
+
+    /// Returns whether or not the `Toggle` is on.
+    pub fn get_value(&self) -> bool { self.value }
+
+    /// Turns the `Toggle` on or off, moving the knob straight to its new
+    /// position.
+    pub fn set_value(&mut self, value: bool) -> &mut Self {
+        self.value = value;
+        self.knob = if value { 1.0 } else { 0.0 };
+
+        self
+    }
+
+    /// Sets the time in seconds that the knob takes to slide across the
+    /// track, where 0 moves it instantly.
+    pub fn set_duration(&mut self, duration: f32) -> &mut Self {
+        self.duration = duration.max(0.0);
+
+        self
+    }
+
+    /// Slides the knob towards the side for the current value by the
+    /// distance it covers in `frame_time` seconds.
+    pub fn animate(&mut self, frame_time: f32) {
+        let target = if self.value { 1.0 } else { 0.0 };
+
+        if self.duration <= 0.0 {
+            self.knob = target;
+            return;
+        }
+
+        let step = frame_time / self.duration;
+        self.knob = match self.value {
+            true => (self.knob + step).min(target),
+            false => (self.knob - step).max(target),
+        };
+    }
+
+    /// Returns `true` while the knob is sliding between sides.
+    pub fn is_animating(&self) -> bool {
+        let target = if self.value { 1.0 } else { 0.0 };
+
+        (self.knob - target).abs() > f32::EPSILON
+    }
+
+    /// Returns whether or not the `Toggle` has keyboard or gamepad focus.
+    pub fn is_focused(&self) -> bool { self.focused }
+
+    /// Gives or takes away keyboard or gamepad focus, which draws an outline
+    /// around the `Toggle`.
+    pub fn set_focused(&mut self, focused: bool) -> &mut Self {
+        self.focused = focused;
+
+        self
+    }
+
+    /// Switches the `Toggle` without the mouse, such as when it is activated
+    /// while focused, sliding the knob across.
+    pub fn activate(&mut self) -> &mut Self {
+        self.value = !self.value;
+        self.changed = true;
+
+        self
+    }
+
+    /// Returns the state the `Toggle` is currently drawn in.
+    pub fn get_state(&self) -> WidgetState {
+        if !self.enabled {
+            return WidgetState::Disabled;
+        }
+
+        match self.hovered {
+            true => WidgetState::Hovered,
+            false => WidgetState::Default,
+        }
+    }
+}
+
+impl<T> GuiComponentBehaviour<bool> for Toggle<T> {
+    /// Draw `Toggle` to screen. The track is drawn in its active accent
+    /// colour while the `Toggle` is on.
+    fn draw(&mut self, renderer: &mut dyn Renderer) {
+        let state = self.get_state();
+        let track_dimensions = self.track_dimensions();
+        let track_position = (
+            self.position.0 + self.style.padding.0,
+            self.position.1 + (self.dimensions.1 - track_dimensions.1) / 2,
+        );
+        let track_state = match (self.value, self.enabled) {
+            (true, true) => WidgetState::Active,
+            _ => state,
+        };
+
+        draw_background(renderer, self.position, self.dimensions, &self.style, state);
+
+        renderer.draw_rounded_rectangle(
+            track_position,
+            track_dimensions,
+            track_dimensions.1 / 2,
+            self.style.accent.get(track_state),
+        );
+
+        let knob_size = track_dimensions.1 - 4;
+        let knob_travel = track_dimensions.0 - track_dimensions.1;
+        renderer.draw_rounded_rectangle(
+            (
+                track_position.0 + 2 + (knob_travel as f32 * self.knob) as i32,
+                track_position.1 + 2,
+            ),
+            (knob_size, knob_size),
+            knob_size / 2,
+            self.style.foreground.get(state),
+        );
+
+        renderer.draw_text(
+            &self.text,
+            (
+                track_position.0 + track_dimensions.0 + self.style.padding.0,
+                self.position.1 + (self.dimensions.1 - self.style.font_size) / 2,
+            ),
+            self.style.font_size,
+            self.style.text.get(state),
+        );
+
+        if self.focused {
+            draw_focus_outline(renderer, self.position, self.dimensions, &self.style);
+        }
+    }
+
+    /// Checks if the `Toggle` or its label is being hovered over.
+    fn is_hovered(&mut self, mouse_position: Point) -> bool {
+        self.hovered = is_inside(self.position, self.dimensions, mouse_position);
+
+        self.hovered
+    }
+
+    /// Checks if the `Toggle` or its label was clicked, switching it and
+    /// returning whether or not it changed.
+    fn is_clicked(&mut self, mouse_position: Point, is_clicked: bool) -> bool {
+        let clicked = is_inside(self.position, self.dimensions, mouse_position) && is_clicked;
+
+        if clicked {
+            self.value = !self.value;
+        }
+        self.changed |= clicked;
+
+        clicked
+    }
+}
+
+widget_from_drawables!(Toggle<T>, Toggle);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::measure_text_monospace;
+
+    fn toggle(value: bool) -> Toggle<Vec<bool>> {
+        let mut toggle = Toggle::new("Sound", value, (0, 0));
+        toggle
+            .set_text_measurer(TextMeasurer::new(measure_text_monospace))
+            .set_on_change(Box::new(|values: &mut Vec<bool>, value| values.push(value)));
+
+        toggle
+    }
+
+    #[test]
+    fn the_knob_slides_across_over_the_duration() {
+        let mut toggle = toggle(false);
+        toggle.set_duration(0.2).activate();
+
+        assert!(toggle.get_value());
+        assert!(toggle.is_animating());
+
+        toggle.animate(0.1);
+        assert_eq!(toggle.knob, 0.5);
+        assert!(toggle.is_animating());
+
+        toggle.animate(0.5);
+        assert_eq!(toggle.knob, 1.0);
+        assert!(!toggle.is_animating());
+
+        toggle.activate().animate(0.05);
+        assert_eq!(toggle.knob, 0.75);
+    }
+
+    #[test]
+    fn the_knob_jumps_without_a_duration_or_when_the_value_is_set() {
+        let mut toggle = toggle(false);
+        toggle.set_duration(0.0).activate().animate(0.01);
+        assert_eq!(toggle.knob, 1.0);
+
+        toggle.set_duration(1.0).set_value(false);
+        assert_eq!(toggle.knob, 0.0);
+        assert!(!toggle.is_animating());
+    }
+
+    #[test]
+    fn clicking_switches_the_toggle_and_calls_the_callback() {
+        let mut toggle = toggle(false);
+        let mut values = Vec::new();
+
+        assert!(toggle.is_clicked((5, 5), true));
+        toggle.execute_callbacks(&mut values);
+        assert!(!toggle.is_clicked((-5, 5), true));
+        toggle.execute_callbacks(&mut values);
+        assert!(toggle.is_clicked((5, 5), true));
+        toggle.execute_callbacks(&mut values);
+
+        assert_eq!(values, vec![true, false]);
+    }
+}
@@ -1,7 +1,7 @@
-use raylib::prelude::RaylibDrawHandle;
+use crate::prelude::Renderer;
 
 /// Allows for external objects to be drawn before any GUI elements are drawn,
 /// effectively making a new layer for GUI.
 pub trait Drawable {
-    fn draw(&mut self, draw_handler: &mut RaylibDrawHandle);
+    fn draw(&mut self, renderer: &mut dyn Renderer);
 }
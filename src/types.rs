@@ -1,6 +1,60 @@
+use crate::renderer::measure_text_default_font;
 use raylib::prelude::Color;
+use std::{fmt, rc::Rc};
 
 pub type Point = (i32, i32);
 pub type Dimensions = (i32, i32);
 pub type Colour = Color;
-pub type Action<T> = fn(&mut T, &str);
+pub type Action<T, E> = fn(&mut T, &E);
+pub type Callback<T> = Box<dyn FnMut(&mut T)>;
+pub type ValueCallback<T, V> = Box<dyn FnMut(&mut T, V)>;
+pub type ValueFormatter = Box<dyn Fn(f32) -> String>;
+
+type MeasureFn = dyn Fn(&str, i32) -> i32;
+
+/// Measures the width in pixels of text drawn at a font size. It is made from
+/// a function, or from a function and the source it measures with, such as a
+/// font, which it keeps alive. Measurers made from the same function and
+/// source are equal.
+#[derive(Clone)]
+pub struct TextMeasurer {
+    key: (usize, usize),
+    measure: Rc<MeasureFn>,
+}
+
+impl TextMeasurer {
+    /// Creates a new `TextMeasurer` that measures text with `measure`.
+    pub fn new(measure: fn(&str, i32) -> i32) -> Self {
+        Self {
+            key: (measure as usize, 0),
+            measure: Rc::new(measure),
+        }
+    }
+
+    /// Creates a new `TextMeasurer` that measures text with `measure` and
+    /// `source`, such as the font that the text is drawn in.
+    pub fn with_source<S: 'static>(source: Rc<S>, measure: fn(&S, &str, i32) -> i32) -> Self {
+        Self {
+            key: (measure as usize, Rc::as_ptr(&source) as usize),
+            measure: Rc::new(move |text, font_size| measure(&source, text, font_size)),
+        }
+    }
+
+    /// Returns the width in pixels of `text` drawn at `font_size`.
+    pub fn measure(&self, text: &str, font_size: i32) -> i32 { (self.measure)(text, font_size) }
+}
+
+impl Default for TextMeasurer {
+    /// Measures text in raylib's default font.
+    fn default() -> Self { Self::new(measure_text_default_font) }
+}
+
+impl PartialEq for TextMeasurer {
+    fn eq(&self, other: &Self) -> bool { self.key == other.key }
+}
+
+impl fmt::Debug for TextMeasurer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TextMeasurer").finish_non_exhaustive()
+    }
+}